@@ -0,0 +1,21 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            /// Replaces the leveling curve. `thresholds[n]` is the experience at which
+/// level `n` starts; the list is sorted if it isn't already.
+void  setLevelCurve({required List<int> thresholds }) => RustLib.instance.api.crateApiLevelingSetLevelCurve(thresholds: thresholds);
+
+/// The index of the highest threshold not above `experience`. Experience
+/// below the first threshold, or an empty curve, counts as level 0.
+int  levelForExperience({required int experience }) => RustLib.instance.api.crateApiLevelingLevelForExperience(experience: experience);
+
+            
+            
\ No newline at end of file
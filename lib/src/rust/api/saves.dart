@@ -0,0 +1,1471 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'saves.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `initialize_system`, `on_worker`, `with_save_manager_mut`, `with_save_manager`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `hash`
+
+
+            /// The message of the most recent save call that failed, or `None` if the
+/// latest call succeeded. Meant for a debug overlay, including failures from
+/// calls whose result nobody awaited.
+String?  lastError() => RustLib.instance.api.crateApiSavesLastError();
+
+/// Sets up the save directory under `base_path` (the app documents directory
+/// on the Flutter side). Must be called before any other save function.
+///
+/// Calling it again with the same `base_path` and configuration is a no-op
+/// that keeps the loaded slot. Anything different is rejected; use
+/// `force_init_system` to switch.
+void  initSystem({required String basePath }) => RustLib.instance.api.crateApiSavesInitSystem(basePath: basePath);
+
+/// Like `init_system`, but keeps saves in `<base_path>/<app_name>/<saves_subdir>`
+/// instead of `my_app/saves`, so several products or isolated test
+/// instances can share a base path. Each name must be a single directory
+/// name.
+void  initSystemWithConfig({required String basePath , required String appName , required String savesSubdir }) => RustLib.instance.api.crateApiSavesInitSystemWithConfig(basePath: basePath, appName: appName, savesSubdir: savesSubdir);
+
+/// Like `init_system`, but every save database is encrypted with `key` via
+/// SQLCipher. Opening saves with a different key fails with
+/// `DecryptionFailed`. Existing unencrypted saves are not converted and
+/// can't be opened this way.
+void  initSystemEncrypted({required String basePath , required String key }) => RustLib.instance.api.crateApiSavesInitSystemEncrypted(basePath: basePath, key: key);
+
+/// Like `init_system`, but keeps saves in `my_app/profiles/<profile>/saves`
+/// with their own metadata, so players sharing a device never see each
+/// other's slots. `profile` must be a single directory name.
+void  initSystemForProfile({required String basePath , required String profile }) => RustLib.instance.api.crateApiSavesInitSystemForProfile(basePath: basePath, profile: profile);
+
+/// The profiles with saves under `base_path`, sorted by name. Works whether
+/// or not the system is initialized.
+List<String>  listProfiles({required String basePath }) => RustLib.instance.api.crateApiSavesListProfiles(basePath: basePath);
+
+/// Shuts the save system down and initializes it again for `profile`, under
+/// the same base path and otherwise the same configuration. Slot ids belong
+/// to one profile, so nothing loaded carries over. If the new profile can't
+/// be initialized the current one stays active.
+void  switchProfile({required String profile }) => RustLib.instance.api.crateApiSavesSwitchProfile(profile: profile);
+
+/// Replaces the save system unconditionally. The previous one is shut down
+/// the way `close_system` does it, so nothing written through its slots is
+/// lost; the new system stays active even if that final flush fails, and the
+/// error is still returned.
+void  forceInitSystem({required String basePath }) => RustLib.instance.api.crateApiSavesForceInitSystem(basePath: basePath);
+
+bool  isInitialized() => RustLib.instance.api.crateApiSavesIsInitialized();
+
+/// Closes every slot and the metadata database and shuts the save system
+/// down, e.g. on logout or an account switch, releasing every file lock.
+/// Other save functions then fail with `NotInitialized` until `init_system`
+/// is called again. Does nothing if the system isn't initialized. The system
+/// is shut down even if the final flush fails; that error is still returned.
+void  closeSystem() => RustLib.instance.api.crateApiSavesCloseSystem();
+
+/// Replaces the built-in slot schema with the app's own migrations, each an
+/// `(up, down)` SQL pair; step `n` takes a slot to schema version `n`, and a
+/// step without down SQL can't be rolled back. An empty list restores the
+/// built-in schema. Must be called before `init_system`: once the system is
+/// initialized this fails with `AlreadyInitialized`.
+///
+/// Player data, inventory, flag and export functions rely on the built-in
+/// `player_stats`, `inventory` and `game_flags` tables, so keep those in a
+/// custom schema to go on using them.
+void  registerSlotMigrations({required List<(String,String?)> migrations }) => RustLib.instance.api.crateApiSavesRegisterSlotMigrations(migrations: migrations);
+
+/// Everything a diagnostics panel needs in one call. Never fails: an
+/// uninitialized system reports `initialized: false` and no other values.
+StoreHealth  storeHealth() => RustLib.instance.api.crateApiSavesStoreHealth();
+
+/// Where slot databases are written, e.g. for an "open folder" button.
+String  savesDirectory() => RustLib.instance.api.crateApiSavesSavesDirectory();
+
+/// Where the slot metadata database is written.
+String  metadataDbPath() => RustLib.instance.api.crateApiSavesMetadataDbPath();
+
+/// Creates an empty slot and returns its complete metadata, including
+/// `created_at`, so the new card can be shown without calling `get_slot`.
+SaveSlotMetadata  createNewSlot({required String displayName }) => RustLib.instance.api.crateApiSavesCreateNewSlot(displayName: displayName);
+
+/// Creates a slot already holding `data`, e.g. a New Game+ or difficulty
+/// preset, in one call instead of create, load and save.
+SaveSlotMetadata  createSlotWithData({required String displayName , required PlayerData data }) => RustLib.instance.api.crateApiSavesCreateSlotWithData(displayName: displayName, data: data);
+
+List<SaveSlotMetadata>  getAllSlots() => RustLib.instance.api.crateApiSavesGetAllSlots();
+
+/// Fetches one page of slots, for save browsers with many entries.
+List<SaveSlotMetadata>  getSlotsPage({required int offset , required int limit , required SlotOrder order }) => RustLib.instance.api.crateApiSavesGetSlotsPage(offset: offset, limit: limit, order: order);
+
+/// How many slots `get_slots_page` can page through, trash excluded, e.g.
+/// for "Showing 20 of 137". 0 when there are none.
+BigInt  countSlots() => RustLib.instance.api.crateApiSavesCountSlots();
+
+/// Cheaply checks that a slot can be loaded: it is registered and its file
+/// is on disk. Returns `false` if either is missing.
+bool  slotExists({required String slotId }) => RustLib.instance.api.crateApiSavesSlotExists(slotId: slotId);
+
+/// Reads a slot's health, experience and item count for the Continue
+/// button without loading it: nothing is migrated and the slot isn't marked
+/// as played.
+SlotPreview  previewSlot({required String slotId }) => RustLib.instance.api.crateApiSavesPreviewSlot(slotId: slotId);
+
+/// Slots whose name contains `query`, ignoring case, most recently played
+/// first, for a save browser's search box. No matches is an empty list.
+List<SaveSlotMetadata>  searchSlots({required String query }) => RustLib.instance.api.crateApiSavesSearchSlots(query: query);
+
+/// Fetches one slot's metadata, e.g. to refresh a single card after a change.
+SaveSlotMetadata  getSlot({required String slotId }) => RustLib.instance.api.crateApiSavesGetSlot(slotId: slotId);
+
+/// Streams an event after every committed slot creation, rename, deletion
+/// and save, so UIs can refresh instead of polling `get_all_slots`. The
+/// subscription ends once Dart cancels the stream.
+Stream<SlotEvent>  subscribeSlotEvents() => RustLib.instance.api.crateApiSavesSubscribeSlotEvents();
+
+/// Every slot change journaled after `timestamp_ms` (Unix epoch
+/// milliseconds), oldest first, so a sync layer can upload just the slots
+/// that changed since its last run. Creation, renames, deletion, restores and
+/// saves are recorded.
+List<ChangeEntry>  changesSince({required PlatformInt64 timestampMs }) => RustLib.instance.api.crateApiSavesChangesSince(timestampMs: timestampMs);
+
+/// Drops journal entries older than `timestamp_ms`, e.g. once they have been
+/// synced, and returns how many were removed.
+BigInt  pruneChangeLog({required PlatformInt64 timestampMs }) => RustLib.instance.api.crateApiSavesPruneChangeLog(timestampMs: timestampMs);
+
+/// Stores a global setting such as volume or language. Settings live in the
+/// metadata database and don't need a loaded slot.
+void  setSetting({required String key , required String value }) => RustLib.instance.api.crateApiSavesSetSetting(key: key, value: value);
+
+String?  getSetting({required String key }) => RustLib.instance.api.crateApiSavesGetSetting(key: key);
+
+/// Every stored setting as `(key, value)` pairs, sorted by key.
+List<(String,String)>  getAllSettings() => RustLib.instance.api.crateApiSavesGetAllSettings();
+
+void  addSlotTag({required String slotId , required String tag }) => RustLib.instance.api.crateApiSavesAddSlotTag(slotId: slotId, tag: tag);
+
+void  removeSlotTag({required String slotId , required String tag }) => RustLib.instance.api.crateApiSavesRemoveSlotTag(slotId: slotId, tag: tag);
+
+List<SaveSlotMetadata>  getSlotsByTag({required String tag }) => RustLib.instance.api.crateApiSavesGetSlotsByTag(tag: tag);
+
+/// Renames a slot and returns its refreshed metadata so the UI can update the
+/// card in place.
+SaveSlotMetadata  renameSlot({required String slotId , required String newName }) => RustLib.instance.api.crateApiSavesRenameSlot(slotId: slotId, newName: newName);
+
+/// Copies an existing slot into a new one so the player can branch their save.
+SaveSlotMetadata  duplicateSlot({required String slotId , required String newName }) => RustLib.instance.api.crateApiSavesDuplicateSlot(slotId: slotId, newName: newName);
+
+/// Starts New Game Plus from a finished slot: a copy named "<name> NG+"
+/// with health reset to the starting value (see `set_new_game_plus_health`),
+/// `experience_carryover_pct` percent of the experience (0 to 100), and the
+/// inventory only if `keep_inventory`. The finished slot is left untouched.
+SaveSlotMetadata  startNewGamePlus({required String slotId , required bool keepInventory , required double experienceCarryoverPct }) => RustLib.instance.api.crateApiSavesStartNewGamePlus(slotId: slotId, keepInventory: keepInventory, experienceCarryoverPct: experienceCarryoverPct);
+
+/// Sets the health New Game Plus runs start with (default 100).
+void  setNewGamePlusHealth({required int health }) => RustLib.instance.api.crateApiSavesSetNewGamePlusHealth(health: health);
+
+/// Compares two slots for a "what changed" screen: their stats and which
+/// items were added, removed or changed in number going from `slot_a` to
+/// `slot_b`. Neither slot needs to be loaded.
+SlotDiff  diffSlots({required String slotA , required String slotB }) => RustLib.instance.api.crateApiSavesDiffSlots(slotA: slotA, slotB: slotB);
+
+/// Renames an item in every save, e.g. after a content update turned
+/// "Potion" into "Health Potion", and reports how many entries changed. Open
+/// slots are updated in place. A slot that can't be updated is reported in
+/// `skipped` without stopping the others.
+ItemRenameResult  renameItemEverywhere({required String old , required String new_ }) => RustLib.instance.api.crateApiSavesRenameItemEverywhere(old: old, new_: new_);
+
+/// Adds the items of slot `from` to slot `into`, e.g. to import a friend's
+/// items, and returns `into`'s new item count. `from` is left as it is and
+/// `into` keeps its stats. `into` must have been saved before; fails with
+/// `InvalidPlayerData` if the result would exceed the inventory limit.
+BigInt  mergeInventories({required String into , required String from , required MergeStrategy strategy }) => RustLib.instance.api.crateApiSavesMergeInventories(into: into, from: from, strategy: strategy);
+
+/// Marks several slots as just played in one transaction, e.g. after a
+/// batch import, and returns the ids that were found.
+List<String>  touchSlots({required List<String> slotIds }) => RustLib.instance.api.crateApiSavesTouchSlots(slotIds: slotIds);
+
+/// Adds to a slot's total play time. Meant to be called periodically or when
+/// the game is paused.
+void  addPlayTime({required String slotId , required PlatformInt64 seconds }) => RustLib.instance.api.crateApiSavesAddPlayTime(slotId: slotId, seconds: seconds);
+
+/// Sets the notes shown on a slot's card, e.g. "before the boss". An empty
+/// string clears them.
+void  setSlotDescription({required String slotId , required String text }) => RustLib.instance.api.crateApiSavesSetSlotDescription(slotId: slotId, text: text);
+
+/// Sets the preview image shown on a slot's card (2 MB max).
+void  setSlotThumbnail({required String slotId , required List<int> pngBytes }) => RustLib.instance.api.crateApiSavesSetSlotThumbnail(slotId: slotId, pngBytes: pngBytes);
+
+/// Returns the slot's preview image, or `None` if it never had one.
+Uint8List?  getSlotThumbnail({required String slotId }) => RustLib.instance.api.crateApiSavesGetSlotThumbnail(slotId: slotId);
+
+/// Checks a slot's database for damage without loading it, so the UI can
+/// flag broken saves up front.
+bool  verifySlot({required String slotId }) => RustLib.instance.api.crateApiSavesVerifySlot(slotId: slotId);
+
+/// The schema version of a slot's file, without loading it. Lower than
+/// `latest_schema_version` means loading the slot will upgrade it.
+BigInt  slotSchemaVersion({required String slotId }) => RustLib.instance.api.crateApiSavesSlotSchemaVersion(slotId: slotId);
+
+/// The schema version this build migrates slots to.
+BigInt  latestSchemaVersion() => RustLib.instance.api.crateApiSavesLatestSchemaVersion();
+
+/// Which schemas and crate version this build runs, for support tickets.
+/// Needs no initialization and touches no files.
+SchemaInfo  schemaInfo() => RustLib.instance.api.crateApiSavesSchemaInfo();
+
+/// Migrates a slot that isn't loaded or open to schema version `target`,
+/// upgrading or rolling back as needed. Rolling back discards data stored
+/// by the reverted versions. The next `load_slot` upgrades it again.
+void  migrateSlotToVersion({required String slotId , required BigInt target }) => RustLib.instance.api.crateApiSavesMigrateSlotToVersion(slotId: slotId, target: target);
+
+/// Slot backups left over from schema upgrades that failed or were cut short
+/// by a crash, for a support screen to surface.
+List<MigrationBackupInfo>  listMigrationBackups() => RustLib.instance.api.crateApiSavesListMigrationBackups();
+
+/// Clears WAL locks a crashed process left on a slot, for when loading or
+/// saving it fails with `SlotLocked`. The slot must not be loaded or open
+/// through a handle. Still fails with `SlotLocked` if another process is
+/// actually using it.
+void  recoverSlotLocks({required String slotId }) => RustLib.instance.api.crateApiSavesRecoverSlotLocks(slotId: slotId);
+
+/// Compacts a slot's database file. The slot must not be loaded or open
+/// through a handle.
+void  vacuumSlot({required String slotId }) => RustLib.instance.api.crateApiSavesVacuumSlot(slotId: slotId);
+
+/// Finds slot files with no metadata row and rows whose file is gone,
+/// without changing anything.
+IntegrityReport  scanIntegrity() => RustLib.instance.api.crateApiSavesScanIntegrity();
+
+/// Fixes what `scan_integrity` reports: dangling rows are removed and
+/// orphaned files are deleted or registered as new slots, per `policy`.
+RepairReport  repairIntegrity({required RepairPolicy policy }) => RustLib.instance.api.crateApiSavesRepairIntegrity(policy: policy);
+
+/// Checkpoints and optimizes every slot and vacuums the ones that aren't open
+/// and take more than `vacuum_above_bytes` (none if `None`). Per-slot
+/// failures are listed in the report instead of failing the run.
+MaintenanceReport  runMaintenance({BigInt? vacuumAboveBytes }) => RustLib.instance.api.crateApiSavesRunMaintenance(vacuumAboveBytes: vacuumAboveBytes);
+
+/// Totals across every slot for a profile screen: highest experience and
+/// health, items held, play time and slot counts. Unreadable slots are
+/// skipped and listed in the result. Reads every slot file, so call it when
+/// the screen opens rather than on every frame.
+AggregateStats  aggregateStats() => RustLib.instance.api.crateApiSavesAggregateStats();
+
+/// Compacts the metadata database.
+void  vacuumMetadata() => RustLib.instance.api.crateApiSavesVacuumMetadata();
+
+/// Moves a slot's database file into `new_dir`, such as external storage,
+/// and updates its `file_path`. The slot must be unloaded first; handles
+/// from `open_slot` are closed.
+void  moveSlot({required String slotId , required String newDir }) => RustLib.instance.api.crateApiSavesMoveSlot(slotId: slotId, newDir: newDir);
+
+/// Bytes used by a slot's database files. Sidecar files that don't exist
+/// count as zero.
+BigInt  slotDiskUsage({required String slotId }) => RustLib.instance.api.crateApiSavesSlotDiskUsage(slotId: slotId);
+
+/// Bytes used by the whole saves directory, including autosave backups.
+BigInt  totalSavesDiskUsage() => RustLib.instance.api.crateApiSavesTotalSavesDiskUsage();
+
+/// Moves the given slots to the trash together and returns the ids that were
+/// actually trashed, so callers can spot ones that were already gone. The loaded slot
+/// is unloaded if it is among them.
+List<String>  deleteSlots({required List<String> slotIds }) => RustLib.instance.api.crateApiSavesDeleteSlots(slotIds: slotIds);
+
+/// Permanently deletes every slot and its backups, trash included. Global
+/// settings are kept.
+void  deleteAllSlots() => RustLib.instance.api.crateApiSavesDeleteAllSlots();
+
+void  loadSlot({required String slotId }) => RustLib.instance.api.crateApiSavesLoadSlot(slotId: slotId);
+
+/// Sets the limits every save, autosave and import is checked against:
+/// at most `max_inventory` items and `max_health` health. Negative health or
+/// experience is always rejected. Limits reset when the system is
+/// re-initialized.
+void  setValidationLimits({required int maxInventory , required int maxHealth }) => RustLib.instance.api.crateApiSavesSetValidationLimits(maxInventory: maxInventory, maxHealth: maxHealth);
+
+/// Sets the longest slot name `create_new_slot` and `rename_slot` accept,
+/// counted in characters (default 100).
+void  setMaxSlotNameLength({required int maxLength }) => RustLib.instance.api.crateApiSavesSetMaxSlotNameLength(maxLength: maxLength);
+
+/// Tunes connections opened from now on: how long to wait on a locked
+/// database before failing (default 5000 ms), and `PRAGMA synchronous`,
+/// `"FULL"` (the default) or `"NORMAL"`. NORMAL is faster and still safe
+/// from corruption in WAL mode, but a power cut can lose the latest saves.
+void  setSqliteOptions({required int busyTimeoutMs , required String synchronous }) => RustLib.instance.api.crateApiSavesSetSqliteOptions(busyTimeoutMs: busyTimeoutMs, synchronous: synchronous);
+
+/// Sets the WAL size, in pages, at which SQLite checkpoints on its own
+/// (default 1000). Smaller means more frequent writes to storage; larger
+/// batches them, which suits flaky flash, but keeps recent data only in the
+/// `-wal` file for longer. 0 disables automatic checkpoints so durability is
+/// left to saves, which checkpoint their slot, and `checkpoint_active_slot`.
+void  setWalAutocheckpoint({required int pages }) => RustLib.instance.api.crateApiSavesSetWalAutocheckpoint(pages: pages);
+
+/// Sets how many times a save is retried when another connection, e.g. a
+/// running autosave, keeps the slot locked past the busy timeout (default 2).
+/// Each retry waits twice as long as the last, starting at 50 ms; once they
+/// run out the save fails with `SlotLocked`.
+void  setBusyRetries({required int retries }) => RustLib.instance.api.crateApiSavesSetBusyRetries(retries: retries);
+
+/// Sets how little free space, in bytes, makes a save emit a `LowDiskSpace`
+/// slot event before it runs (default 50 MiB); 0 turns the warning off. The
+/// save is still attempted, and fails with `DiskFull` if space does run out.
+void  setLowDiskSpaceThreshold({required BigInt bytes }) => RustLib.instance.api.crateApiSavesSetLowDiskSpaceThreshold(bytes: bytes);
+
+/// Turns save checksums on (the default) or off. With checks on, every save
+/// stores a SHA-256 of its data and `load_player_data` fails with
+/// `ChecksumMismatch` if the file was edited since.
+void  setIntegrityCheckEnabled({required bool enabled }) => RustLib.instance.api.crateApiSavesSetIntegrityCheckEnabled(enabled: enabled);
+
+/// Sets the app's own save format version, stamped into every slot saved
+/// from now on (default 0). It is independent of the database schema, so the
+/// app can migrate save contents itself by comparing it with
+/// `slot_app_version`. Resets when the system is re-initialized.
+void  setAppSaveVersion({required int version }) => RustLib.instance.api.crateApiSavesSetAppSaveVersion(version: version);
+
+/// The app save version `slot_id` was last saved with, without loading it.
+/// Slots never saved, or saved before versions were stored, report 0.
+int  slotAppVersion({required String slotId }) => RustLib.instance.api.crateApiSavesSlotAppVersion(slotId: slotId);
+
+/// Keeps the slot metadata database open between calls (the default) instead
+/// of reopening it for every call, which saves the open and key setup on
+/// every metadata read. Turn it off only to release the file between calls.
+void  setKeepMetadataConnection({required bool enabled }) => RustLib.instance.api.crateApiSavesSetKeepMetadataConnection(enabled: enabled);
+
+SaveResult  savePlayerData({required PlayerData data }) => RustLib.instance.api.crateApiSavesSavePlayerData(data: data);
+
+/// Saves only if the loaded slot is still at `expected_revision`; otherwise
+/// fails with `RevisionConflict` carrying the current one, so the UI can
+/// offer to merge or overwrite.
+SaveResult  savePlayerDataChecked({required PlayerData data , required PlatformInt64 expectedRevision }) => RustLib.instance.api.crateApiSavesSavePlayerDataChecked(data: data, expectedRevision: expectedRevision);
+
+/// The loaded slot's revision, which every save bumps. 0 if it was never
+/// saved.
+PlatformInt64  playerDataRevision() => RustLib.instance.api.crateApiSavesPlayerDataRevision();
+
+/// Same as `save_player_data`, but without `frb(sync)`: flutter_rust_bridge
+/// runs it on its worker pool and hands Dart a `Future`, so writing a large
+/// inventory doesn't block the isolate.
+///
+/// It is intentionally a plain function rather than an `async fn`: the pool
+/// thread just blocks until the save worker, which owns every `Connection`,
+/// has run the write. Nothing non-`Send` ever crosses threads, and the write
+/// is serialized with the sync API by the worker's queue.
+Future<SaveResult>  savePlayerDataAsync({required PlayerData data }) => RustLib.instance.api.crateApiSavesSavePlayerDataAsync(data: data);
+
+/// Saves the loaded slot and updates its thumbnail together. A failure in
+/// either part rolls back the other; the only gap is an I/O error while
+/// undoing the save after the thumbnail failed to commit.
+SaveResult  savePlayerDataWithThumbnail({required PlayerData data , required List<int> pngBytes }) => RustLib.instance.api.crateApiSavesSavePlayerDataWithThumbnail(data: data, pngBytes: pngBytes);
+
+/// Forces the loaded slot's pending WAL writes into its `.db` file so the file
+/// is consistent on its own. Useful right before the app is backgrounded.
+void  checkpointActiveSlot() => RustLib.instance.api.crateApiSavesCheckpointActiveSlot();
+
+/// Applies `ops` to the loaded slot atomically: either every change is kept
+/// or, if one fails, none are. The slot must have been saved once.
+void  applyBatch({required List<SaveOp> ops }) => RustLib.instance.api.crateApiSavesApplyBatch(ops: ops);
+
+/// Reads only the loaded slot's inventory, in order, e.g. for an inventory
+/// screen.
+List<String>  loadInventory() => RustLib.instance.api.crateApiSavesLoadInventory();
+
+/// Reads only the loaded slot's health and experience, in that order.
+(int,int)  loadPlayerStats() => RustLib.instance.api.crateApiSavesLoadPlayerStats();
+
+/// Appends `quantity` copies of `item` to the loaded slot's inventory
+/// without rewriting the rest of it. The slot must have been saved once.
+void  addInventoryItem({required String item , required int quantity }) => RustLib.instance.api.crateApiSavesAddInventoryItem(item: item, quantity: quantity);
+
+/// Removes the item at `position`; later items move up one, so positions
+/// stay contiguous from 0. The last 20 removals can be undone with
+/// `undo_last_inventory_change`.
+void  removeInventoryItem({required PlatformInt64 position }) => RustLib.instance.api.crateApiSavesRemoveInventoryItem(position: position);
+
+/// Puts the most recently removed item back at its old position. Up to 20
+/// removals per loaded slot are remembered, in memory only: they are
+/// forgotten when the slot is unloaded or the app restarts. Fails with
+/// `NothingToUndo` when there is none left.
+void  undoLastInventoryChange() => RustLib.instance.api.crateApiSavesUndoLastInventoryChange();
+
+/// Replaces the item at `position` in the loaded slot's inventory.
+void  setInventoryItem({required PlatformInt64 position , required String item }) => RustLib.instance.api.crateApiSavesSetInventoryItem(position: position, item: item);
+
+/// Reads back the loaded slot's data. A slot that was created but never saved
+/// yields a "no player data" error, which the UI can treat as a new game.
+PlayerData  loadPlayerData() => RustLib.instance.api.crateApiSavesLoadPlayerData();
+
+/// Checkpoints and closes the loaded slot, e.g. when returning to the main
+/// menu. This releases its file handles, which matters on Windows where open
+/// WAL files block deletion. A no-op if nothing is loaded. A transaction
+/// still open on the slot is rolled back.
+void  unloadSlot() => RustLib.instance.api.crateApiSavesUnloadSlot();
+
+/// Opens a transaction on the loaded slot that stays open across calls.
+/// Until `commit_transaction` or `rollback_transaction`, flag writes,
+/// inventory edits and batches join it, reads see only committed data, and
+/// `save_player_data` fails with `TransactionInProgress`. Unloading the slot
+/// rolls back whatever is still uncommitted.
+void  beginTransaction() => RustLib.instance.api.crateApiSavesBeginTransaction();
+
+/// Commits the loaded slot's transaction. Fails with `NoTransaction` if
+/// none is open.
+void  commitTransaction() => RustLib.instance.api.crateApiSavesCommitTransaction();
+
+/// Discards every change made since `begin_transaction`. Fails with
+/// `NoTransaction` if none is open.
+void  rollbackTransaction() => RustLib.instance.api.crateApiSavesRollbackTransaction();
+
+/// The id of the loaded slot, or `None` if nothing is loaded or the system
+/// isn't initialized. Useful for restoring UI state after a hot reload.
+String?  activeSlotId() => RustLib.instance.api.crateApiSavesActiveSlotId();
+
+/// Stores a custom flag (quest state, unlocked levels, ...) in the loaded
+/// slot, replacing any previous value for `key`.
+void  setFlag({required String key , required String value }) => RustLib.instance.api.crateApiSavesSetFlag(key: key, value: value);
+
+String?  getFlag({required String key }) => RustLib.instance.api.crateApiSavesGetFlag(key: key);
+
+/// Stores a stat of any type (a float multiplier, a serialized skill tree,
+/// ...) in the loaded slot under `key`. `health` and `experience` are stats
+/// too, and `set_stat("health", ...)` is the same as editing health, so
+/// those two only accept `Int`. The slot must have been saved once.
+void  setStat({required String key , required StatValue value }) => RustLib.instance.api.crateApiSavesSetStat(key: key, value: value);
+
+/// The stat stored under `key` in the loaded slot, including `health` and
+/// `experience`, or `None` if there is none.
+StatValue?  getStat({required String key }) => RustLib.instance.api.crateApiSavesGetStat(key: key);
+
+/// Every flag in the loaded slot as `(key, value)` pairs, sorted by key.
+List<(String,String)>  getAllFlags() => RustLib.instance.api.crateApiSavesGetAllFlags();
+
+/// Opens a slot independently of the loaded one, e.g. for split-screen or a
+/// comparison view. Pass the returned handle to the `*_for` functions.
+SlotHandle  openSlot({required String slotId }) => RustLib.instance.api.crateApiSavesOpenSlot(slotId: slotId);
+
+void  closeSlot({required SlotHandle handle }) => RustLib.instance.api.crateApiSavesCloseSlot(handle: handle);
+
+SaveResult  savePlayerDataFor({required SlotHandle handle , required PlayerData data }) => RustLib.instance.api.crateApiSavesSavePlayerDataFor(handle: handle, data: data);
+
+PlayerData  loadPlayerDataFor({required SlotHandle handle }) => RustLib.instance.api.crateApiSavesLoadPlayerDataFor(handle: handle);
+
+/// Moves a slot to the trash; `restore_slot` undoes it until `purge_trash`
+/// removes its files. Deleting the loaded slot unloads it.
+void  deleteSlot({required String slotId }) => RustLib.instance.api.crateApiSavesDeleteSlot(slotId: slotId);
+
+/// Slots in the trash, most recently deleted first.
+List<TrashedSlot>  listTrashedSlots() => RustLib.instance.api.crateApiSavesListTrashedSlots();
+
+/// Takes a slot back out of the trash and returns its metadata.
+SaveSlotMetadata  restoreSlot({required String slotId }) => RustLib.instance.api.crateApiSavesRestoreSlot(slotId: slotId);
+
+/// Permanently deletes the files of slots that have been in the trash for
+/// at least `older_than_days` days and returns their ids. Zero empties the
+/// trash.
+List<String>  purgeTrash({required PlatformInt64 olderThanDays }) => RustLib.instance.api.crateApiSavesPurgeTrash(olderThanDays: olderThanDays);
+
+/// Points autosave at `slot_id`, keeping up to `max_backups` rotating backups.
+void  configureAutosave({required String slotId , required int maxBackups }) => RustLib.instance.api.crateApiSavesConfigureAutosave(slotId: slotId, maxBackups: maxBackups);
+
+/// Writes a crash-recovery backup of the configured slot without touching the
+/// slot itself.
+void  autosave({required PlayerData data }) => RustLib.instance.api.crateApiSavesAutosave(data: data);
+
+/// Starts autosaving every `interval_secs` seconds from a Rust thread,
+/// replacing any timer already running. Each tick writes the latest data
+/// passed to `update_autosave_payload` as a backup of the configured slot,
+/// and skips the write if nothing new arrived since the previous tick. Tick
+/// failures show up in `last_error`. Runs until `stop_autosave_timer` or
+/// `close_system`.
+void  startAutosaveTimer({required BigInt intervalSecs }) => RustLib.instance.api.crateApiSavesStartAutosaveTimer(intervalSecs: intervalSecs);
+
+/// Stops the autosave timer, waiting for a tick in progress to finish. Does
+/// nothing if it isn't running.
+void  stopAutosaveTimer() => RustLib.instance.api.crateApiSavesStopAutosaveTimer();
+
+/// Hands the autosave timer the current player data; its next tick saves it.
+void  updateAutosavePayload({required PlayerData data }) => RustLib.instance.api.crateApiSavesUpdateAutosavePayload(data: data);
+
+/// Prunes autosaves by count and age every time one is written: at most
+/// `max_count` are kept, and ones older than `max_age_days` days are
+/// removed, except the newest. 0 turns either limit off.
+void  setAutosaveRetention({required int maxCount , required int maxAgeDays }) => RustLib.instance.api.crateApiSavesSetAutosaveRetention(maxCount: maxCount, maxAgeDays: maxAgeDays);
+
+/// How many autosaves `slot_id` has and their total size on disk.
+AutosaveUsage  autosaveUsage({required String slotId }) => RustLib.instance.api.crateApiSavesAutosaveUsage(slotId: slotId);
+
+List<AutosaveInfo>  listAutosaves({required String slotId }) => RustLib.instance.api.crateApiSavesListAutosaves(slotId: slotId);
+
+/// Promotes backup `index` back to being the slot's main save.
+void  restoreAutosave({required String slotId , required int index }) => RustLib.instance.api.crateApiSavesRestoreAutosave(slotId: slotId, index: index);
+
+/// Saves a labelled copy of the loaded slot, e.g. "before the boss", and
+/// returns its id. Checkpoints are kept until the slot is permanently
+/// deleted.
+String  createCheckpoint({required String label }) => RustLib.instance.api.crateApiSavesCreateCheckpoint(label: label);
+
+/// A slot's checkpoints, newest first.
+List<CheckpointInfo>  listCheckpoints({required String slotId }) => RustLib.instance.api.crateApiSavesListCheckpoints(slotId: slotId);
+
+/// Puts a checkpoint back as its slot's save. If the slot is loaded it is
+/// reloaded from the checkpoint.
+void  restoreCheckpoint({required String checkpointId }) => RustLib.instance.api.crateApiSavesRestoreCheckpoint(checkpointId: checkpointId);
+
+/// Serializes a slot to a versioned JSON document for sharing or backup.
+/// Works on any slot without disturbing the loaded one.
+String  exportSlotToJson({required String slotId }) => RustLib.instance.api.crateApiSavesExportSlotToJson(slotId: slotId);
+
+/// Creates a new slot from a document produced by `export_slot_to_json`.
+SaveSlotMetadata  importSlotFromJson({required String json , required String displayName }) => RustLib.instance.api.crateApiSavesImportSlotFromJson(json: json, displayName: displayName);
+
+/// Reports what `import_slot_from_json` would import from `json`, for a
+/// confirmation dialog, without creating a slot. Fails with the same errors
+/// the import would.
+ImportPreview  inspectImport({required String json }) => RustLib.instance.api.crateApiSavesInspectImport(json: json);
+
+/// Imports every `.json` export directly inside `path` as a new slot named
+/// after its file, streaming one `ImportProgress` per file for a progress
+/// bar. Files that fail are reported in the stream and skipped; the call
+/// itself only fails if the directory can't be read. The stream closes once
+/// every file has been handled.
+///
+/// Not `frb(sync)`, so a large directory doesn't block the isolate; like
+/// `save_player_data_async`, the pool thread just waits on the save worker.
+Stream<ImportProgress>  importDirectory({required String path }) => RustLib.instance.api.crateApiSavesImportDirectory(path: path);
+
+/// Backs up the whole store to a tar archive at `path`: the metadata
+/// database and every slot outside the trash, each checkpointed first.
+/// Checkpoints and autosaves aren't included. Streams one
+/// `ArchiveProgress` per file written; the stream closes when the archive is
+/// complete.
+Stream<ArchiveProgress>  exportAll({required String path }) => RustLib.instance.api.crateApiSavesExportAll(path: path);
+
+/// Restores slots from an `export_all` archive. With `merge` they join the
+/// existing slots under new ids; otherwise every current slot is deleted
+/// first and the archive's slots and settings replace them. The archive is
+/// checked in full before anything is changed, and must have been written
+/// with the same encryption key. Streams one `ArchiveProgress` per slot
+/// restored; the stream closes once the import is done. The last event's
+/// `total` is how many slots were imported, and an archive without slots
+/// closes the stream without any.
+Stream<ArchiveProgress>  importAll({required String path , required bool merge }) => RustLib.instance.api.crateApiSavesImportAll(path: path, merge: merge);
+
+/// Serializes a slot to a compact, versioned binary format. Much smaller
+/// than JSON for large inventories.
+Uint8List  exportSlotBinary({required String slotId }) => RustLib.instance.api.crateApiSavesExportSlotBinary(slotId: slotId);
+
+/// Creates a new slot from bytes produced by `export_slot_binary`. Foreign
+/// or newer files are rejected with `UnsupportedFormat`.
+SaveSlotMetadata  importSlotBinary({required List<int> bytes , required String displayName }) => RustLib.instance.api.crateApiSavesImportSlotBinary(bytes: bytes, displayName: displayName);
+
+/// Exports a slot as JSON or binary and gzips it, for cloud backups with a
+/// quota.
+Uint8List  exportSlotCompressed({required String slotId , required ExportFormat format }) => RustLib.instance.api.crateApiSavesExportSlotCompressed(slotId: slotId, format: format);
+
+/// Creates a new slot from any export: JSON or binary, gzipped or not. The
+/// result reports which one it was.
+ImportedSlot  importSlot({required List<int> bytes , required String displayName }) => RustLib.instance.api.crateApiSavesImportSlot(bytes: bytes, displayName: displayName);
+
+            /// Totals over every slot, from `aggregate_stats`.
+class AggregateStats  {
+                /// Live slots, whether or not they were ever saved.
+final BigInt slotCount;
+/// Slots that hold player data and went into the totals below.
+final BigInt savedSlotCount;
+final int highestExperience;
+final int highestHealth;
+/// Inventory items summed over every slot.
+final BigInt totalItems;
+final PlatformInt64 totalPlayTimeSeconds;
+/// Slots that couldn't be read and were left out.
+final List<SkippedSlot> skipped;
+
+                const AggregateStats({required this.slotCount ,required this.savedSlotCount ,required this.highestExperience ,required this.highestHealth ,required this.totalItems ,required this.totalPlayTimeSeconds ,required this.skipped ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => slotCount.hashCode^savedSlotCount.hashCode^highestExperience.hashCode^highestHealth.hashCode^totalItems.hashCode^totalPlayTimeSeconds.hashCode^skipped.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AggregateStats &&
+                runtimeType == other.runtimeType
+                && slotCount == other.slotCount&& savedSlotCount == other.savedSlotCount&& highestExperience == other.highestExperience&& highestHealth == other.highestHealth&& totalItems == other.totalItems&& totalPlayTimeSeconds == other.totalPlayTimeSeconds&& skipped == other.skipped;
+        
+            }
+
+/// One file written by `export_all` or slot restored by `import_all`.
+class ArchiveProgress  {
+                /// 1-based position of this file among the `total` being handled.
+final BigInt current;
+final BigInt total;
+/// The file's name inside the archive.
+final String fileName;
+
+                const ArchiveProgress({required this.current ,required this.total ,required this.fileName ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => current.hashCode^total.hashCode^fileName.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ArchiveProgress &&
+                runtimeType == other.runtimeType
+                && current == other.current&& total == other.total&& fileName == other.fileName;
+        
+            }
+
+/// One rotating backup of a slot; index 0 is the most recent.
+class AutosaveInfo  {
+                final int index;
+final String savedAt;
+final BigInt sizeBytes;
+final String filePath;
+
+                const AutosaveInfo({required this.index ,required this.savedAt ,required this.sizeBytes ,required this.filePath ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => index.hashCode^savedAt.hashCode^sizeBytes.hashCode^filePath.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AutosaveInfo &&
+                runtimeType == other.runtimeType
+                && index == other.index&& savedAt == other.savedAt&& sizeBytes == other.sizeBytes&& filePath == other.filePath;
+        
+            }
+
+/// How much space a slot's autosave backups take up.
+class AutosaveUsage  {
+                final int count;
+/// Including the backups' `-wal` and `-shm` files.
+final BigInt totalBytes;
+
+                const AutosaveUsage({required this.count ,required this.totalBytes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => count.hashCode^totalBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AutosaveUsage &&
+                runtimeType == other.runtimeType
+                && count == other.count&& totalBytes == other.totalBytes;
+        
+            }
+
+/// One entry in the change journal read by `changes_since`.
+class ChangeEntry  {
+                /// Increases with every entry, so it also orders entries that share a
+/// timestamp.
+final PlatformInt64 sequence;
+/// When the change was committed, in Unix epoch milliseconds.
+final PlatformInt64 timestampMs;
+final String slotId;
+final SlotEventKind operation;
+/// The slot's revision after a save; `None` for other operations.
+final PlatformInt64? revision;
+
+                const ChangeEntry({required this.sequence ,required this.timestampMs ,required this.slotId ,required this.operation ,this.revision ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sequence.hashCode^timestampMs.hashCode^slotId.hashCode^operation.hashCode^revision.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ChangeEntry &&
+                runtimeType == other.runtimeType
+                && sequence == other.sequence&& timestampMs == other.timestampMs&& slotId == other.slotId&& operation == other.operation&& revision == other.revision;
+        
+            }
+
+/// A labelled save point taken with `create_checkpoint`.
+class CheckpointInfo  {
+                final String id;
+final String slotId;
+final String label;
+final String createdAt;
+final BigInt sizeBytes;
+final String filePath;
+
+                const CheckpointInfo({required this.id ,required this.slotId ,required this.label ,required this.createdAt ,required this.sizeBytes ,required this.filePath ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^slotId.hashCode^label.hashCode^createdAt.hashCode^sizeBytes.hashCode^filePath.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CheckpointInfo &&
+                runtimeType == other.runtimeType
+                && id == other.id&& slotId == other.slotId&& label == other.label&& createdAt == other.createdAt&& sizeBytes == other.sizeBytes&& filePath == other.filePath;
+        
+            }
+
+/// Encodings produced by the export functions.
+enum ExportFormat {
+                    json,
+binary,
+                    ;
+                    
+                }
+
+/// What a JSON export holds, as reported by `inspect_import`.
+class ImportPreview  {
+                final PlatformInt64 schemaVersion;
+final int health;
+final int experience;
+final BigInt itemCount;
+
+                const ImportPreview({required this.schemaVersion ,required this.health ,required this.experience ,required this.itemCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => schemaVersion.hashCode^health.hashCode^experience.hashCode^itemCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ImportPreview &&
+                runtimeType == other.runtimeType
+                && schemaVersion == other.schemaVersion&& health == other.health&& experience == other.experience&& itemCount == other.itemCount;
+        
+            }
+
+/// One file finished by `import_directory`.
+class ImportProgress  {
+                /// 1-based position of this file among the `total` being imported.
+final BigInt current;
+final BigInt total;
+final String fileName;
+/// The new slot, or `None` if this file failed.
+final String? slotId;
+final String? error;
+
+                const ImportProgress({required this.current ,required this.total ,required this.fileName ,this.slotId ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => current.hashCode^total.hashCode^fileName.hashCode^slotId.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ImportProgress &&
+                runtimeType == other.runtimeType
+                && current == other.current&& total == other.total&& fileName == other.fileName&& slotId == other.slotId&& error == other.error;
+        
+            }
+
+/// A slot created by `import_slot`, and what the import turned out to be.
+class ImportedSlot  {
+                final SaveSlotMetadata slot;
+final ExportFormat format;
+final bool compressed;
+
+                const ImportedSlot({required this.slot ,required this.format ,required this.compressed ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => slot.hashCode^format.hashCode^compressed.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ImportedSlot &&
+                runtimeType == other.runtimeType
+                && slot == other.slot&& format == other.format&& compressed == other.compressed;
+        
+            }
+
+/// Where the saves directory and the slot metadata disagree, as found by
+/// `scan_integrity`.
+class IntegrityReport  {
+                /// Slot database files that no slot row points at.
+final List<String> orphanedFiles;
+/// Ids of slot rows whose database file is missing.
+final List<String> danglingRows;
+
+                const IntegrityReport({required this.orphanedFiles ,required this.danglingRows ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orphanedFiles.hashCode^danglingRows.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IntegrityReport &&
+                runtimeType == other.runtimeType
+                && orphanedFiles == other.orphanedFiles&& danglingRows == other.danglingRows;
+        
+            }
+
+enum ItemChange {
+                    /// Only slot `b` has the item.
+added,
+/// Only slot `a` has the item.
+removed,
+/// Both have it, in different numbers.
+quantityChanged,
+                    ;
+                    
+                }
+
+class ItemDiff  {
+                final String item;
+final ItemChange change;
+final int countA;
+final int countB;
+
+                const ItemDiff({required this.item ,required this.change ,required this.countA ,required this.countB ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => item.hashCode^change.hashCode^countA.hashCode^countB.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ItemDiff &&
+                runtimeType == other.runtimeType
+                && item == other.item&& change == other.change&& countA == other.countA&& countB == other.countB;
+        
+            }
+
+/// What `rename_item_everywhere` changed.
+class ItemRenameResult  {
+                /// Inventory entries renamed, across all slots.
+final BigInt rowsChanged;
+/// Slots that couldn't be updated; the others were.
+final List<SkippedSlot> skipped;
+
+                const ItemRenameResult({required this.rowsChanged ,required this.skipped ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => rowsChanged.hashCode^skipped.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ItemRenameResult &&
+                runtimeType == other.runtimeType
+                && rowsChanged == other.rowsChanged&& skipped == other.skipped;
+        
+            }
+
+class MaintenanceReport  {
+                final List<SlotMaintenance> slots;
+/// Sum over all slots.
+final BigInt bytesReclaimed;
+
+                const MaintenanceReport({required this.slots ,required this.bytesReclaimed ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => slots.hashCode^bytesReclaimed.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MaintenanceReport &&
+                runtimeType == other.runtimeType
+                && slots == other.slots&& bytesReclaimed == other.bytesReclaimed;
+        
+            }
+
+/// How `merge_inventories` adds one slot's items to another's.
+enum MergeStrategy {
+                    /// Every incoming item goes on the end, in its original order.
+append,
+/// Incoming items the inventory already holds are dropped, as are
+/// repeats among the incoming ones.
+dedupeByItem,
+/// Every incoming item is kept, next to the existing copies of the same
+/// item so each item's count simply adds up; new items go on the end.
+sumQuantities,
+                    ;
+                    
+                }
+
+/// A copy of a slot taken before a schema upgrade that didn't complete.
+class MigrationBackupInfo  {
+                final String slotId;
+final String savedAt;
+final BigInt sizeBytes;
+final String filePath;
+
+                const MigrationBackupInfo({required this.slotId ,required this.savedAt ,required this.sizeBytes ,required this.filePath ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => slotId.hashCode^savedAt.hashCode^sizeBytes.hashCode^filePath.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MigrationBackupInfo &&
+                runtimeType == other.runtimeType
+                && slotId == other.slotId&& savedAt == other.savedAt&& sizeBytes == other.sizeBytes&& filePath == other.filePath;
+        
+            }
+
+class PlayerData  {
+                final int health;
+final int experience;
+final List<String> inventory;
+
+                const PlayerData({required this.health ,required this.experience ,required this.inventory ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => health.hashCode^experience.hashCode^inventory.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PlayerData &&
+                runtimeType == other.runtimeType
+                && health == other.health&& experience == other.experience&& inventory == other.inventory;
+        
+            }
+
+/// What `repair_integrity` does with orphaned files. Dangling rows are always
+/// removed.
+enum RepairPolicy {
+                    deleteOrphans,
+/// Adds each readable orphan back to the slot list as a new slot.
+registerOrphans,
+                    ;
+                    
+                }
+
+class RepairReport  {
+                final BigInt filesDeleted;
+final BigInt filesRegistered;
+final BigInt rowsRemoved;
+
+                const RepairReport({required this.filesDeleted ,required this.filesRegistered ,required this.rowsRemoved ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => filesDeleted.hashCode^filesRegistered.hashCode^rowsRemoved.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RepairReport &&
+                runtimeType == other.runtimeType
+                && filesDeleted == other.filesDeleted&& filesRegistered == other.filesRegistered&& rowsRemoved == other.rowsRemoved;
+        
+            }
+
+@freezed
+                sealed class SaveError with _$SaveError implements FrbException {
+                    const SaveError._();
+
+                     const factory SaveError.notInitialized() = SaveError_NotInitialized;
+ const factory SaveError.alreadyInitialized({   required String basePath , }) = SaveError_AlreadyInitialized;
+ const factory SaveError.workerUnavailable() = SaveError_WorkerUnavailable;
+ const factory SaveError.operationPanicked() = SaveError_OperationPanicked;
+ const factory SaveError.noActiveSlot() = SaveError_NoActiveSlot;
+ const factory SaveError.unknownHandle({   required int handle , }) = SaveError_UnknownHandle;
+ const factory SaveError.slotNotFound({   required String id , }) = SaveError_SlotNotFound;
+ const factory SaveError.invalidSlotName({   required String reason , }) = SaveError_InvalidSlotName;
+ const factory SaveError.corruptSlot({   required String id , }) = SaveError_CorruptSlot;
+ const factory SaveError.invalidTag({   required String reason , }) = SaveError_InvalidTag;
+ const factory SaveError.noPlayerData() = SaveError_NoPlayerData;
+ const factory SaveError.invalidPlayerData({   required String field ,  required String reason , }) = SaveError_InvalidPlayerData;
+ const factory SaveError.unsupportedFormat({   required String reason , }) = SaveError_UnsupportedFormat;
+ const factory SaveError.thumbnailTooLarge({   required BigInt size ,  required BigInt max , }) = SaveError_ThumbnailTooLarge;
+ const factory SaveError.autosaveNotConfigured() = SaveError_AutosaveNotConfigured;
+ const factory SaveError.autosaveNotFound({   required String slotId ,  required int index , }) = SaveError_AutosaveNotFound;
+ const factory SaveError.checkpointNotFound({   required String id , }) = SaveError_CheckpointNotFound;
+ const factory SaveError.slotInUse({   required String id , }) = SaveError_SlotInUse;
+ const factory SaveError.decryptionFailed() = SaveError_DecryptionFailed;
+ const factory SaveError.invalidConfig({   required String reason , }) = SaveError_InvalidConfig;
+ const factory SaveError.checksumMismatch({   required String id , }) = SaveError_ChecksumMismatch;
+ const factory SaveError.revisionConflict({   required PlatformInt64 current , }) = SaveError_RevisionConflict;
+ const factory SaveError.slotLocked({   required String id , }) = SaveError_SlotLocked;
+ const factory SaveError.slotBusy({   required String id , }) = SaveError_SlotBusy;
+ const factory SaveError.diskFull() = SaveError_DiskFull;
+ const factory SaveError.transactionInProgress({   required String id , }) = SaveError_TransactionInProgress;
+ const factory SaveError.noTransaction() = SaveError_NoTransaction;
+ const factory SaveError.nothingToUndo() = SaveError_NothingToUndo;
+ const factory SaveError.schemaMismatch({   required List<String> missing , }) = SaveError_SchemaMismatch;
+ const factory SaveError.io({   required String message , }) = SaveError_Io;
+ const factory SaveError.database({   required String message , }) = SaveError_Database;
+ const factory SaveError.migration({   required String message , }) = SaveError_Migration;
+
+                    
+
+                    
+                }
+
+@freezed
+                sealed class SaveOp with _$SaveOp  {
+                    const SaveOp._();
+
+                     const factory SaveOp.setStats({   required int health ,  required int experience , }) = SaveOp_SetStats;
+ /// Appends `quantity` copies of `item` to the inventory.
+const factory SaveOp.addItem({   required String item ,  required int quantity , }) = SaveOp_AddItem;
+ /// Removes the item at `position`; later items move up one.
+const factory SaveOp.removeItem({   required PlatformInt64 position , }) = SaveOp_RemoveItem;
+ const factory SaveOp.setFlag({   required String key ,  required String value , }) = SaveOp_SetFlag;
+
+                    
+
+                    
+                }
+
+/// What a save actually wrote, so callers can assert the data landed.
+class SaveResult  {
+                final bool statsUpdated;
+/// Inventory rows written; equals the inventory length on success.
+final BigInt inventoryRows;
+/// The slot's revision after this save, to pass to the next
+/// `save_player_data_checked`.
+final PlatformInt64 revision;
+
+                const SaveResult({required this.statsUpdated ,required this.inventoryRows ,required this.revision ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => statsUpdated.hashCode^inventoryRows.hashCode^revision.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SaveResult &&
+                runtimeType == other.runtimeType
+                && statsUpdated == other.statsUpdated&& inventoryRows == other.inventoryRows&& revision == other.revision;
+        
+            }
+
+class SaveSlotMetadata  {
+                final String id;
+final String name;
+/// `%Y-%m-%d %H:%M:%S` in UTC.
+final String lastPlayed;
+/// The same moment as Unix epoch milliseconds, which Dart can turn into a
+/// `DateTime` without parsing.
+final PlatformInt64 lastPlayedEpochMs;
+final String createdAt;
+/// `created_at` as Unix epoch milliseconds, or 0 if the stored value
+/// isn't a valid timestamp.
+final PlatformInt64 createdAtEpochMs;
+final String filePath;
+final PlatformInt64 playTimeSeconds;
+/// Player-written notes, empty if none were left.
+final String description;
+/// Filled in by `get_slot` only; list queries leave it empty to stay
+/// cheap.
+final List<String> tags;
+
+                const SaveSlotMetadata({required this.id ,required this.name ,required this.lastPlayed ,required this.lastPlayedEpochMs ,required this.createdAt ,required this.createdAtEpochMs ,required this.filePath ,required this.playTimeSeconds ,required this.description ,required this.tags ,});
+
+                /// `last_played` as ISO 8601 in UTC with millisecond precision, e.g.
+/// `2024-05-01T18:30:00.250Z`, for display.
+ String  lastPlayedIso8601()=>RustLib.instance.api.crateApiSavesSaveSlotMetadataLastPlayedIso8601(that: this, );
+
+
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode^lastPlayed.hashCode^lastPlayedEpochMs.hashCode^createdAt.hashCode^createdAtEpochMs.hashCode^filePath.hashCode^playTimeSeconds.hashCode^description.hashCode^tags.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SaveSlotMetadata &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name&& lastPlayed == other.lastPlayed&& lastPlayedEpochMs == other.lastPlayedEpochMs&& createdAt == other.createdAt&& createdAtEpochMs == other.createdAtEpochMs&& filePath == other.filePath&& playTimeSeconds == other.playTimeSeconds&& description == other.description&& tags == other.tags;
+        
+            }
+
+/// The build's schema versions, from `schema_info`.
+class SchemaInfo  {
+                /// The version slots are migrated to when loaded.
+final BigInt slotSchemaVersion;
+final BigInt metadataSchemaVersion;
+final String crateVersion;
+
+                const SchemaInfo({required this.slotSchemaVersion ,required this.metadataSchemaVersion ,required this.crateVersion ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => slotSchemaVersion.hashCode^metadataSchemaVersion.hashCode^crateVersion.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SchemaInfo &&
+                runtimeType == other.runtimeType
+                && slotSchemaVersion == other.slotSchemaVersion&& metadataSchemaVersion == other.metadataSchemaVersion&& crateVersion == other.crateVersion;
+        
+            }
+
+/// A slot an all-slots scan had to leave out, and why.
+class SkippedSlot  {
+                final String slotId;
+final String error;
+
+                const SkippedSlot({required this.slotId ,required this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => slotId.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SkippedSlot &&
+                runtimeType == other.runtimeType
+                && slotId == other.slotId&& error == other.error;
+        
+            }
+
+/// How two slots differ, from `diff_slots`. Stats are `None` for a slot that
+/// was never saved.
+class SlotDiff  {
+                final int? healthA;
+final int? healthB;
+final int? experienceA;
+final int? experienceB;
+/// Only items whose count differs, sorted by name.
+final List<ItemDiff> items;
+
+                const SlotDiff({this.healthA ,this.healthB ,this.experienceA ,this.experienceB ,required this.items ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => healthA.hashCode^healthB.hashCode^experienceA.hashCode^experienceB.hashCode^items.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SlotDiff &&
+                runtimeType == other.runtimeType
+                && healthA == other.healthA&& healthB == other.healthB&& experienceA == other.experienceA&& experienceB == other.experienceB&& items == other.items;
+        
+            }
+
+class SlotEvent  {
+                final String slotId;
+final SlotEventKind kind;
+
+                const SlotEvent({required this.slotId ,required this.kind ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => slotId.hashCode^kind.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SlotEvent &&
+                runtimeType == other.runtimeType
+                && slotId == other.slotId&& kind == other.kind;
+        
+            }
+
+/// What happened to a slot, as reported to slot event subscribers.
+enum SlotEventKind {
+                    created,
+renamed,
+deleted,
+/// Player data was written to the slot.
+saved,
+/// A trashed slot was brought back by `restore_slot`.
+restored,
+/// A save to the slot is about to run while free disk space is below the
+/// low disk space threshold. Only emitted, never journaled.
+lowDiskSpace,
+                    ;
+                    
+                }
+
+/// Opaque reference to a slot opened with `open_slot`.
+class SlotHandle  {
+                final int value;
+
+                const SlotHandle({required this.value ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => value.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SlotHandle &&
+                runtimeType == other.runtimeType
+                && value == other.value;
+        
+            }
+
+/// What `run_maintenance` did to one slot.
+class SlotMaintenance  {
+                final String slotId;
+final BigInt bytesReclaimed;
+final bool vacuumed;
+/// Why the slot was skipped, e.g. because it is corrupt.
+final String? error;
+
+                const SlotMaintenance({required this.slotId ,required this.bytesReclaimed ,required this.vacuumed ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => slotId.hashCode^bytesReclaimed.hashCode^vacuumed.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SlotMaintenance &&
+                runtimeType == other.runtimeType
+                && slotId == other.slotId&& bytesReclaimed == other.bytesReclaimed&& vacuumed == other.vacuumed&& error == other.error;
+        
+            }
+
+/// Sort orders accepted by `get_slots_page`.
+enum SlotOrder {
+                    lastPlayedDesc,
+lastPlayedAsc,
+nameAsc,
+nameDesc,
+createdDesc,
+createdAsc,
+                    ;
+                    
+                }
+
+/// A slot's headline numbers from `preview_slot`. Stats are `None` for a slot
+/// that was never saved.
+class SlotPreview  {
+                final int? health;
+final int? experience;
+final BigInt itemCount;
+
+                const SlotPreview({this.health ,this.experience ,required this.itemCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => health.hashCode^experience.hashCode^itemCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SlotPreview &&
+                runtimeType == other.runtimeType
+                && health == other.health&& experience == other.experience&& itemCount == other.itemCount;
+        
+            }
+
+@freezed
+                sealed class StatValue with _$StatValue  {
+                    const StatValue._();
+
+                     const factory StatValue.int(  PlatformInt64 field0,) = StatValue_Int;
+ const factory StatValue.real(  double field0,) = StatValue_Real;
+ const factory StatValue.text(  String field0,) = StatValue_Text;
+ const factory StatValue.blob(  Uint8List field0,) = StatValue_Blob;
+
+                    
+
+                    
+                }
+
+/// The store's state for a diagnostics panel, from `store_health`. Values
+/// that couldn't be read, or that need an initialized system, are `None`.
+class StoreHealth  {
+                final bool initialized;
+/// The loaded slot, if any.
+final String? activeSlotId;
+final String? savesDirectory;
+/// Space left for saves on their filesystem.
+final BigInt? freeDiskBytes;
+/// Slots outside the trash.
+final BigInt? slotCount;
+
+                const StoreHealth({required this.initialized ,this.activeSlotId ,this.savesDirectory ,this.freeDiskBytes ,this.slotCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => initialized.hashCode^activeSlotId.hashCode^savesDirectory.hashCode^freeDiskBytes.hashCode^slotCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StoreHealth &&
+                runtimeType == other.runtimeType
+                && initialized == other.initialized&& activeSlotId == other.activeSlotId&& savesDirectory == other.savesDirectory&& freeDiskBytes == other.freeDiskBytes&& slotCount == other.slotCount;
+        
+            }
+
+/// A slot in the trash, and when it was put there.
+class TrashedSlot  {
+                final SaveSlotMetadata slot;
+final String deletedAt;
+
+                const TrashedSlot({required this.slot ,required this.deletedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => slot.hashCode^deletedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TrashedSlot &&
+                runtimeType == other.runtimeType
+                && slot == other.slot&& deletedAt == other.deletedAt;
+        
+            }
+            
\ No newline at end of file
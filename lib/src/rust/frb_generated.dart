@@ -3,240 +3,5148 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/leveling.dart';
+import 'api/saves.dart';
 import 'api/simple.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiSimpleInitApp();
-  }
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
-
-  @override
-  int get rustContentHash => -1918914929;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'rust_lib_my_app',
-        ioDirectory: 'rust/target/release/',
-        webPrefix: 'pkg/',
-      );
-}
-
-abstract class RustLibApi extends BaseApi {
-  String crateApiSimpleGreet({required String name});
-
-  Future<void> crateApiSimpleInitApp();
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiSimpleInitApp();
+
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.11.1';
+
+                  @override
+                  int get rustContentHash => 767941153;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib_my_app',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  String? crateApiSavesActiveSlotId();
+
+void crateApiSavesAddInventoryItem({required String item , required int quantity });
+
+void crateApiSavesAddPlayTime({required String slotId , required PlatformInt64 seconds });
+
+void crateApiSavesAddSlotTag({required String slotId , required String tag });
+
+AggregateStats crateApiSavesAggregateStats();
+
+void crateApiSavesApplyBatch({required List<SaveOp> ops });
+
+void crateApiSavesAutosave({required PlayerData data });
+
+AutosaveUsage crateApiSavesAutosaveUsage({required String slotId });
+
+void crateApiSavesBeginTransaction();
+
+List<ChangeEntry> crateApiSavesChangesSince({required PlatformInt64 timestampMs });
+
+void crateApiSavesCheckpointActiveSlot();
+
+void crateApiSavesCloseSlot({required SlotHandle handle });
+
+void crateApiSavesCloseSystem();
+
+void crateApiSavesCommitTransaction();
+
+void crateApiSavesConfigureAutosave({required String slotId , required int maxBackups });
+
+BigInt crateApiSavesCountSlots();
+
+String crateApiSavesCreateCheckpoint({required String label });
+
+SaveSlotMetadata crateApiSavesCreateNewSlot({required String displayName });
+
+SaveSlotMetadata crateApiSavesCreateSlotWithData({required String displayName , required PlayerData data });
+
+void crateApiSavesDeleteAllSlots();
+
+void crateApiSavesDeleteSlot({required String slotId });
+
+List<String> crateApiSavesDeleteSlots({required List<String> slotIds });
+
+SlotDiff crateApiSavesDiffSlots({required String slotA , required String slotB });
+
+SaveSlotMetadata crateApiSavesDuplicateSlot({required String slotId , required String newName });
+
+Stream<ArchiveProgress> crateApiSavesExportAll({required String path });
+
+Uint8List crateApiSavesExportSlotBinary({required String slotId });
+
+Uint8List crateApiSavesExportSlotCompressed({required String slotId , required ExportFormat format });
+
+String crateApiSavesExportSlotToJson({required String slotId });
+
+void crateApiSavesForceInitSystem({required String basePath });
+
+List<(String,String)> crateApiSavesGetAllFlags();
+
+List<(String,String)> crateApiSavesGetAllSettings();
+
+List<SaveSlotMetadata> crateApiSavesGetAllSlots();
+
+String? crateApiSavesGetFlag({required String key });
+
+String? crateApiSavesGetSetting({required String key });
+
+SaveSlotMetadata crateApiSavesGetSlot({required String slotId });
+
+Uint8List? crateApiSavesGetSlotThumbnail({required String slotId });
+
+List<SaveSlotMetadata> crateApiSavesGetSlotsByTag({required String tag });
+
+List<SaveSlotMetadata> crateApiSavesGetSlotsPage({required int offset , required int limit , required SlotOrder order });
+
+StatValue? crateApiSavesGetStat({required String key });
+
+String crateApiSimpleGreet({required String name });
+
+Stream<ArchiveProgress> crateApiSavesImportAll({required String path , required bool merge });
+
+Stream<ImportProgress> crateApiSavesImportDirectory({required String path });
+
+ImportedSlot crateApiSavesImportSlot({required List<int> bytes , required String displayName });
+
+SaveSlotMetadata crateApiSavesImportSlotBinary({required List<int> bytes , required String displayName });
+
+SaveSlotMetadata crateApiSavesImportSlotFromJson({required String json , required String displayName });
+
+Future<void> crateApiSimpleInitApp();
+
+void crateApiSavesInitSystem({required String basePath });
+
+void crateApiSavesInitSystemEncrypted({required String basePath , required String key });
+
+void crateApiSavesInitSystemForProfile({required String basePath , required String profile });
+
+void crateApiSavesInitSystemWithConfig({required String basePath , required String appName , required String savesSubdir });
+
+ImportPreview crateApiSavesInspectImport({required String json });
+
+bool crateApiSavesIsInitialized();
+
+String? crateApiSavesLastError();
+
+BigInt crateApiSavesLatestSchemaVersion();
+
+int crateApiLevelingLevelForExperience({required int experience });
+
+List<AutosaveInfo> crateApiSavesListAutosaves({required String slotId });
+
+List<CheckpointInfo> crateApiSavesListCheckpoints({required String slotId });
+
+List<MigrationBackupInfo> crateApiSavesListMigrationBackups();
+
+List<String> crateApiSavesListProfiles({required String basePath });
+
+List<TrashedSlot> crateApiSavesListTrashedSlots();
+
+List<String> crateApiSavesLoadInventory();
+
+PlayerData crateApiSavesLoadPlayerData();
+
+PlayerData crateApiSavesLoadPlayerDataFor({required SlotHandle handle });
+
+(int,int) crateApiSavesLoadPlayerStats();
+
+void crateApiSavesLoadSlot({required String slotId });
+
+BigInt crateApiSavesMergeInventories({required String into , required String from , required MergeStrategy strategy });
+
+String crateApiSavesMetadataDbPath();
+
+void crateApiSavesMigrateSlotToVersion({required String slotId , required BigInt target });
+
+void crateApiSavesMoveSlot({required String slotId , required String newDir });
+
+SlotHandle crateApiSavesOpenSlot({required String slotId });
+
+PlatformInt64 crateApiSavesPlayerDataRevision();
+
+SlotPreview crateApiSavesPreviewSlot({required String slotId });
+
+BigInt crateApiSavesPruneChangeLog({required PlatformInt64 timestampMs });
+
+List<String> crateApiSavesPurgeTrash({required PlatformInt64 olderThanDays });
+
+void crateApiSavesRecoverSlotLocks({required String slotId });
+
+void crateApiSavesRegisterSlotMigrations({required List<(String,String?)> migrations });
+
+void crateApiSavesRemoveInventoryItem({required PlatformInt64 position });
+
+void crateApiSavesRemoveSlotTag({required String slotId , required String tag });
+
+ItemRenameResult crateApiSavesRenameItemEverywhere({required String old , required String new_ });
+
+SaveSlotMetadata crateApiSavesRenameSlot({required String slotId , required String newName });
+
+RepairReport crateApiSavesRepairIntegrity({required RepairPolicy policy });
+
+void crateApiSavesRestoreAutosave({required String slotId , required int index });
+
+void crateApiSavesRestoreCheckpoint({required String checkpointId });
+
+SaveSlotMetadata crateApiSavesRestoreSlot({required String slotId });
+
+void crateApiSavesRollbackTransaction();
+
+MaintenanceReport crateApiSavesRunMaintenance({BigInt? vacuumAboveBytes });
+
+SaveResult crateApiSavesSavePlayerData({required PlayerData data });
+
+Future<SaveResult> crateApiSavesSavePlayerDataAsync({required PlayerData data });
+
+SaveResult crateApiSavesSavePlayerDataChecked({required PlayerData data , required PlatformInt64 expectedRevision });
+
+SaveResult crateApiSavesSavePlayerDataFor({required SlotHandle handle , required PlayerData data });
+
+SaveResult crateApiSavesSavePlayerDataWithThumbnail({required PlayerData data , required List<int> pngBytes });
+
+String crateApiSavesSaveSlotMetadataLastPlayedIso8601({required SaveSlotMetadata that });
+
+String crateApiSavesSavesDirectory();
+
+IntegrityReport crateApiSavesScanIntegrity();
+
+SchemaInfo crateApiSavesSchemaInfo();
+
+List<SaveSlotMetadata> crateApiSavesSearchSlots({required String query });
+
+void crateApiSavesSetAppSaveVersion({required int version });
+
+void crateApiSavesSetAutosaveRetention({required int maxCount , required int maxAgeDays });
+
+void crateApiSavesSetBusyRetries({required int retries });
+
+void crateApiSavesSetFlag({required String key , required String value });
+
+void crateApiSavesSetIntegrityCheckEnabled({required bool enabled });
+
+void crateApiSavesSetInventoryItem({required PlatformInt64 position , required String item });
+
+void crateApiSavesSetKeepMetadataConnection({required bool enabled });
+
+void crateApiLevelingSetLevelCurve({required List<int> thresholds });
+
+void crateApiSavesSetLowDiskSpaceThreshold({required BigInt bytes });
+
+void crateApiSavesSetMaxSlotNameLength({required int maxLength });
+
+void crateApiSavesSetNewGamePlusHealth({required int health });
+
+void crateApiSavesSetSetting({required String key , required String value });
+
+void crateApiSavesSetSlotDescription({required String slotId , required String text });
+
+void crateApiSavesSetSlotThumbnail({required String slotId , required List<int> pngBytes });
+
+void crateApiSavesSetSqliteOptions({required int busyTimeoutMs , required String synchronous });
+
+void crateApiSavesSetStat({required String key , required StatValue value });
+
+void crateApiSavesSetValidationLimits({required int maxInventory , required int maxHealth });
+
+void crateApiSavesSetWalAutocheckpoint({required int pages });
+
+int crateApiSavesSlotAppVersion({required String slotId });
+
+BigInt crateApiSavesSlotDiskUsage({required String slotId });
+
+bool crateApiSavesSlotExists({required String slotId });
+
+BigInt crateApiSavesSlotSchemaVersion({required String slotId });
+
+void crateApiSavesStartAutosaveTimer({required BigInt intervalSecs });
+
+SaveSlotMetadata crateApiSavesStartNewGamePlus({required String slotId , required bool keepInventory , required double experienceCarryoverPct });
+
+void crateApiSavesStopAutosaveTimer();
+
+StoreHealth crateApiSavesStoreHealth();
+
+Stream<SlotEvent> crateApiSavesSubscribeSlotEvents();
+
+void crateApiSavesSwitchProfile({required String profile });
+
+BigInt crateApiSavesTotalSavesDiskUsage();
+
+List<String> crateApiSavesTouchSlots({required List<String> slotIds });
+
+void crateApiSavesUndoLastInventoryChange();
+
+void crateApiSavesUnloadSlot();
+
+void crateApiSavesUpdateAutosavePayload({required PlayerData data });
+
+void crateApiSavesVacuumMetadata();
+
+void crateApiSavesVacuumSlot({required String slotId });
+
+bool crateApiSavesVerifySlot({required String slotId });
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override String? crateApiSavesActiveSlotId()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
-
-  @override
-  Future<void> crateApiSimpleInitApp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 2,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSavesActiveSlotIdConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesActiveSlotIdConstMeta => const TaskConstMeta(
+            debugName: "active_slot_id",
+            argNames: [],
+        );
+        
+
+@override void crateApiSavesAddInventoryItem({required String item , required int quantity })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(item, serializer);
+sse_encode_i_32(quantity, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-}
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesAddInventoryItemConstMeta,
+            argValues: [item, quantity],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesAddInventoryItemConstMeta => const TaskConstMeta(
+            debugName: "add_inventory_item",
+            argNames: ["item", "quantity"],
+        );
+        
+
+@override void crateApiSavesAddPlayTime({required String slotId , required PlatformInt64 seconds })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+sse_encode_i_64(seconds, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesAddPlayTimeConstMeta,
+            argValues: [slotId, seconds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesAddPlayTimeConstMeta => const TaskConstMeta(
+            debugName: "add_play_time",
+            argNames: ["slotId", "seconds"],
+        );
+        
+
+@override void crateApiSavesAddSlotTag({required String slotId , required String tag })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+sse_encode_String(tag, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesAddSlotTagConstMeta,
+            argValues: [slotId, tag],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesAddSlotTagConstMeta => const TaskConstMeta(
+            debugName: "add_slot_tag",
+            argNames: ["slotId", "tag"],
+        );
+        
+
+@override AggregateStats crateApiSavesAggregateStats()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_aggregate_stats,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesAggregateStatsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesAggregateStatsConstMeta => const TaskConstMeta(
+            debugName: "aggregate_stats",
+            argNames: [],
+        );
+        
+
+@override void crateApiSavesApplyBatch({required List<SaveOp> ops })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_save_op(ops, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesApplyBatchConstMeta,
+            argValues: [ops],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesApplyBatchConstMeta => const TaskConstMeta(
+            debugName: "apply_batch",
+            argNames: ["ops"],
+        );
+        
+
+@override void crateApiSavesAutosave({required PlayerData data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_player_data(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesAutosaveConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesAutosaveConstMeta => const TaskConstMeta(
+            debugName: "autosave",
+            argNames: ["data"],
+        );
+        
+
+@override AutosaveUsage crateApiSavesAutosaveUsage({required String slotId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_autosave_usage,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesAutosaveUsageConstMeta,
+            argValues: [slotId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesAutosaveUsageConstMeta => const TaskConstMeta(
+            debugName: "autosave_usage",
+            argNames: ["slotId"],
+        );
+        
+
+@override void crateApiSavesBeginTransaction()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesBeginTransactionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesBeginTransactionConstMeta => const TaskConstMeta(
+            debugName: "begin_transaction",
+            argNames: [],
+        );
+        
+
+@override List<ChangeEntry> crateApiSavesChangesSince({required PlatformInt64 timestampMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(timestampMs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_change_entry,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesChangesSinceConstMeta,
+            argValues: [timestampMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesChangesSinceConstMeta => const TaskConstMeta(
+            debugName: "changes_since",
+            argNames: ["timestampMs"],
+        );
+        
+
+@override void crateApiSavesCheckpointActiveSlot()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesCheckpointActiveSlotConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesCheckpointActiveSlotConstMeta => const TaskConstMeta(
+            debugName: "checkpoint_active_slot",
+            argNames: [],
+        );
+        
+
+@override void crateApiSavesCloseSlot({required SlotHandle handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_slot_handle(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesCloseSlotConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesCloseSlotConstMeta => const TaskConstMeta(
+            debugName: "close_slot",
+            argNames: ["handle"],
+        );
+        
+
+@override void crateApiSavesCloseSystem()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesCloseSystemConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesCloseSystemConstMeta => const TaskConstMeta(
+            debugName: "close_system",
+            argNames: [],
+        );
+        
+
+@override void crateApiSavesCommitTransaction()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesCommitTransactionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesCommitTransactionConstMeta => const TaskConstMeta(
+            debugName: "commit_transaction",
+            argNames: [],
+        );
+        
+
+@override void crateApiSavesConfigureAutosave({required String slotId , required int maxBackups })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+sse_encode_u_32(maxBackups, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesConfigureAutosaveConstMeta,
+            argValues: [slotId, maxBackups],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesConfigureAutosaveConstMeta => const TaskConstMeta(
+            debugName: "configure_autosave",
+            argNames: ["slotId", "maxBackups"],
+        );
+        
+
+@override BigInt crateApiSavesCountSlots()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesCountSlotsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesCountSlotsConstMeta => const TaskConstMeta(
+            debugName: "count_slots",
+            argNames: [],
+        );
+        
+
+@override String crateApiSavesCreateCheckpoint({required String label })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(label, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesCreateCheckpointConstMeta,
+            argValues: [label],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesCreateCheckpointConstMeta => const TaskConstMeta(
+            debugName: "create_checkpoint",
+            argNames: ["label"],
+        );
+        
+
+@override SaveSlotMetadata crateApiSavesCreateNewSlot({required String displayName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(displayName, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_save_slot_metadata,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesCreateNewSlotConstMeta,
+            argValues: [displayName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesCreateNewSlotConstMeta => const TaskConstMeta(
+            debugName: "create_new_slot",
+            argNames: ["displayName"],
+        );
+        
+
+@override SaveSlotMetadata crateApiSavesCreateSlotWithData({required String displayName , required PlayerData data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(displayName, serializer);
+sse_encode_box_autoadd_player_data(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_save_slot_metadata,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesCreateSlotWithDataConstMeta,
+            argValues: [displayName, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesCreateSlotWithDataConstMeta => const TaskConstMeta(
+            debugName: "create_slot_with_data",
+            argNames: ["displayName", "data"],
+        );
+        
+
+@override void crateApiSavesDeleteAllSlots()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesDeleteAllSlotsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesDeleteAllSlotsConstMeta => const TaskConstMeta(
+            debugName: "delete_all_slots",
+            argNames: [],
+        );
+        
+
+@override void crateApiSavesDeleteSlot({required String slotId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesDeleteSlotConstMeta,
+            argValues: [slotId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesDeleteSlotConstMeta => const TaskConstMeta(
+            debugName: "delete_slot",
+            argNames: ["slotId"],
+        );
+        
+
+@override List<String> crateApiSavesDeleteSlots({required List<String> slotIds })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(slotIds, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesDeleteSlotsConstMeta,
+            argValues: [slotIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesDeleteSlotsConstMeta => const TaskConstMeta(
+            debugName: "delete_slots",
+            argNames: ["slotIds"],
+        );
+        
+
+@override SlotDiff crateApiSavesDiffSlots({required String slotA , required String slotB })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotA, serializer);
+sse_encode_String(slotB, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_slot_diff,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesDiffSlotsConstMeta,
+            argValues: [slotA, slotB],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesDiffSlotsConstMeta => const TaskConstMeta(
+            debugName: "diff_slots",
+            argNames: ["slotA", "slotB"],
+        );
+        
+
+@override SaveSlotMetadata crateApiSavesDuplicateSlot({required String slotId , required String newName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+sse_encode_String(newName, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_save_slot_metadata,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesDuplicateSlotConstMeta,
+            argValues: [slotId, newName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesDuplicateSlotConstMeta => const TaskConstMeta(
+            debugName: "duplicate_slot",
+            argNames: ["slotId", "newName"],
+        );
+        
+
+@override Stream<ArchiveProgress> crateApiSavesExportAll({required String path })  { 
+            final sink = RustStreamSink<ArchiveProgress>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_StreamSink_archive_progress_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesExportAllConstMeta,
+            argValues: [path, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiSavesExportAllConstMeta => const TaskConstMeta(
+            debugName: "export_all",
+            argNames: ["path", "sink"],
+        );
+        
+
+@override Uint8List crateApiSavesExportSlotBinary({required String slotId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesExportSlotBinaryConstMeta,
+            argValues: [slotId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesExportSlotBinaryConstMeta => const TaskConstMeta(
+            debugName: "export_slot_binary",
+            argNames: ["slotId"],
+        );
+        
+
+@override Uint8List crateApiSavesExportSlotCompressed({required String slotId , required ExportFormat format })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+sse_encode_export_format(format, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesExportSlotCompressedConstMeta,
+            argValues: [slotId, format],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesExportSlotCompressedConstMeta => const TaskConstMeta(
+            debugName: "export_slot_compressed",
+            argNames: ["slotId", "format"],
+        );
+        
+
+@override String crateApiSavesExportSlotToJson({required String slotId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesExportSlotToJsonConstMeta,
+            argValues: [slotId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesExportSlotToJsonConstMeta => const TaskConstMeta(
+            debugName: "export_slot_to_json",
+            argNames: ["slotId"],
+        );
+        
+
+@override void crateApiSavesForceInitSystem({required String basePath })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(basePath, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesForceInitSystemConstMeta,
+            argValues: [basePath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesForceInitSystemConstMeta => const TaskConstMeta(
+            debugName: "force_init_system",
+            argNames: ["basePath"],
+        );
+        
+
+@override List<(String,String)> crateApiSavesGetAllFlags()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_record_string_string,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesGetAllFlagsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesGetAllFlagsConstMeta => const TaskConstMeta(
+            debugName: "get_all_flags",
+            argNames: [],
+        );
+        
+
+@override List<(String,String)> crateApiSavesGetAllSettings()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_record_string_string,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesGetAllSettingsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesGetAllSettingsConstMeta => const TaskConstMeta(
+            debugName: "get_all_settings",
+            argNames: [],
+        );
+        
+
+@override List<SaveSlotMetadata> crateApiSavesGetAllSlots()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_save_slot_metadata,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesGetAllSlotsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesGetAllSlotsConstMeta => const TaskConstMeta(
+            debugName: "get_all_slots",
+            argNames: [],
+        );
+        
+
+@override String? crateApiSavesGetFlag({required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesGetFlagConstMeta,
+            argValues: [key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesGetFlagConstMeta => const TaskConstMeta(
+            debugName: "get_flag",
+            argNames: ["key"],
+        );
+        
+
+@override String? crateApiSavesGetSetting({required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesGetSettingConstMeta,
+            argValues: [key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesGetSettingConstMeta => const TaskConstMeta(
+            debugName: "get_setting",
+            argNames: ["key"],
+        );
+        
+
+@override SaveSlotMetadata crateApiSavesGetSlot({required String slotId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_save_slot_metadata,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesGetSlotConstMeta,
+            argValues: [slotId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesGetSlotConstMeta => const TaskConstMeta(
+            debugName: "get_slot",
+            argNames: ["slotId"],
+        );
+        
+
+@override Uint8List? crateApiSavesGetSlotThumbnail({required String slotId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesGetSlotThumbnailConstMeta,
+            argValues: [slotId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesGetSlotThumbnailConstMeta => const TaskConstMeta(
+            debugName: "get_slot_thumbnail",
+            argNames: ["slotId"],
+        );
+        
+
+@override List<SaveSlotMetadata> crateApiSavesGetSlotsByTag({required String tag })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(tag, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_save_slot_metadata,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesGetSlotsByTagConstMeta,
+            argValues: [tag],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesGetSlotsByTagConstMeta => const TaskConstMeta(
+            debugName: "get_slots_by_tag",
+            argNames: ["tag"],
+        );
+        
+
+@override List<SaveSlotMetadata> crateApiSavesGetSlotsPage({required int offset , required int limit , required SlotOrder order })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(offset, serializer);
+sse_encode_u_32(limit, serializer);
+sse_encode_slot_order(order, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_save_slot_metadata,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesGetSlotsPageConstMeta,
+            argValues: [offset, limit, order],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesGetSlotsPageConstMeta => const TaskConstMeta(
+            debugName: "get_slots_page",
+            argNames: ["offset", "limit", "order"],
+        );
+        
+
+@override StatValue? crateApiSavesGetStat({required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_stat_value,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesGetStatConstMeta,
+            argValues: [key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesGetStatConstMeta => const TaskConstMeta(
+            debugName: "get_stat",
+            argNames: ["key"],
+        );
+        
+
+@override String crateApiSimpleGreet({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleGreetConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleGreetConstMeta => const TaskConstMeta(
+            debugName: "greet",
+            argNames: ["name"],
+        );
+        
+
+@override Stream<ArchiveProgress> crateApiSavesImportAll({required String path , required bool merge })  { 
+            final sink = RustStreamSink<ArchiveProgress>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_bool(merge, serializer);
+sse_encode_StreamSink_archive_progress_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesImportAllConstMeta,
+            argValues: [path, merge, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiSavesImportAllConstMeta => const TaskConstMeta(
+            debugName: "import_all",
+            argNames: ["path", "merge", "sink"],
+        );
+        
+
+@override Stream<ImportProgress> crateApiSavesImportDirectory({required String path })  { 
+            final sink = RustStreamSink<ImportProgress>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_StreamSink_import_progress_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesImportDirectoryConstMeta,
+            argValues: [path, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiSavesImportDirectoryConstMeta => const TaskConstMeta(
+            debugName: "import_directory",
+            argNames: ["path", "sink"],
+        );
+        
+
+@override ImportedSlot crateApiSavesImportSlot({required List<int> bytes , required String displayName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(bytes, serializer);
+sse_encode_String(displayName, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_imported_slot,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesImportSlotConstMeta,
+            argValues: [bytes, displayName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesImportSlotConstMeta => const TaskConstMeta(
+            debugName: "import_slot",
+            argNames: ["bytes", "displayName"],
+        );
+        
+
+@override SaveSlotMetadata crateApiSavesImportSlotBinary({required List<int> bytes , required String displayName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(bytes, serializer);
+sse_encode_String(displayName, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_save_slot_metadata,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesImportSlotBinaryConstMeta,
+            argValues: [bytes, displayName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesImportSlotBinaryConstMeta => const TaskConstMeta(
+            debugName: "import_slot_binary",
+            argNames: ["bytes", "displayName"],
+        );
+        
+
+@override SaveSlotMetadata crateApiSavesImportSlotFromJson({required String json , required String displayName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(json, serializer);
+sse_encode_String(displayName, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_save_slot_metadata,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesImportSlotFromJsonConstMeta,
+            argValues: [json, displayName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesImportSlotFromJsonConstMeta => const TaskConstMeta(
+            debugName: "import_slot_from_json",
+            argNames: ["json", "displayName"],
+        );
+        
+
+@override Future<void> crateApiSimpleInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override void crateApiSavesInitSystem({required String basePath })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(basePath, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesInitSystemConstMeta,
+            argValues: [basePath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesInitSystemConstMeta => const TaskConstMeta(
+            debugName: "init_system",
+            argNames: ["basePath"],
+        );
+        
+
+@override void crateApiSavesInitSystemEncrypted({required String basePath , required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(basePath, serializer);
+sse_encode_String(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesInitSystemEncryptedConstMeta,
+            argValues: [basePath, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesInitSystemEncryptedConstMeta => const TaskConstMeta(
+            debugName: "init_system_encrypted",
+            argNames: ["basePath", "key"],
+        );
+        
+
+@override void crateApiSavesInitSystemForProfile({required String basePath , required String profile })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(basePath, serializer);
+sse_encode_String(profile, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesInitSystemForProfileConstMeta,
+            argValues: [basePath, profile],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesInitSystemForProfileConstMeta => const TaskConstMeta(
+            debugName: "init_system_for_profile",
+            argNames: ["basePath", "profile"],
+        );
+        
+
+@override void crateApiSavesInitSystemWithConfig({required String basePath , required String appName , required String savesSubdir })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(basePath, serializer);
+sse_encode_String(appName, serializer);
+sse_encode_String(savesSubdir, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesInitSystemWithConfigConstMeta,
+            argValues: [basePath, appName, savesSubdir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesInitSystemWithConfigConstMeta => const TaskConstMeta(
+            debugName: "init_system_with_config",
+            argNames: ["basePath", "appName", "savesSubdir"],
+        );
+        
+
+@override ImportPreview crateApiSavesInspectImport({required String json })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(json, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_import_preview,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesInspectImportConstMeta,
+            argValues: [json],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesInspectImportConstMeta => const TaskConstMeta(
+            debugName: "inspect_import",
+            argNames: ["json"],
+        );
+        
+
+@override bool crateApiSavesIsInitialized()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSavesIsInitializedConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesIsInitializedConstMeta => const TaskConstMeta(
+            debugName: "is_initialized",
+            argNames: [],
+        );
+        
+
+@override String? crateApiSavesLastError()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSavesLastErrorConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesLastErrorConstMeta => const TaskConstMeta(
+            debugName: "last_error",
+            argNames: [],
+        );
+        
+
+@override BigInt crateApiSavesLatestSchemaVersion()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSavesLatestSchemaVersionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesLatestSchemaVersionConstMeta => const TaskConstMeta(
+            debugName: "latest_schema_version",
+            argNames: [],
+        );
+        
+
+@override int crateApiLevelingLevelForExperience({required int experience })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(experience, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLevelingLevelForExperienceConstMeta,
+            argValues: [experience],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLevelingLevelForExperienceConstMeta => const TaskConstMeta(
+            debugName: "level_for_experience",
+            argNames: ["experience"],
+        );
+        
+
+@override List<AutosaveInfo> crateApiSavesListAutosaves({required String slotId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_autosave_info,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesListAutosavesConstMeta,
+            argValues: [slotId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesListAutosavesConstMeta => const TaskConstMeta(
+            debugName: "list_autosaves",
+            argNames: ["slotId"],
+        );
+        
+
+@override List<CheckpointInfo> crateApiSavesListCheckpoints({required String slotId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_checkpoint_info,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesListCheckpointsConstMeta,
+            argValues: [slotId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesListCheckpointsConstMeta => const TaskConstMeta(
+            debugName: "list_checkpoints",
+            argNames: ["slotId"],
+        );
+        
+
+@override List<MigrationBackupInfo> crateApiSavesListMigrationBackups()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_migration_backup_info,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesListMigrationBackupsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesListMigrationBackupsConstMeta => const TaskConstMeta(
+            debugName: "list_migration_backups",
+            argNames: [],
+        );
+        
+
+@override List<String> crateApiSavesListProfiles({required String basePath })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(basePath, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesListProfilesConstMeta,
+            argValues: [basePath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesListProfilesConstMeta => const TaskConstMeta(
+            debugName: "list_profiles",
+            argNames: ["basePath"],
+        );
+        
+
+@override List<TrashedSlot> crateApiSavesListTrashedSlots()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_trashed_slot,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesListTrashedSlotsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesListTrashedSlotsConstMeta => const TaskConstMeta(
+            debugName: "list_trashed_slots",
+            argNames: [],
+        );
+        
+
+@override List<String> crateApiSavesLoadInventory()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesLoadInventoryConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesLoadInventoryConstMeta => const TaskConstMeta(
+            debugName: "load_inventory",
+            argNames: [],
+        );
+        
+
+@override PlayerData crateApiSavesLoadPlayerData()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_player_data,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesLoadPlayerDataConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesLoadPlayerDataConstMeta => const TaskConstMeta(
+            debugName: "load_player_data",
+            argNames: [],
+        );
+        
+
+@override PlayerData crateApiSavesLoadPlayerDataFor({required SlotHandle handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_slot_handle(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_player_data,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesLoadPlayerDataForConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesLoadPlayerDataForConstMeta => const TaskConstMeta(
+            debugName: "load_player_data_for",
+            argNames: ["handle"],
+        );
+        
+
+@override (int,int) crateApiSavesLoadPlayerStats()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_i_32_i_32,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesLoadPlayerStatsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesLoadPlayerStatsConstMeta => const TaskConstMeta(
+            debugName: "load_player_stats",
+            argNames: [],
+        );
+        
+
+@override void crateApiSavesLoadSlot({required String slotId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesLoadSlotConstMeta,
+            argValues: [slotId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesLoadSlotConstMeta => const TaskConstMeta(
+            debugName: "load_slot",
+            argNames: ["slotId"],
+        );
+        
+
+@override BigInt crateApiSavesMergeInventories({required String into , required String from , required MergeStrategy strategy })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(into, serializer);
+sse_encode_String(from, serializer);
+sse_encode_merge_strategy(strategy, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesMergeInventoriesConstMeta,
+            argValues: [into, from, strategy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesMergeInventoriesConstMeta => const TaskConstMeta(
+            debugName: "merge_inventories",
+            argNames: ["into", "from", "strategy"],
+        );
+        
+
+@override String crateApiSavesMetadataDbPath()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesMetadataDbPathConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesMetadataDbPathConstMeta => const TaskConstMeta(
+            debugName: "metadata_db_path",
+            argNames: [],
+        );
+        
+
+@override void crateApiSavesMigrateSlotToVersion({required String slotId , required BigInt target })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+sse_encode_usize(target, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesMigrateSlotToVersionConstMeta,
+            argValues: [slotId, target],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesMigrateSlotToVersionConstMeta => const TaskConstMeta(
+            debugName: "migrate_slot_to_version",
+            argNames: ["slotId", "target"],
+        );
+        
+
+@override void crateApiSavesMoveSlot({required String slotId , required String newDir })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+sse_encode_String(newDir, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesMoveSlotConstMeta,
+            argValues: [slotId, newDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesMoveSlotConstMeta => const TaskConstMeta(
+            debugName: "move_slot",
+            argNames: ["slotId", "newDir"],
+        );
+        
+
+@override SlotHandle crateApiSavesOpenSlot({required String slotId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_slot_handle,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesOpenSlotConstMeta,
+            argValues: [slotId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesOpenSlotConstMeta => const TaskConstMeta(
+            debugName: "open_slot",
+            argNames: ["slotId"],
+        );
+        
+
+@override PlatformInt64 crateApiSavesPlayerDataRevision()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesPlayerDataRevisionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesPlayerDataRevisionConstMeta => const TaskConstMeta(
+            debugName: "player_data_revision",
+            argNames: [],
+        );
+        
+
+@override SlotPreview crateApiSavesPreviewSlot({required String slotId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_slot_preview,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesPreviewSlotConstMeta,
+            argValues: [slotId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesPreviewSlotConstMeta => const TaskConstMeta(
+            debugName: "preview_slot",
+            argNames: ["slotId"],
+        );
+        
+
+@override BigInt crateApiSavesPruneChangeLog({required PlatformInt64 timestampMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(timestampMs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesPruneChangeLogConstMeta,
+            argValues: [timestampMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesPruneChangeLogConstMeta => const TaskConstMeta(
+            debugName: "prune_change_log",
+            argNames: ["timestampMs"],
+        );
+        
+
+@override List<String> crateApiSavesPurgeTrash({required PlatformInt64 olderThanDays })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(olderThanDays, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesPurgeTrashConstMeta,
+            argValues: [olderThanDays],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesPurgeTrashConstMeta => const TaskConstMeta(
+            debugName: "purge_trash",
+            argNames: ["olderThanDays"],
+        );
+        
+
+@override void crateApiSavesRecoverSlotLocks({required String slotId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesRecoverSlotLocksConstMeta,
+            argValues: [slotId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesRecoverSlotLocksConstMeta => const TaskConstMeta(
+            debugName: "recover_slot_locks",
+            argNames: ["slotId"],
+        );
+        
+
+@override void crateApiSavesRegisterSlotMigrations({required List<(String,String?)> migrations })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_record_string_opt_string(migrations, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesRegisterSlotMigrationsConstMeta,
+            argValues: [migrations],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesRegisterSlotMigrationsConstMeta => const TaskConstMeta(
+            debugName: "register_slot_migrations",
+            argNames: ["migrations"],
+        );
+        
+
+@override void crateApiSavesRemoveInventoryItem({required PlatformInt64 position })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(position, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesRemoveInventoryItemConstMeta,
+            argValues: [position],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesRemoveInventoryItemConstMeta => const TaskConstMeta(
+            debugName: "remove_inventory_item",
+            argNames: ["position"],
+        );
+        
+
+@override void crateApiSavesRemoveSlotTag({required String slotId , required String tag })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+sse_encode_String(tag, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesRemoveSlotTagConstMeta,
+            argValues: [slotId, tag],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesRemoveSlotTagConstMeta => const TaskConstMeta(
+            debugName: "remove_slot_tag",
+            argNames: ["slotId", "tag"],
+        );
+        
+
+@override ItemRenameResult crateApiSavesRenameItemEverywhere({required String old , required String new_ })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(old, serializer);
+sse_encode_String(new_, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_item_rename_result,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesRenameItemEverywhereConstMeta,
+            argValues: [old, new_],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesRenameItemEverywhereConstMeta => const TaskConstMeta(
+            debugName: "rename_item_everywhere",
+            argNames: ["old", "new_"],
+        );
+        
+
+@override SaveSlotMetadata crateApiSavesRenameSlot({required String slotId , required String newName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+sse_encode_String(newName, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_save_slot_metadata,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesRenameSlotConstMeta,
+            argValues: [slotId, newName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesRenameSlotConstMeta => const TaskConstMeta(
+            debugName: "rename_slot",
+            argNames: ["slotId", "newName"],
+        );
+        
+
+@override RepairReport crateApiSavesRepairIntegrity({required RepairPolicy policy })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_repair_policy(policy, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_repair_report,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesRepairIntegrityConstMeta,
+            argValues: [policy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesRepairIntegrityConstMeta => const TaskConstMeta(
+            debugName: "repair_integrity",
+            argNames: ["policy"],
+        );
+        
+
+@override void crateApiSavesRestoreAutosave({required String slotId , required int index })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+sse_encode_u_32(index, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesRestoreAutosaveConstMeta,
+            argValues: [slotId, index],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesRestoreAutosaveConstMeta => const TaskConstMeta(
+            debugName: "restore_autosave",
+            argNames: ["slotId", "index"],
+        );
+        
+
+@override void crateApiSavesRestoreCheckpoint({required String checkpointId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(checkpointId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesRestoreCheckpointConstMeta,
+            argValues: [checkpointId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesRestoreCheckpointConstMeta => const TaskConstMeta(
+            debugName: "restore_checkpoint",
+            argNames: ["checkpointId"],
+        );
+        
+
+@override SaveSlotMetadata crateApiSavesRestoreSlot({required String slotId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_save_slot_metadata,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesRestoreSlotConstMeta,
+            argValues: [slotId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesRestoreSlotConstMeta => const TaskConstMeta(
+            debugName: "restore_slot",
+            argNames: ["slotId"],
+        );
+        
+
+@override void crateApiSavesRollbackTransaction()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesRollbackTransactionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesRollbackTransactionConstMeta => const TaskConstMeta(
+            debugName: "rollback_transaction",
+            argNames: [],
+        );
+        
+
+@override MaintenanceReport crateApiSavesRunMaintenance({BigInt? vacuumAboveBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_u_64(vacuumAboveBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_maintenance_report,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesRunMaintenanceConstMeta,
+            argValues: [vacuumAboveBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesRunMaintenanceConstMeta => const TaskConstMeta(
+            debugName: "run_maintenance",
+            argNames: ["vacuumAboveBytes"],
+        );
+        
+
+@override SaveResult crateApiSavesSavePlayerData({required PlayerData data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_player_data(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_save_result,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSavePlayerDataConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSavePlayerDataConstMeta => const TaskConstMeta(
+            debugName: "save_player_data",
+            argNames: ["data"],
+        );
+        
+
+@override Future<SaveResult> crateApiSavesSavePlayerDataAsync({required PlayerData data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_player_data(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_save_result,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSavePlayerDataAsyncConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSavePlayerDataAsyncConstMeta => const TaskConstMeta(
+            debugName: "save_player_data_async",
+            argNames: ["data"],
+        );
+        
+
+@override SaveResult crateApiSavesSavePlayerDataChecked({required PlayerData data , required PlatformInt64 expectedRevision })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_player_data(data, serializer);
+sse_encode_i_64(expectedRevision, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_save_result,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSavePlayerDataCheckedConstMeta,
+            argValues: [data, expectedRevision],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSavePlayerDataCheckedConstMeta => const TaskConstMeta(
+            debugName: "save_player_data_checked",
+            argNames: ["data", "expectedRevision"],
+        );
+        
+
+@override SaveResult crateApiSavesSavePlayerDataFor({required SlotHandle handle , required PlayerData data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_slot_handle(handle, serializer);
+sse_encode_box_autoadd_player_data(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_save_result,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSavePlayerDataForConstMeta,
+            argValues: [handle, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSavePlayerDataForConstMeta => const TaskConstMeta(
+            debugName: "save_player_data_for",
+            argNames: ["handle", "data"],
+        );
+        
+
+@override SaveResult crateApiSavesSavePlayerDataWithThumbnail({required PlayerData data , required List<int> pngBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_player_data(data, serializer);
+sse_encode_list_prim_u_8_loose(pngBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_save_result,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSavePlayerDataWithThumbnailConstMeta,
+            argValues: [data, pngBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSavePlayerDataWithThumbnailConstMeta => const TaskConstMeta(
+            debugName: "save_player_data_with_thumbnail",
+            argNames: ["data", "pngBytes"],
+        );
+        
+
+@override String crateApiSavesSaveSlotMetadataLastPlayedIso8601({required SaveSlotMetadata that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_save_slot_metadata(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSavesSaveSlotMetadataLastPlayedIso8601ConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSaveSlotMetadataLastPlayedIso8601ConstMeta => const TaskConstMeta(
+            debugName: "save_slot_metadata_last_played_iso8601",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiSavesSavesDirectory()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSavesDirectoryConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSavesDirectoryConstMeta => const TaskConstMeta(
+            debugName: "saves_directory",
+            argNames: [],
+        );
+        
+
+@override IntegrityReport crateApiSavesScanIntegrity()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_integrity_report,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesScanIntegrityConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesScanIntegrityConstMeta => const TaskConstMeta(
+            debugName: "scan_integrity",
+            argNames: [],
+        );
+        
+
+@override SchemaInfo crateApiSavesSchemaInfo()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_schema_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSavesSchemaInfoConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSchemaInfoConstMeta => const TaskConstMeta(
+            debugName: "schema_info",
+            argNames: [],
+        );
+        
+
+@override List<SaveSlotMetadata> crateApiSavesSearchSlots({required String query })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_save_slot_metadata,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSearchSlotsConstMeta,
+            argValues: [query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSearchSlotsConstMeta => const TaskConstMeta(
+            debugName: "search_slots",
+            argNames: ["query"],
+        );
+        
+
+@override void crateApiSavesSetAppSaveVersion({required int version })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(version, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSetAppSaveVersionConstMeta,
+            argValues: [version],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSetAppSaveVersionConstMeta => const TaskConstMeta(
+            debugName: "set_app_save_version",
+            argNames: ["version"],
+        );
+        
+
+@override void crateApiSavesSetAutosaveRetention({required int maxCount , required int maxAgeDays })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(maxCount, serializer);
+sse_encode_u_32(maxAgeDays, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSetAutosaveRetentionConstMeta,
+            argValues: [maxCount, maxAgeDays],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSetAutosaveRetentionConstMeta => const TaskConstMeta(
+            debugName: "set_autosave_retention",
+            argNames: ["maxCount", "maxAgeDays"],
+        );
+        
+
+@override void crateApiSavesSetBusyRetries({required int retries })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(retries, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSetBusyRetriesConstMeta,
+            argValues: [retries],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSetBusyRetriesConstMeta => const TaskConstMeta(
+            debugName: "set_busy_retries",
+            argNames: ["retries"],
+        );
+        
+
+@override void crateApiSavesSetFlag({required String key , required String value })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(key, serializer);
+sse_encode_String(value, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSetFlagConstMeta,
+            argValues: [key, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSetFlagConstMeta => const TaskConstMeta(
+            debugName: "set_flag",
+            argNames: ["key", "value"],
+        );
+        
+
+@override void crateApiSavesSetIntegrityCheckEnabled({required bool enabled })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(enabled, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSetIntegrityCheckEnabledConstMeta,
+            argValues: [enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSetIntegrityCheckEnabledConstMeta => const TaskConstMeta(
+            debugName: "set_integrity_check_enabled",
+            argNames: ["enabled"],
+        );
+        
+
+@override void crateApiSavesSetInventoryItem({required PlatformInt64 position , required String item })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(position, serializer);
+sse_encode_String(item, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSetInventoryItemConstMeta,
+            argValues: [position, item],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSetInventoryItemConstMeta => const TaskConstMeta(
+            debugName: "set_inventory_item",
+            argNames: ["position", "item"],
+        );
+        
+
+@override void crateApiSavesSetKeepMetadataConnection({required bool enabled })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(enabled, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSetKeepMetadataConnectionConstMeta,
+            argValues: [enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSetKeepMetadataConnectionConstMeta => const TaskConstMeta(
+            debugName: "set_keep_metadata_connection",
+            argNames: ["enabled"],
+        );
+        
+
+@override void crateApiLevelingSetLevelCurve({required List<int> thresholds })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_i_32_loose(thresholds, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLevelingSetLevelCurveConstMeta,
+            argValues: [thresholds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLevelingSetLevelCurveConstMeta => const TaskConstMeta(
+            debugName: "set_level_curve",
+            argNames: ["thresholds"],
+        );
+        
+
+@override void crateApiSavesSetLowDiskSpaceThreshold({required BigInt bytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(bytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSetLowDiskSpaceThresholdConstMeta,
+            argValues: [bytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSetLowDiskSpaceThresholdConstMeta => const TaskConstMeta(
+            debugName: "set_low_disk_space_threshold",
+            argNames: ["bytes"],
+        );
+        
+
+@override void crateApiSavesSetMaxSlotNameLength({required int maxLength })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(maxLength, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSetMaxSlotNameLengthConstMeta,
+            argValues: [maxLength],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSetMaxSlotNameLengthConstMeta => const TaskConstMeta(
+            debugName: "set_max_slot_name_length",
+            argNames: ["maxLength"],
+        );
+        
+
+@override void crateApiSavesSetNewGamePlusHealth({required int health })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(health, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSetNewGamePlusHealthConstMeta,
+            argValues: [health],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSetNewGamePlusHealthConstMeta => const TaskConstMeta(
+            debugName: "set_new_game_plus_health",
+            argNames: ["health"],
+        );
+        
+
+@override void crateApiSavesSetSetting({required String key , required String value })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(key, serializer);
+sse_encode_String(value, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSetSettingConstMeta,
+            argValues: [key, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSetSettingConstMeta => const TaskConstMeta(
+            debugName: "set_setting",
+            argNames: ["key", "value"],
+        );
+        
+
+@override void crateApiSavesSetSlotDescription({required String slotId , required String text })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+sse_encode_String(text, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSetSlotDescriptionConstMeta,
+            argValues: [slotId, text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSetSlotDescriptionConstMeta => const TaskConstMeta(
+            debugName: "set_slot_description",
+            argNames: ["slotId", "text"],
+        );
+        
+
+@override void crateApiSavesSetSlotThumbnail({required String slotId , required List<int> pngBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+sse_encode_list_prim_u_8_loose(pngBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSetSlotThumbnailConstMeta,
+            argValues: [slotId, pngBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSetSlotThumbnailConstMeta => const TaskConstMeta(
+            debugName: "set_slot_thumbnail",
+            argNames: ["slotId", "pngBytes"],
+        );
+        
+
+@override void crateApiSavesSetSqliteOptions({required int busyTimeoutMs , required String synchronous })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(busyTimeoutMs, serializer);
+sse_encode_String(synchronous, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSetSqliteOptionsConstMeta,
+            argValues: [busyTimeoutMs, synchronous],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSetSqliteOptionsConstMeta => const TaskConstMeta(
+            debugName: "set_sqlite_options",
+            argNames: ["busyTimeoutMs", "synchronous"],
+        );
+        
+
+@override void crateApiSavesSetStat({required String key , required StatValue value })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(key, serializer);
+sse_encode_box_autoadd_stat_value(value, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSetStatConstMeta,
+            argValues: [key, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSetStatConstMeta => const TaskConstMeta(
+            debugName: "set_stat",
+            argNames: ["key", "value"],
+        );
+        
+
+@override void crateApiSavesSetValidationLimits({required int maxInventory , required int maxHealth })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(maxInventory, serializer);
+sse_encode_i_32(maxHealth, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSetValidationLimitsConstMeta,
+            argValues: [maxInventory, maxHealth],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSetValidationLimitsConstMeta => const TaskConstMeta(
+            debugName: "set_validation_limits",
+            argNames: ["maxInventory", "maxHealth"],
+        );
+        
+
+@override void crateApiSavesSetWalAutocheckpoint({required int pages })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(pages, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSetWalAutocheckpointConstMeta,
+            argValues: [pages],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSetWalAutocheckpointConstMeta => const TaskConstMeta(
+            debugName: "set_wal_autocheckpoint",
+            argNames: ["pages"],
+        );
+        
+
+@override int crateApiSavesSlotAppVersion({required String slotId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSlotAppVersionConstMeta,
+            argValues: [slotId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSlotAppVersionConstMeta => const TaskConstMeta(
+            debugName: "slot_app_version",
+            argNames: ["slotId"],
+        );
+        
+
+@override BigInt crateApiSavesSlotDiskUsage({required String slotId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSlotDiskUsageConstMeta,
+            argValues: [slotId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSlotDiskUsageConstMeta => const TaskConstMeta(
+            debugName: "slot_disk_usage",
+            argNames: ["slotId"],
+        );
+        
+
+@override bool crateApiSavesSlotExists({required String slotId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 117)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSlotExistsConstMeta,
+            argValues: [slotId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSlotExistsConstMeta => const TaskConstMeta(
+            debugName: "slot_exists",
+            argNames: ["slotId"],
+        );
+        
+
+@override BigInt crateApiSavesSlotSchemaVersion({required String slotId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 118)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSlotSchemaVersionConstMeta,
+            argValues: [slotId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSlotSchemaVersionConstMeta => const TaskConstMeta(
+            debugName: "slot_schema_version",
+            argNames: ["slotId"],
+        );
+        
+
+@override void crateApiSavesStartAutosaveTimer({required BigInt intervalSecs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(intervalSecs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 119)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesStartAutosaveTimerConstMeta,
+            argValues: [intervalSecs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesStartAutosaveTimerConstMeta => const TaskConstMeta(
+            debugName: "start_autosave_timer",
+            argNames: ["intervalSecs"],
+        );
+        
+
+@override SaveSlotMetadata crateApiSavesStartNewGamePlus({required String slotId , required bool keepInventory , required double experienceCarryoverPct })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+sse_encode_bool(keepInventory, serializer);
+sse_encode_f_32(experienceCarryoverPct, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 120)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_save_slot_metadata,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesStartNewGamePlusConstMeta,
+            argValues: [slotId, keepInventory, experienceCarryoverPct],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesStartNewGamePlusConstMeta => const TaskConstMeta(
+            debugName: "start_new_game_plus",
+            argNames: ["slotId", "keepInventory", "experienceCarryoverPct"],
+        );
+        
+
+@override void crateApiSavesStopAutosaveTimer()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 121)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSavesStopAutosaveTimerConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesStopAutosaveTimerConstMeta => const TaskConstMeta(
+            debugName: "stop_autosave_timer",
+            argNames: [],
+        );
+        
+
+@override StoreHealth crateApiSavesStoreHealth()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 122)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_store_health,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSavesStoreHealthConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesStoreHealthConstMeta => const TaskConstMeta(
+            debugName: "store_health",
+            argNames: [],
+        );
+        
+
+@override Stream<SlotEvent> crateApiSavesSubscribeSlotEvents()  { 
+            final sink = RustStreamSink<SlotEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_slot_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 123, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSavesSubscribeSlotEventsConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiSavesSubscribeSlotEventsConstMeta => const TaskConstMeta(
+            debugName: "subscribe_slot_events",
+            argNames: ["sink"],
+        );
+        
+
+@override void crateApiSavesSwitchProfile({required String profile })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(profile, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 124)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesSwitchProfileConstMeta,
+            argValues: [profile],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesSwitchProfileConstMeta => const TaskConstMeta(
+            debugName: "switch_profile",
+            argNames: ["profile"],
+        );
+        
+
+@override BigInt crateApiSavesTotalSavesDiskUsage()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 125)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesTotalSavesDiskUsageConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesTotalSavesDiskUsageConstMeta => const TaskConstMeta(
+            debugName: "total_saves_disk_usage",
+            argNames: [],
+        );
+        
+
+@override List<String> crateApiSavesTouchSlots({required List<String> slotIds })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(slotIds, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 126)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesTouchSlotsConstMeta,
+            argValues: [slotIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesTouchSlotsConstMeta => const TaskConstMeta(
+            debugName: "touch_slots",
+            argNames: ["slotIds"],
+        );
+        
+
+@override void crateApiSavesUndoLastInventoryChange()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 127)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesUndoLastInventoryChangeConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesUndoLastInventoryChangeConstMeta => const TaskConstMeta(
+            debugName: "undo_last_inventory_change",
+            argNames: [],
+        );
+        
+
+@override void crateApiSavesUnloadSlot()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 128)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesUnloadSlotConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesUnloadSlotConstMeta => const TaskConstMeta(
+            debugName: "unload_slot",
+            argNames: [],
+        );
+        
+
+@override void crateApiSavesUpdateAutosavePayload({required PlayerData data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_player_data(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 129)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesUpdateAutosavePayloadConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesUpdateAutosavePayloadConstMeta => const TaskConstMeta(
+            debugName: "update_autosave_payload",
+            argNames: ["data"],
+        );
+        
+
+@override void crateApiSavesVacuumMetadata()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 130)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesVacuumMetadataConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesVacuumMetadataConstMeta => const TaskConstMeta(
+            debugName: "vacuum_metadata",
+            argNames: [],
+        );
+        
+
+@override void crateApiSavesVacuumSlot({required String slotId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 131)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesVacuumSlotConstMeta,
+            argValues: [slotId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesVacuumSlotConstMeta => const TaskConstMeta(
+            debugName: "vacuum_slot",
+            argNames: ["slotId"],
+        );
+        
+
+@override bool crateApiSavesVerifySlot({required String slotId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(slotId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 132)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_save_error,
+        )
+        ,
+            constMeta: kCrateApiSavesVerifySlotConstMeta,
+            argValues: [slotId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSavesVerifySlotConstMeta => const TaskConstMeta(
+            debugName: "verify_slot",
+            argNames: ["slotId"],
+        );
+        
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected RustStreamSink<ArchiveProgress> dco_decode_StreamSink_archive_progress_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<ImportProgress> dco_decode_StreamSink_import_progress_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<SlotEvent> dco_decode_StreamSink_slot_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected AggregateStats dco_decode_aggregate_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return AggregateStats(slotCount: dco_decode_usize(arr[0]),
+savedSlotCount: dco_decode_usize(arr[1]),
+highestExperience: dco_decode_i_32(arr[2]),
+highestHealth: dco_decode_i_32(arr[3]),
+totalItems: dco_decode_usize(arr[4]),
+totalPlayTimeSeconds: dco_decode_i_64(arr[5]),
+skipped: dco_decode_list_skipped_slot(arr[6]),); }
+
+@protected ArchiveProgress dco_decode_archive_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ArchiveProgress(current: dco_decode_usize(arr[0]),
+total: dco_decode_usize(arr[1]),
+fileName: dco_decode_String(arr[2]),); }
+
+@protected AutosaveInfo dco_decode_autosave_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return AutosaveInfo(index: dco_decode_u_32(arr[0]),
+savedAt: dco_decode_String(arr[1]),
+sizeBytes: dco_decode_u_64(arr[2]),
+filePath: dco_decode_String(arr[3]),); }
+
+@protected AutosaveUsage dco_decode_autosave_usage(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return AutosaveUsage(count: dco_decode_u_32(arr[0]),
+totalBytes: dco_decode_u_64(arr[1]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected int dco_decode_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_i_64(raw); }
+
+@protected PlayerData dco_decode_box_autoadd_player_data(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_player_data(raw); }
+
+@protected SaveSlotMetadata dco_decode_box_autoadd_save_slot_metadata(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_save_slot_metadata(raw); }
+
+@protected SlotHandle dco_decode_box_autoadd_slot_handle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_slot_handle(raw); }
+
+@protected StatValue dco_decode_box_autoadd_stat_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_stat_value(raw); }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected BigInt dco_decode_box_autoadd_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_usize(raw); }
+
+@protected ChangeEntry dco_decode_change_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ChangeEntry(sequence: dco_decode_i_64(arr[0]),
+timestampMs: dco_decode_i_64(arr[1]),
+slotId: dco_decode_String(arr[2]),
+operation: dco_decode_slot_event_kind(arr[3]),
+revision: dco_decode_opt_box_autoadd_i_64(arr[4]),); }
+
+@protected CheckpointInfo dco_decode_checkpoint_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return CheckpointInfo(id: dco_decode_String(arr[0]),
+slotId: dco_decode_String(arr[1]),
+label: dco_decode_String(arr[2]),
+createdAt: dco_decode_String(arr[3]),
+sizeBytes: dco_decode_u_64(arr[4]),
+filePath: dco_decode_String(arr[5]),); }
+
+@protected ExportFormat dco_decode_export_format(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ExportFormat.values[raw as int]; }
+
+@protected double dco_decode_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected ImportPreview dco_decode_import_preview(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ImportPreview(schemaVersion: dco_decode_i_64(arr[0]),
+health: dco_decode_i_32(arr[1]),
+experience: dco_decode_i_32(arr[2]),
+itemCount: dco_decode_usize(arr[3]),); }
+
+@protected ImportProgress dco_decode_import_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ImportProgress(current: dco_decode_usize(arr[0]),
+total: dco_decode_usize(arr[1]),
+fileName: dco_decode_String(arr[2]),
+slotId: dco_decode_opt_String(arr[3]),
+error: dco_decode_opt_String(arr[4]),); }
+
+@protected ImportedSlot dco_decode_imported_slot(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ImportedSlot(slot: dco_decode_save_slot_metadata(arr[0]),
+format: dco_decode_export_format(arr[1]),
+compressed: dco_decode_bool(arr[2]),); }
+
+@protected IntegrityReport dco_decode_integrity_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return IntegrityReport(orphanedFiles: dco_decode_list_String(arr[0]),
+danglingRows: dco_decode_list_String(arr[1]),); }
+
+@protected ItemChange dco_decode_item_change(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ItemChange.values[raw as int]; }
+
+@protected ItemDiff dco_decode_item_diff(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ItemDiff(item: dco_decode_String(arr[0]),
+change: dco_decode_item_change(arr[1]),
+countA: dco_decode_u_32(arr[2]),
+countB: dco_decode_u_32(arr[3]),); }
+
+@protected ItemRenameResult dco_decode_item_rename_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ItemRenameResult(rowsChanged: dco_decode_u_64(arr[0]),
+skipped: dco_decode_list_skipped_slot(arr[1]),); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<AutosaveInfo> dco_decode_list_autosave_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_autosave_info).toList(); }
+
+@protected List<ChangeEntry> dco_decode_list_change_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_change_entry).toList(); }
+
+@protected List<CheckpointInfo> dco_decode_list_checkpoint_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_checkpoint_info).toList(); }
+
+@protected List<ItemDiff> dco_decode_list_item_diff(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_item_diff).toList(); }
+
+@protected List<MigrationBackupInfo> dco_decode_list_migration_backup_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_migration_backup_info).toList(); }
+
+@protected List<int> dco_decode_list_prim_i_32_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Int32List dco_decode_list_prim_i_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Int32List; }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<(String,String?)> dco_decode_list_record_string_opt_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_opt_string).toList(); }
+
+@protected List<(String,String)> dco_decode_list_record_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_string).toList(); }
+
+@protected List<SaveOp> dco_decode_list_save_op(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_save_op).toList(); }
+
+@protected List<SaveSlotMetadata> dco_decode_list_save_slot_metadata(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_save_slot_metadata).toList(); }
+
+@protected List<SkippedSlot> dco_decode_list_skipped_slot(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_skipped_slot).toList(); }
+
+@protected List<SlotMaintenance> dco_decode_list_slot_maintenance(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_slot_maintenance).toList(); }
+
+@protected List<TrashedSlot> dco_decode_list_trashed_slot(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_trashed_slot).toList(); }
+
+@protected MaintenanceReport dco_decode_maintenance_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return MaintenanceReport(slots: dco_decode_list_slot_maintenance(arr[0]),
+bytesReclaimed: dco_decode_u_64(arr[1]),); }
+
+@protected MergeStrategy dco_decode_merge_strategy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MergeStrategy.values[raw as int]; }
+
+@protected MigrationBackupInfo dco_decode_migration_backup_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return MigrationBackupInfo(slotId: dco_decode_String(arr[0]),
+savedAt: dco_decode_String(arr[1]),
+sizeBytes: dco_decode_u_64(arr[2]),
+filePath: dco_decode_String(arr[3]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_32(raw); }
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_64(raw); }
+
+@protected StatValue? dco_decode_opt_box_autoadd_stat_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_stat_value(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_usize(raw); }
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_prim_u_8_strict(raw); }
+
+@protected PlayerData dco_decode_player_data(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return PlayerData(health: dco_decode_i_32(arr[0]),
+experience: dco_decode_i_32(arr[1]),
+inventory: dco_decode_list_String(arr[2]),); }
+
+@protected (int,int) dco_decode_record_i_32_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_i_32(arr[0]),dco_decode_i_32(arr[1]),); }
+
+@protected (String,String?) dco_decode_record_string_opt_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_opt_String(arr[1]),); }
+
+@protected (String,String) dco_decode_record_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_String(arr[1]),); }
+
+@protected RepairPolicy dco_decode_repair_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RepairPolicy.values[raw as int]; }
+
+@protected RepairReport dco_decode_repair_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return RepairReport(filesDeleted: dco_decode_usize(arr[0]),
+filesRegistered: dco_decode_usize(arr[1]),
+rowsRemoved: dco_decode_usize(arr[2]),); }
+
+@protected SaveError dco_decode_save_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return SaveError_NotInitialized();
+case 1: return SaveError_AlreadyInitialized(basePath: dco_decode_String(raw[1]),);
+case 2: return SaveError_WorkerUnavailable();
+case 3: return SaveError_OperationPanicked();
+case 4: return SaveError_NoActiveSlot();
+case 5: return SaveError_UnknownHandle(handle: dco_decode_u_32(raw[1]),);
+case 6: return SaveError_SlotNotFound(id: dco_decode_String(raw[1]),);
+case 7: return SaveError_InvalidSlotName(reason: dco_decode_String(raw[1]),);
+case 8: return SaveError_CorruptSlot(id: dco_decode_String(raw[1]),);
+case 9: return SaveError_InvalidTag(reason: dco_decode_String(raw[1]),);
+case 10: return SaveError_NoPlayerData();
+case 11: return SaveError_InvalidPlayerData(field: dco_decode_String(raw[1]),reason: dco_decode_String(raw[2]),);
+case 12: return SaveError_UnsupportedFormat(reason: dco_decode_String(raw[1]),);
+case 13: return SaveError_ThumbnailTooLarge(size: dco_decode_u_64(raw[1]),max: dco_decode_u_64(raw[2]),);
+case 14: return SaveError_AutosaveNotConfigured();
+case 15: return SaveError_AutosaveNotFound(slotId: dco_decode_String(raw[1]),index: dco_decode_u_32(raw[2]),);
+case 16: return SaveError_CheckpointNotFound(id: dco_decode_String(raw[1]),);
+case 17: return SaveError_SlotInUse(id: dco_decode_String(raw[1]),);
+case 18: return SaveError_DecryptionFailed();
+case 19: return SaveError_InvalidConfig(reason: dco_decode_String(raw[1]),);
+case 20: return SaveError_ChecksumMismatch(id: dco_decode_String(raw[1]),);
+case 21: return SaveError_RevisionConflict(current: dco_decode_i_64(raw[1]),);
+case 22: return SaveError_SlotLocked(id: dco_decode_String(raw[1]),);
+case 23: return SaveError_SlotBusy(id: dco_decode_String(raw[1]),);
+case 24: return SaveError_DiskFull();
+case 25: return SaveError_TransactionInProgress(id: dco_decode_String(raw[1]),);
+case 26: return SaveError_NoTransaction();
+case 27: return SaveError_NothingToUndo();
+case 28: return SaveError_SchemaMismatch(missing: dco_decode_list_String(raw[1]),);
+case 29: return SaveError_Io(message: dco_decode_String(raw[1]),);
+case 30: return SaveError_Database(message: dco_decode_String(raw[1]),);
+case 31: return SaveError_Migration(message: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected SaveOp dco_decode_save_op(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return SaveOp_SetStats(health: dco_decode_i_32(raw[1]),experience: dco_decode_i_32(raw[2]),);
+case 1: return SaveOp_AddItem(item: dco_decode_String(raw[1]),quantity: dco_decode_i_32(raw[2]),);
+case 2: return SaveOp_RemoveItem(position: dco_decode_i_64(raw[1]),);
+case 3: return SaveOp_SetFlag(key: dco_decode_String(raw[1]),value: dco_decode_String(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected SaveResult dco_decode_save_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SaveResult(statsUpdated: dco_decode_bool(arr[0]),
+inventoryRows: dco_decode_usize(arr[1]),
+revision: dco_decode_i_64(arr[2]),); }
+
+@protected SaveSlotMetadata dco_decode_save_slot_metadata(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return SaveSlotMetadata(id: dco_decode_String(arr[0]),
+name: dco_decode_String(arr[1]),
+lastPlayed: dco_decode_String(arr[2]),
+lastPlayedEpochMs: dco_decode_i_64(arr[3]),
+createdAt: dco_decode_String(arr[4]),
+createdAtEpochMs: dco_decode_i_64(arr[5]),
+filePath: dco_decode_String(arr[6]),
+playTimeSeconds: dco_decode_i_64(arr[7]),
+description: dco_decode_String(arr[8]),
+tags: dco_decode_list_String(arr[9]),); }
+
+@protected SchemaInfo dco_decode_schema_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SchemaInfo(slotSchemaVersion: dco_decode_usize(arr[0]),
+metadataSchemaVersion: dco_decode_usize(arr[1]),
+crateVersion: dco_decode_String(arr[2]),); }
+
+@protected SkippedSlot dco_decode_skipped_slot(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SkippedSlot(slotId: dco_decode_String(arr[0]),
+error: dco_decode_String(arr[1]),); }
+
+@protected SlotDiff dco_decode_slot_diff(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return SlotDiff(healthA: dco_decode_opt_box_autoadd_i_32(arr[0]),
+healthB: dco_decode_opt_box_autoadd_i_32(arr[1]),
+experienceA: dco_decode_opt_box_autoadd_i_32(arr[2]),
+experienceB: dco_decode_opt_box_autoadd_i_32(arr[3]),
+items: dco_decode_list_item_diff(arr[4]),); }
+
+@protected SlotEvent dco_decode_slot_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SlotEvent(slotId: dco_decode_String(arr[0]),
+kind: dco_decode_slot_event_kind(arr[1]),); }
+
+@protected SlotEventKind dco_decode_slot_event_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SlotEventKind.values[raw as int]; }
+
+@protected SlotHandle dco_decode_slot_handle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return SlotHandle(value: dco_decode_u_32(arr[0]),); }
+
+@protected SlotMaintenance dco_decode_slot_maintenance(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return SlotMaintenance(slotId: dco_decode_String(arr[0]),
+bytesReclaimed: dco_decode_u_64(arr[1]),
+vacuumed: dco_decode_bool(arr[2]),
+error: dco_decode_opt_String(arr[3]),); }
+
+@protected SlotOrder dco_decode_slot_order(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SlotOrder.values[raw as int]; }
+
+@protected SlotPreview dco_decode_slot_preview(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SlotPreview(health: dco_decode_opt_box_autoadd_i_32(arr[0]),
+experience: dco_decode_opt_box_autoadd_i_32(arr[1]),
+itemCount: dco_decode_usize(arr[2]),); }
+
+@protected StatValue dco_decode_stat_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return StatValue_Int(dco_decode_i_64(raw[1]),);
+case 1: return StatValue_Real(dco_decode_f_64(raw[1]),);
+case 2: return StatValue_Text(dco_decode_String(raw[1]),);
+case 3: return StatValue_Blob(dco_decode_list_prim_u_8_strict(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected StoreHealth dco_decode_store_health(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return StoreHealth(initialized: dco_decode_bool(arr[0]),
+activeSlotId: dco_decode_opt_String(arr[1]),
+savesDirectory: dco_decode_opt_String(arr[2]),
+freeDiskBytes: dco_decode_opt_box_autoadd_u_64(arr[3]),
+slotCount: dco_decode_opt_box_autoadd_usize(arr[4]),); }
+
+@protected TrashedSlot dco_decode_trashed_slot(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return TrashedSlot(slot: dco_decode_save_slot_metadata(arr[0]),
+deletedAt: dco_decode_String(arr[1]),); }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected RustStreamSink<ArchiveProgress> sse_decode_StreamSink_archive_progress_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<ImportProgress> sse_decode_StreamSink_import_progress_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<SlotEvent> sse_decode_StreamSink_slot_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected AggregateStats sse_decode_aggregate_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_slotCount = sse_decode_usize(deserializer);
+var var_savedSlotCount = sse_decode_usize(deserializer);
+var var_highestExperience = sse_decode_i_32(deserializer);
+var var_highestHealth = sse_decode_i_32(deserializer);
+var var_totalItems = sse_decode_usize(deserializer);
+var var_totalPlayTimeSeconds = sse_decode_i_64(deserializer);
+var var_skipped = sse_decode_list_skipped_slot(deserializer);
+return AggregateStats(slotCount: var_slotCount, savedSlotCount: var_savedSlotCount, highestExperience: var_highestExperience, highestHealth: var_highestHealth, totalItems: var_totalItems, totalPlayTimeSeconds: var_totalPlayTimeSeconds, skipped: var_skipped); }
+
+@protected ArchiveProgress sse_decode_archive_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_current = sse_decode_usize(deserializer);
+var var_total = sse_decode_usize(deserializer);
+var var_fileName = sse_decode_String(deserializer);
+return ArchiveProgress(current: var_current, total: var_total, fileName: var_fileName); }
+
+@protected AutosaveInfo sse_decode_autosave_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_index = sse_decode_u_32(deserializer);
+var var_savedAt = sse_decode_String(deserializer);
+var var_sizeBytes = sse_decode_u_64(deserializer);
+var var_filePath = sse_decode_String(deserializer);
+return AutosaveInfo(index: var_index, savedAt: var_savedAt, sizeBytes: var_sizeBytes, filePath: var_filePath); }
+
+@protected AutosaveUsage sse_decode_autosave_usage(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_count = sse_decode_u_32(deserializer);
+var var_totalBytes = sse_decode_u_64(deserializer);
+return AutosaveUsage(count: var_count, totalBytes: var_totalBytes); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_32(deserializer)); }
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_64(deserializer)); }
+
+@protected PlayerData sse_decode_box_autoadd_player_data(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_player_data(deserializer)); }
+
+@protected SaveSlotMetadata sse_decode_box_autoadd_save_slot_metadata(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_save_slot_metadata(deserializer)); }
+
+@protected SlotHandle sse_decode_box_autoadd_slot_handle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_slot_handle(deserializer)); }
+
+@protected StatValue sse_decode_box_autoadd_stat_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_stat_value(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_usize(deserializer)); }
+
+@protected ChangeEntry sse_decode_change_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sequence = sse_decode_i_64(deserializer);
+var var_timestampMs = sse_decode_i_64(deserializer);
+var var_slotId = sse_decode_String(deserializer);
+var var_operation = sse_decode_slot_event_kind(deserializer);
+var var_revision = sse_decode_opt_box_autoadd_i_64(deserializer);
+return ChangeEntry(sequence: var_sequence, timestampMs: var_timestampMs, slotId: var_slotId, operation: var_operation, revision: var_revision); }
+
+@protected CheckpointInfo sse_decode_checkpoint_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_slotId = sse_decode_String(deserializer);
+var var_label = sse_decode_String(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+var var_sizeBytes = sse_decode_u_64(deserializer);
+var var_filePath = sse_decode_String(deserializer);
+return CheckpointInfo(id: var_id, slotId: var_slotId, label: var_label, createdAt: var_createdAt, sizeBytes: var_sizeBytes, filePath: var_filePath); }
+
+@protected ExportFormat sse_decode_export_format(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ExportFormat.values[inner]; }
+
+@protected double sse_decode_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat32(); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected ImportPreview sse_decode_import_preview(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_schemaVersion = sse_decode_i_64(deserializer);
+var var_health = sse_decode_i_32(deserializer);
+var var_experience = sse_decode_i_32(deserializer);
+var var_itemCount = sse_decode_usize(deserializer);
+return ImportPreview(schemaVersion: var_schemaVersion, health: var_health, experience: var_experience, itemCount: var_itemCount); }
+
+@protected ImportProgress sse_decode_import_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_current = sse_decode_usize(deserializer);
+var var_total = sse_decode_usize(deserializer);
+var var_fileName = sse_decode_String(deserializer);
+var var_slotId = sse_decode_opt_String(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return ImportProgress(current: var_current, total: var_total, fileName: var_fileName, slotId: var_slotId, error: var_error); }
+
+@protected ImportedSlot sse_decode_imported_slot(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_slot = sse_decode_save_slot_metadata(deserializer);
+var var_format = sse_decode_export_format(deserializer);
+var var_compressed = sse_decode_bool(deserializer);
+return ImportedSlot(slot: var_slot, format: var_format, compressed: var_compressed); }
+
+@protected IntegrityReport sse_decode_integrity_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orphanedFiles = sse_decode_list_String(deserializer);
+var var_danglingRows = sse_decode_list_String(deserializer);
+return IntegrityReport(orphanedFiles: var_orphanedFiles, danglingRows: var_danglingRows); }
+
+@protected ItemChange sse_decode_item_change(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ItemChange.values[inner]; }
+
+@protected ItemDiff sse_decode_item_diff(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_item = sse_decode_String(deserializer);
+var var_change = sse_decode_item_change(deserializer);
+var var_countA = sse_decode_u_32(deserializer);
+var var_countB = sse_decode_u_32(deserializer);
+return ItemDiff(item: var_item, change: var_change, countA: var_countA, countB: var_countB); }
+
+@protected ItemRenameResult sse_decode_item_rename_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_rowsChanged = sse_decode_u_64(deserializer);
+var var_skipped = sse_decode_list_skipped_slot(deserializer);
+return ItemRenameResult(rowsChanged: var_rowsChanged, skipped: var_skipped); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<AutosaveInfo> sse_decode_list_autosave_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <AutosaveInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_autosave_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ChangeEntry> sse_decode_list_change_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ChangeEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_change_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CheckpointInfo> sse_decode_list_checkpoint_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CheckpointInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_checkpoint_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ItemDiff> sse_decode_list_item_diff(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ItemDiff>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_item_diff(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MigrationBackupInfo> sse_decode_list_migration_backup_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MigrationBackupInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_migration_backup_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<int> sse_decode_list_prim_i_32_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getInt32List(len_); }
+
+@protected Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getInt32List(len_); }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<(String,String?)> sse_decode_list_record_string_opt_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,String?)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_opt_string(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(String,String)> sse_decode_list_record_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,String)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_string(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SaveOp> sse_decode_list_save_op(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SaveOp>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_save_op(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SaveSlotMetadata> sse_decode_list_save_slot_metadata(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SaveSlotMetadata>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_save_slot_metadata(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SkippedSlot> sse_decode_list_skipped_slot(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SkippedSlot>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_skipped_slot(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SlotMaintenance> sse_decode_list_slot_maintenance(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SlotMaintenance>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_slot_maintenance(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TrashedSlot> sse_decode_list_trashed_slot(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TrashedSlot>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_trashed_slot(deserializer)); }
+        return ans_;
+         }
+
+@protected MaintenanceReport sse_decode_maintenance_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_slots = sse_decode_list_slot_maintenance(deserializer);
+var var_bytesReclaimed = sse_decode_u_64(deserializer);
+return MaintenanceReport(slots: var_slots, bytesReclaimed: var_bytesReclaimed); }
+
+@protected MergeStrategy sse_decode_merge_strategy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return MergeStrategy.values[inner]; }
+
+@protected MigrationBackupInfo sse_decode_migration_backup_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_slotId = sse_decode_String(deserializer);
+var var_savedAt = sse_decode_String(deserializer);
+var var_sizeBytes = sse_decode_u_64(deserializer);
+var var_filePath = sse_decode_String(deserializer);
+return MigrationBackupInfo(slotId: var_slotId, savedAt: var_savedAt, sizeBytes: var_sizeBytes, filePath: var_filePath); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected StatValue? sse_decode_opt_box_autoadd_stat_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_stat_value(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_usize(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_prim_u_8_strict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlayerData sse_decode_player_data(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_health = sse_decode_i_32(deserializer);
+var var_experience = sse_decode_i_32(deserializer);
+var var_inventory = sse_decode_list_String(deserializer);
+return PlayerData(health: var_health, experience: var_experience, inventory: var_inventory); }
+
+@protected (int,int) sse_decode_record_i_32_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_i_32(deserializer);
+var var_field1 = sse_decode_i_32(deserializer);
+return (var_field0, var_field1); }
+
+@protected (String,String?) sse_decode_record_string_opt_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_opt_String(deserializer);
+return (var_field0, var_field1); }
+
+@protected (String,String) sse_decode_record_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_String(deserializer);
+return (var_field0, var_field1); }
+
+@protected RepairPolicy sse_decode_repair_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return RepairPolicy.values[inner]; }
+
+@protected RepairReport sse_decode_repair_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_filesDeleted = sse_decode_usize(deserializer);
+var var_filesRegistered = sse_decode_usize(deserializer);
+var var_rowsRemoved = sse_decode_usize(deserializer);
+return RepairReport(filesDeleted: var_filesDeleted, filesRegistered: var_filesRegistered, rowsRemoved: var_rowsRemoved); }
+
+@protected SaveError sse_decode_save_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return SaveError_NotInitialized();case 1: var var_basePath = sse_decode_String(deserializer);
+return SaveError_AlreadyInitialized(basePath: var_basePath);case 2: return SaveError_WorkerUnavailable();case 3: return SaveError_OperationPanicked();case 4: return SaveError_NoActiveSlot();case 5: var var_handle = sse_decode_u_32(deserializer);
+return SaveError_UnknownHandle(handle: var_handle);case 6: var var_id = sse_decode_String(deserializer);
+return SaveError_SlotNotFound(id: var_id);case 7: var var_reason = sse_decode_String(deserializer);
+return SaveError_InvalidSlotName(reason: var_reason);case 8: var var_id = sse_decode_String(deserializer);
+return SaveError_CorruptSlot(id: var_id);case 9: var var_reason = sse_decode_String(deserializer);
+return SaveError_InvalidTag(reason: var_reason);case 10: return SaveError_NoPlayerData();case 11: var var_field = sse_decode_String(deserializer);
+var var_reason = sse_decode_String(deserializer);
+return SaveError_InvalidPlayerData(field: var_field, reason: var_reason);case 12: var var_reason = sse_decode_String(deserializer);
+return SaveError_UnsupportedFormat(reason: var_reason);case 13: var var_size = sse_decode_u_64(deserializer);
+var var_max = sse_decode_u_64(deserializer);
+return SaveError_ThumbnailTooLarge(size: var_size, max: var_max);case 14: return SaveError_AutosaveNotConfigured();case 15: var var_slotId = sse_decode_String(deserializer);
+var var_index = sse_decode_u_32(deserializer);
+return SaveError_AutosaveNotFound(slotId: var_slotId, index: var_index);case 16: var var_id = sse_decode_String(deserializer);
+return SaveError_CheckpointNotFound(id: var_id);case 17: var var_id = sse_decode_String(deserializer);
+return SaveError_SlotInUse(id: var_id);case 18: return SaveError_DecryptionFailed();case 19: var var_reason = sse_decode_String(deserializer);
+return SaveError_InvalidConfig(reason: var_reason);case 20: var var_id = sse_decode_String(deserializer);
+return SaveError_ChecksumMismatch(id: var_id);case 21: var var_current = sse_decode_i_64(deserializer);
+return SaveError_RevisionConflict(current: var_current);case 22: var var_id = sse_decode_String(deserializer);
+return SaveError_SlotLocked(id: var_id);case 23: var var_id = sse_decode_String(deserializer);
+return SaveError_SlotBusy(id: var_id);case 24: return SaveError_DiskFull();case 25: var var_id = sse_decode_String(deserializer);
+return SaveError_TransactionInProgress(id: var_id);case 26: return SaveError_NoTransaction();case 27: return SaveError_NothingToUndo();case 28: var var_missing = sse_decode_list_String(deserializer);
+return SaveError_SchemaMismatch(missing: var_missing);case 29: var var_message = sse_decode_String(deserializer);
+return SaveError_Io(message: var_message);case 30: var var_message = sse_decode_String(deserializer);
+return SaveError_Database(message: var_message);case 31: var var_message = sse_decode_String(deserializer);
+return SaveError_Migration(message: var_message); default: throw UnimplementedError(''); }
+             }
+
+@protected SaveOp sse_decode_save_op(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_health = sse_decode_i_32(deserializer);
+var var_experience = sse_decode_i_32(deserializer);
+return SaveOp_SetStats(health: var_health, experience: var_experience);case 1: var var_item = sse_decode_String(deserializer);
+var var_quantity = sse_decode_i_32(deserializer);
+return SaveOp_AddItem(item: var_item, quantity: var_quantity);case 2: var var_position = sse_decode_i_64(deserializer);
+return SaveOp_RemoveItem(position: var_position);case 3: var var_key = sse_decode_String(deserializer);
+var var_value = sse_decode_String(deserializer);
+return SaveOp_SetFlag(key: var_key, value: var_value); default: throw UnimplementedError(''); }
+             }
+
+@protected SaveResult sse_decode_save_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_statsUpdated = sse_decode_bool(deserializer);
+var var_inventoryRows = sse_decode_usize(deserializer);
+var var_revision = sse_decode_i_64(deserializer);
+return SaveResult(statsUpdated: var_statsUpdated, inventoryRows: var_inventoryRows, revision: var_revision); }
+
+@protected SaveSlotMetadata sse_decode_save_slot_metadata(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_lastPlayed = sse_decode_String(deserializer);
+var var_lastPlayedEpochMs = sse_decode_i_64(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+var var_createdAtEpochMs = sse_decode_i_64(deserializer);
+var var_filePath = sse_decode_String(deserializer);
+var var_playTimeSeconds = sse_decode_i_64(deserializer);
+var var_description = sse_decode_String(deserializer);
+var var_tags = sse_decode_list_String(deserializer);
+return SaveSlotMetadata(id: var_id, name: var_name, lastPlayed: var_lastPlayed, lastPlayedEpochMs: var_lastPlayedEpochMs, createdAt: var_createdAt, createdAtEpochMs: var_createdAtEpochMs, filePath: var_filePath, playTimeSeconds: var_playTimeSeconds, description: var_description, tags: var_tags); }
+
+@protected SchemaInfo sse_decode_schema_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_slotSchemaVersion = sse_decode_usize(deserializer);
+var var_metadataSchemaVersion = sse_decode_usize(deserializer);
+var var_crateVersion = sse_decode_String(deserializer);
+return SchemaInfo(slotSchemaVersion: var_slotSchemaVersion, metadataSchemaVersion: var_metadataSchemaVersion, crateVersion: var_crateVersion); }
+
+@protected SkippedSlot sse_decode_skipped_slot(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_slotId = sse_decode_String(deserializer);
+var var_error = sse_decode_String(deserializer);
+return SkippedSlot(slotId: var_slotId, error: var_error); }
+
+@protected SlotDiff sse_decode_slot_diff(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_healthA = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_healthB = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_experienceA = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_experienceB = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_items = sse_decode_list_item_diff(deserializer);
+return SlotDiff(healthA: var_healthA, healthB: var_healthB, experienceA: var_experienceA, experienceB: var_experienceB, items: var_items); }
+
+@protected SlotEvent sse_decode_slot_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_slotId = sse_decode_String(deserializer);
+var var_kind = sse_decode_slot_event_kind(deserializer);
+return SlotEvent(slotId: var_slotId, kind: var_kind); }
+
+@protected SlotEventKind sse_decode_slot_event_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SlotEventKind.values[inner]; }
+
+@protected SlotHandle sse_decode_slot_handle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_value = sse_decode_u_32(deserializer);
+return SlotHandle(value: var_value); }
+
+@protected SlotMaintenance sse_decode_slot_maintenance(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_slotId = sse_decode_String(deserializer);
+var var_bytesReclaimed = sse_decode_u_64(deserializer);
+var var_vacuumed = sse_decode_bool(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return SlotMaintenance(slotId: var_slotId, bytesReclaimed: var_bytesReclaimed, vacuumed: var_vacuumed, error: var_error); }
+
+@protected SlotOrder sse_decode_slot_order(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SlotOrder.values[inner]; }
+
+@protected SlotPreview sse_decode_slot_preview(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_health = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_experience = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_itemCount = sse_decode_usize(deserializer);
+return SlotPreview(health: var_health, experience: var_experience, itemCount: var_itemCount); }
+
+@protected StatValue sse_decode_stat_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_i_64(deserializer);
+return StatValue_Int(var_field0);case 1: var var_field0 = sse_decode_f_64(deserializer);
+return StatValue_Real(var_field0);case 2: var var_field0 = sse_decode_String(deserializer);
+return StatValue_Text(var_field0);case 3: var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
+return StatValue_Blob(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected StoreHealth sse_decode_store_health(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_initialized = sse_decode_bool(deserializer);
+var var_activeSlotId = sse_decode_opt_String(deserializer);
+var var_savesDirectory = sse_decode_opt_String(deserializer);
+var var_freeDiskBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_slotCount = sse_decode_opt_box_autoadd_usize(deserializer);
+return StoreHealth(initialized: var_initialized, activeSlotId: var_activeSlotId, savesDirectory: var_savesDirectory, freeDiskBytes: var_freeDiskBytes, slotCount: var_slotCount); }
+
+@protected TrashedSlot sse_decode_trashed_slot(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_slot = sse_decode_save_slot_metadata(deserializer);
+var var_deletedAt = sse_decode_String(deserializer);
+return TrashedSlot(slot: var_slot, deletedAt: var_deletedAt); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_StreamSink_archive_progress_Sse(RustStreamSink<ArchiveProgress> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_archive_progress,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_import_progress_Sse(RustStreamSink<ImportProgress> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_import_progress,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_slot_event_Sse(RustStreamSink<SlotEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_slot_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_aggregate_stats(AggregateStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.slotCount, serializer);
+sse_encode_usize(self.savedSlotCount, serializer);
+sse_encode_i_32(self.highestExperience, serializer);
+sse_encode_i_32(self.highestHealth, serializer);
+sse_encode_usize(self.totalItems, serializer);
+sse_encode_i_64(self.totalPlayTimeSeconds, serializer);
+sse_encode_list_skipped_slot(self.skipped, serializer);
+ }
+
+@protected void sse_encode_archive_progress(ArchiveProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.current, serializer);
+sse_encode_usize(self.total, serializer);
+sse_encode_String(self.fileName, serializer);
+ }
+
+@protected void sse_encode_autosave_info(AutosaveInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.index, serializer);
+sse_encode_String(self.savedAt, serializer);
+sse_encode_u_64(self.sizeBytes, serializer);
+sse_encode_String(self.filePath, serializer);
+ }
+
+@protected void sse_encode_autosave_usage(AutosaveUsage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.count, serializer);
+sse_encode_u_64(self.totalBytes, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_player_data(PlayerData self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_player_data(self, serializer); }
+
+@protected void sse_encode_box_autoadd_save_slot_metadata(SaveSlotMetadata self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_save_slot_metadata(self, serializer); }
+
+@protected void sse_encode_box_autoadd_slot_handle(SlotHandle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_slot_handle(self, serializer); }
+
+@protected void sse_encode_box_autoadd_stat_value(StatValue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_stat_value(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self, serializer); }
+
+@protected void sse_encode_change_entry(ChangeEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.sequence, serializer);
+sse_encode_i_64(self.timestampMs, serializer);
+sse_encode_String(self.slotId, serializer);
+sse_encode_slot_event_kind(self.operation, serializer);
+sse_encode_opt_box_autoadd_i_64(self.revision, serializer);
+ }
+
+@protected void sse_encode_checkpoint_info(CheckpointInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.slotId, serializer);
+sse_encode_String(self.label, serializer);
+sse_encode_String(self.createdAt, serializer);
+sse_encode_u_64(self.sizeBytes, serializer);
+sse_encode_String(self.filePath, serializer);
+ }
+
+@protected void sse_encode_export_format(ExportFormat self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat32(self); }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_import_preview(ImportPreview self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.schemaVersion, serializer);
+sse_encode_i_32(self.health, serializer);
+sse_encode_i_32(self.experience, serializer);
+sse_encode_usize(self.itemCount, serializer);
+ }
+
+@protected void sse_encode_import_progress(ImportProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.current, serializer);
+sse_encode_usize(self.total, serializer);
+sse_encode_String(self.fileName, serializer);
+sse_encode_opt_String(self.slotId, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_imported_slot(ImportedSlot self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_save_slot_metadata(self.slot, serializer);
+sse_encode_export_format(self.format, serializer);
+sse_encode_bool(self.compressed, serializer);
+ }
+
+@protected void sse_encode_integrity_report(IntegrityReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.orphanedFiles, serializer);
+sse_encode_list_String(self.danglingRows, serializer);
+ }
+
+@protected void sse_encode_item_change(ItemChange self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_item_diff(ItemDiff self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.item, serializer);
+sse_encode_item_change(self.change, serializer);
+sse_encode_u_32(self.countA, serializer);
+sse_encode_u_32(self.countB, serializer);
+ }
+
+@protected void sse_encode_item_rename_result(ItemRenameResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.rowsChanged, serializer);
+sse_encode_list_skipped_slot(self.skipped, serializer);
+ }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_autosave_info(List<AutosaveInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_autosave_info(item, serializer); } }
+
+@protected void sse_encode_list_change_entry(List<ChangeEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_change_entry(item, serializer); } }
+
+@protected void sse_encode_list_checkpoint_info(List<CheckpointInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_checkpoint_info(item, serializer); } }
+
+@protected void sse_encode_list_item_diff(List<ItemDiff> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_item_diff(item, serializer); } }
+
+@protected void sse_encode_list_migration_backup_info(List<MigrationBackupInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_migration_backup_info(item, serializer); } }
+
+@protected void sse_encode_list_prim_i_32_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putInt32List(self is Int32List ? self : Int32List.fromList(self)); }
+
+@protected void sse_encode_list_prim_i_32_strict(Int32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putInt32List(self); }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_record_string_opt_string(List<(String,String?)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_opt_string(item, serializer); } }
+
+@protected void sse_encode_list_record_string_string(List<(String,String)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_string(item, serializer); } }
+
+@protected void sse_encode_list_save_op(List<SaveOp> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_save_op(item, serializer); } }
+
+@protected void sse_encode_list_save_slot_metadata(List<SaveSlotMetadata> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_save_slot_metadata(item, serializer); } }
+
+@protected void sse_encode_list_skipped_slot(List<SkippedSlot> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_skipped_slot(item, serializer); } }
+
+@protected void sse_encode_list_slot_maintenance(List<SlotMaintenance> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_slot_maintenance(item, serializer); } }
+
+@protected void sse_encode_list_trashed_slot(List<TrashedSlot> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_trashed_slot(item, serializer); } }
+
+@protected void sse_encode_maintenance_report(MaintenanceReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_slot_maintenance(self.slots, serializer);
+sse_encode_u_64(self.bytesReclaimed, serializer);
+ }
+
+@protected void sse_encode_merge_strategy(MergeStrategy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_migration_backup_info(MigrationBackupInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.slotId, serializer);
+sse_encode_String(self.savedAt, serializer);
+sse_encode_u_64(self.sizeBytes, serializer);
+sse_encode_String(self.filePath, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_stat_value(StatValue? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_stat_value(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_usize(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_usize(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_prim_u_8_strict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_player_data(PlayerData self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.health, serializer);
+sse_encode_i_32(self.experience, serializer);
+sse_encode_list_String(self.inventory, serializer);
+ }
+
+@protected void sse_encode_record_i_32_i_32((int,int) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.$1, serializer);
+sse_encode_i_32(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_string_opt_string((String,String?) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_opt_String(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_string_string((String,String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_String(self.$2, serializer);
+ }
+
+@protected void sse_encode_repair_policy(RepairPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_repair_report(RepairReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.filesDeleted, serializer);
+sse_encode_usize(self.filesRegistered, serializer);
+sse_encode_usize(self.rowsRemoved, serializer);
+ }
+
+@protected void sse_encode_save_error(SaveError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case SaveError_NotInitialized(): sse_encode_i_32(0, serializer); case SaveError_AlreadyInitialized(basePath: final basePath): sse_encode_i_32(1, serializer); sse_encode_String(basePath, serializer);
+case SaveError_WorkerUnavailable(): sse_encode_i_32(2, serializer); case SaveError_OperationPanicked(): sse_encode_i_32(3, serializer); case SaveError_NoActiveSlot(): sse_encode_i_32(4, serializer); case SaveError_UnknownHandle(handle: final handle): sse_encode_i_32(5, serializer); sse_encode_u_32(handle, serializer);
+case SaveError_SlotNotFound(id: final id): sse_encode_i_32(6, serializer); sse_encode_String(id, serializer);
+case SaveError_InvalidSlotName(reason: final reason): sse_encode_i_32(7, serializer); sse_encode_String(reason, serializer);
+case SaveError_CorruptSlot(id: final id): sse_encode_i_32(8, serializer); sse_encode_String(id, serializer);
+case SaveError_InvalidTag(reason: final reason): sse_encode_i_32(9, serializer); sse_encode_String(reason, serializer);
+case SaveError_NoPlayerData(): sse_encode_i_32(10, serializer); case SaveError_InvalidPlayerData(field: final field,reason: final reason): sse_encode_i_32(11, serializer); sse_encode_String(field, serializer);
+sse_encode_String(reason, serializer);
+case SaveError_UnsupportedFormat(reason: final reason): sse_encode_i_32(12, serializer); sse_encode_String(reason, serializer);
+case SaveError_ThumbnailTooLarge(size: final size,max: final max): sse_encode_i_32(13, serializer); sse_encode_u_64(size, serializer);
+sse_encode_u_64(max, serializer);
+case SaveError_AutosaveNotConfigured(): sse_encode_i_32(14, serializer); case SaveError_AutosaveNotFound(slotId: final slotId,index: final index): sse_encode_i_32(15, serializer); sse_encode_String(slotId, serializer);
+sse_encode_u_32(index, serializer);
+case SaveError_CheckpointNotFound(id: final id): sse_encode_i_32(16, serializer); sse_encode_String(id, serializer);
+case SaveError_SlotInUse(id: final id): sse_encode_i_32(17, serializer); sse_encode_String(id, serializer);
+case SaveError_DecryptionFailed(): sse_encode_i_32(18, serializer); case SaveError_InvalidConfig(reason: final reason): sse_encode_i_32(19, serializer); sse_encode_String(reason, serializer);
+case SaveError_ChecksumMismatch(id: final id): sse_encode_i_32(20, serializer); sse_encode_String(id, serializer);
+case SaveError_RevisionConflict(current: final current): sse_encode_i_32(21, serializer); sse_encode_i_64(current, serializer);
+case SaveError_SlotLocked(id: final id): sse_encode_i_32(22, serializer); sse_encode_String(id, serializer);
+case SaveError_SlotBusy(id: final id): sse_encode_i_32(23, serializer); sse_encode_String(id, serializer);
+case SaveError_DiskFull(): sse_encode_i_32(24, serializer); case SaveError_TransactionInProgress(id: final id): sse_encode_i_32(25, serializer); sse_encode_String(id, serializer);
+case SaveError_NoTransaction(): sse_encode_i_32(26, serializer); case SaveError_NothingToUndo(): sse_encode_i_32(27, serializer); case SaveError_SchemaMismatch(missing: final missing): sse_encode_i_32(28, serializer); sse_encode_list_String(missing, serializer);
+case SaveError_Io(message: final message): sse_encode_i_32(29, serializer); sse_encode_String(message, serializer);
+case SaveError_Database(message: final message): sse_encode_i_32(30, serializer); sse_encode_String(message, serializer);
+case SaveError_Migration(message: final message): sse_encode_i_32(31, serializer); sse_encode_String(message, serializer);
+  } }
+
+@protected void sse_encode_save_op(SaveOp self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case SaveOp_SetStats(health: final health,experience: final experience): sse_encode_i_32(0, serializer); sse_encode_i_32(health, serializer);
+sse_encode_i_32(experience, serializer);
+case SaveOp_AddItem(item: final item,quantity: final quantity): sse_encode_i_32(1, serializer); sse_encode_String(item, serializer);
+sse_encode_i_32(quantity, serializer);
+case SaveOp_RemoveItem(position: final position): sse_encode_i_32(2, serializer); sse_encode_i_64(position, serializer);
+case SaveOp_SetFlag(key: final key,value: final value): sse_encode_i_32(3, serializer); sse_encode_String(key, serializer);
+sse_encode_String(value, serializer);
+  } }
+
+@protected void sse_encode_save_result(SaveResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.statsUpdated, serializer);
+sse_encode_usize(self.inventoryRows, serializer);
+sse_encode_i_64(self.revision, serializer);
+ }
+
+@protected void sse_encode_save_slot_metadata(SaveSlotMetadata self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.lastPlayed, serializer);
+sse_encode_i_64(self.lastPlayedEpochMs, serializer);
+sse_encode_String(self.createdAt, serializer);
+sse_encode_i_64(self.createdAtEpochMs, serializer);
+sse_encode_String(self.filePath, serializer);
+sse_encode_i_64(self.playTimeSeconds, serializer);
+sse_encode_String(self.description, serializer);
+sse_encode_list_String(self.tags, serializer);
+ }
+
+@protected void sse_encode_schema_info(SchemaInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.slotSchemaVersion, serializer);
+sse_encode_usize(self.metadataSchemaVersion, serializer);
+sse_encode_String(self.crateVersion, serializer);
+ }
+
+@protected void sse_encode_skipped_slot(SkippedSlot self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.slotId, serializer);
+sse_encode_String(self.error, serializer);
+ }
+
+@protected void sse_encode_slot_diff(SlotDiff self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_i_32(self.healthA, serializer);
+sse_encode_opt_box_autoadd_i_32(self.healthB, serializer);
+sse_encode_opt_box_autoadd_i_32(self.experienceA, serializer);
+sse_encode_opt_box_autoadd_i_32(self.experienceB, serializer);
+sse_encode_list_item_diff(self.items, serializer);
+ }
+
+@protected void sse_encode_slot_event(SlotEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.slotId, serializer);
+sse_encode_slot_event_kind(self.kind, serializer);
+ }
+
+@protected void sse_encode_slot_event_kind(SlotEventKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_slot_handle(SlotHandle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.value, serializer);
+ }
+
+@protected void sse_encode_slot_maintenance(SlotMaintenance self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.slotId, serializer);
+sse_encode_u_64(self.bytesReclaimed, serializer);
+sse_encode_bool(self.vacuumed, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_slot_order(SlotOrder self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_slot_preview(SlotPreview self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_i_32(self.health, serializer);
+sse_encode_opt_box_autoadd_i_32(self.experience, serializer);
+sse_encode_usize(self.itemCount, serializer);
+ }
+
+@protected void sse_encode_stat_value(StatValue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case StatValue_Int(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_i_64(field0, serializer);
+case StatValue_Real(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_f_64(field0, serializer);
+case StatValue_Text(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_String(field0, serializer);
+case StatValue_Blob(field0: final field0): sse_encode_i_32(3, serializer); sse_encode_list_prim_u_8_strict(field0, serializer);
+  } }
+
+@protected void sse_encode_store_health(StoreHealth self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.initialized, serializer);
+sse_encode_opt_String(self.activeSlotId, serializer);
+sse_encode_opt_String(self.savesDirectory, serializer);
+sse_encode_opt_box_autoadd_u_64(self.freeDiskBytes, serializer);
+sse_encode_opt_box_autoadd_usize(self.slotCount, serializer);
+ }
+
+@protected void sse_encode_trashed_slot(TrashedSlot self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_save_slot_metadata(self.slot, serializer);
+sse_encode_String(self.deletedAt, serializer);
+ }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+                }
+                
@@ -3,84 +3,560 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
+import 'api/leveling.dart';
+import 'api/saves.dart';
 import 'api/simple.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  @protected
-  String dco_decode_String(dynamic raw);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+@protected RustStreamSink<ArchiveProgress> dco_decode_StreamSink_archive_progress_Sse(dynamic raw);
+
+@protected RustStreamSink<ImportProgress> dco_decode_StreamSink_import_progress_Sse(dynamic raw);
+
+@protected RustStreamSink<SlotEvent> dco_decode_StreamSink_slot_event_Sse(dynamic raw);
+
+@protected String dco_decode_String(dynamic raw);
+
+@protected AggregateStats dco_decode_aggregate_stats(dynamic raw);
+
+@protected ArchiveProgress dco_decode_archive_progress(dynamic raw);
+
+@protected AutosaveInfo dco_decode_autosave_info(dynamic raw);
+
+@protected AutosaveUsage dco_decode_autosave_usage(dynamic raw);
+
+@protected bool dco_decode_bool(dynamic raw);
+
+@protected int dco_decode_box_autoadd_i_32(dynamic raw);
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+@protected PlayerData dco_decode_box_autoadd_player_data(dynamic raw);
+
+@protected SaveSlotMetadata dco_decode_box_autoadd_save_slot_metadata(dynamic raw);
+
+@protected SlotHandle dco_decode_box_autoadd_slot_handle(dynamic raw);
+
+@protected StatValue dco_decode_box_autoadd_stat_value(dynamic raw);
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+@protected BigInt dco_decode_box_autoadd_usize(dynamic raw);
+
+@protected ChangeEntry dco_decode_change_entry(dynamic raw);
+
+@protected CheckpointInfo dco_decode_checkpoint_info(dynamic raw);
+
+@protected ExportFormat dco_decode_export_format(dynamic raw);
+
+@protected double dco_decode_f_32(dynamic raw);
+
+@protected double dco_decode_f_64(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
+
+@protected ImportPreview dco_decode_import_preview(dynamic raw);
+
+@protected ImportProgress dco_decode_import_progress(dynamic raw);
+
+@protected ImportedSlot dco_decode_imported_slot(dynamic raw);
+
+@protected IntegrityReport dco_decode_integrity_report(dynamic raw);
+
+@protected ItemChange dco_decode_item_change(dynamic raw);
+
+@protected ItemDiff dco_decode_item_diff(dynamic raw);
+
+@protected ItemRenameResult dco_decode_item_rename_result(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<AutosaveInfo> dco_decode_list_autosave_info(dynamic raw);
+
+@protected List<ChangeEntry> dco_decode_list_change_entry(dynamic raw);
+
+@protected List<CheckpointInfo> dco_decode_list_checkpoint_info(dynamic raw);
+
+@protected List<ItemDiff> dco_decode_list_item_diff(dynamic raw);
+
+@protected List<MigrationBackupInfo> dco_decode_list_migration_backup_info(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_i_32_loose(dynamic raw);
+
+@protected Int32List dco_decode_list_prim_i_32_strict(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<(String,String?)> dco_decode_list_record_string_opt_string(dynamic raw);
+
+@protected List<(String,String)> dco_decode_list_record_string_string(dynamic raw);
+
+@protected List<SaveOp> dco_decode_list_save_op(dynamic raw);
+
+@protected List<SaveSlotMetadata> dco_decode_list_save_slot_metadata(dynamic raw);
+
+@protected List<SkippedSlot> dco_decode_list_skipped_slot(dynamic raw);
+
+@protected List<SlotMaintenance> dco_decode_list_slot_maintenance(dynamic raw);
+
+@protected List<TrashedSlot> dco_decode_list_trashed_slot(dynamic raw);
+
+@protected MaintenanceReport dco_decode_maintenance_report(dynamic raw);
+
+@protected MergeStrategy dco_decode_merge_strategy(dynamic raw);
+
+@protected MigrationBackupInfo dco_decode_migration_backup_info(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+@protected StatValue? dco_decode_opt_box_autoadd_stat_value(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw);
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+@protected PlayerData dco_decode_player_data(dynamic raw);
+
+@protected (int,int) dco_decode_record_i_32_i_32(dynamic raw);
+
+@protected (String,String?) dco_decode_record_string_opt_string(dynamic raw);
+
+@protected (String,String) dco_decode_record_string_string(dynamic raw);
+
+@protected RepairPolicy dco_decode_repair_policy(dynamic raw);
+
+@protected RepairReport dco_decode_repair_report(dynamic raw);
+
+@protected SaveError dco_decode_save_error(dynamic raw);
+
+@protected SaveOp dco_decode_save_op(dynamic raw);
+
+@protected SaveResult dco_decode_save_result(dynamic raw);
+
+@protected SaveSlotMetadata dco_decode_save_slot_metadata(dynamic raw);
+
+@protected SchemaInfo dco_decode_schema_info(dynamic raw);
+
+@protected SkippedSlot dco_decode_skipped_slot(dynamic raw);
+
+@protected SlotDiff dco_decode_slot_diff(dynamic raw);
+
+@protected SlotEvent dco_decode_slot_event(dynamic raw);
+
+@protected SlotEventKind dco_decode_slot_event_kind(dynamic raw);
+
+@protected SlotHandle dco_decode_slot_handle(dynamic raw);
+
+@protected SlotMaintenance dco_decode_slot_maintenance(dynamic raw);
+
+@protected SlotOrder dco_decode_slot_order(dynamic raw);
+
+@protected SlotPreview dco_decode_slot_preview(dynamic raw);
+
+@protected StatValue dco_decode_stat_value(dynamic raw);
+
+@protected StoreHealth dco_decode_store_health(dynamic raw);
+
+@protected TrashedSlot dco_decode_trashed_slot(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected RustStreamSink<ArchiveProgress> sse_decode_StreamSink_archive_progress_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<ImportProgress> sse_decode_StreamSink_import_progress_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<SlotEvent> sse_decode_StreamSink_slot_event_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected AggregateStats sse_decode_aggregate_stats(SseDeserializer deserializer);
+
+@protected ArchiveProgress sse_decode_archive_progress(SseDeserializer deserializer);
+
+@protected AutosaveInfo sse_decode_autosave_info(SseDeserializer deserializer);
+
+@protected AutosaveUsage sse_decode_autosave_usage(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected PlayerData sse_decode_box_autoadd_player_data(SseDeserializer deserializer);
+
+@protected SaveSlotMetadata sse_decode_box_autoadd_save_slot_metadata(SseDeserializer deserializer);
+
+@protected SlotHandle sse_decode_box_autoadd_slot_handle(SseDeserializer deserializer);
+
+@protected StatValue sse_decode_box_autoadd_stat_value(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer);
+
+@protected ChangeEntry sse_decode_change_entry(SseDeserializer deserializer);
+
+@protected CheckpointInfo sse_decode_checkpoint_info(SseDeserializer deserializer);
+
+@protected ExportFormat sse_decode_export_format(SseDeserializer deserializer);
+
+@protected double sse_decode_f_32(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected ImportPreview sse_decode_import_preview(SseDeserializer deserializer);
+
+@protected ImportProgress sse_decode_import_progress(SseDeserializer deserializer);
+
+@protected ImportedSlot sse_decode_imported_slot(SseDeserializer deserializer);
+
+@protected IntegrityReport sse_decode_integrity_report(SseDeserializer deserializer);
+
+@protected ItemChange sse_decode_item_change(SseDeserializer deserializer);
+
+@protected ItemDiff sse_decode_item_diff(SseDeserializer deserializer);
+
+@protected ItemRenameResult sse_decode_item_rename_result(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<AutosaveInfo> sse_decode_list_autosave_info(SseDeserializer deserializer);
+
+@protected List<ChangeEntry> sse_decode_list_change_entry(SseDeserializer deserializer);
+
+@protected List<CheckpointInfo> sse_decode_list_checkpoint_info(SseDeserializer deserializer);
+
+@protected List<ItemDiff> sse_decode_list_item_diff(SseDeserializer deserializer);
+
+@protected List<MigrationBackupInfo> sse_decode_list_migration_backup_info(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_i_32_loose(SseDeserializer deserializer);
+
+@protected Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<(String,String?)> sse_decode_list_record_string_opt_string(SseDeserializer deserializer);
+
+@protected List<(String,String)> sse_decode_list_record_string_string(SseDeserializer deserializer);
+
+@protected List<SaveOp> sse_decode_list_save_op(SseDeserializer deserializer);
+
+@protected List<SaveSlotMetadata> sse_decode_list_save_slot_metadata(SseDeserializer deserializer);
+
+@protected List<SkippedSlot> sse_decode_list_skipped_slot(SseDeserializer deserializer);
+
+@protected List<SlotMaintenance> sse_decode_list_slot_maintenance(SseDeserializer deserializer);
+
+@protected List<TrashedSlot> sse_decode_list_trashed_slot(SseDeserializer deserializer);
+
+@protected MaintenanceReport sse_decode_maintenance_report(SseDeserializer deserializer);
+
+@protected MergeStrategy sse_decode_merge_strategy(SseDeserializer deserializer);
+
+@protected MigrationBackupInfo sse_decode_migration_backup_info(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected StatValue? sse_decode_opt_box_autoadd_stat_value(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer);
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected PlayerData sse_decode_player_data(SseDeserializer deserializer);
+
+@protected (int,int) sse_decode_record_i_32_i_32(SseDeserializer deserializer);
+
+@protected (String,String?) sse_decode_record_string_opt_string(SseDeserializer deserializer);
+
+@protected (String,String) sse_decode_record_string_string(SseDeserializer deserializer);
+
+@protected RepairPolicy sse_decode_repair_policy(SseDeserializer deserializer);
+
+@protected RepairReport sse_decode_repair_report(SseDeserializer deserializer);
+
+@protected SaveError sse_decode_save_error(SseDeserializer deserializer);
+
+@protected SaveOp sse_decode_save_op(SseDeserializer deserializer);
+
+@protected SaveResult sse_decode_save_result(SseDeserializer deserializer);
+
+@protected SaveSlotMetadata sse_decode_save_slot_metadata(SseDeserializer deserializer);
+
+@protected SchemaInfo sse_decode_schema_info(SseDeserializer deserializer);
+
+@protected SkippedSlot sse_decode_skipped_slot(SseDeserializer deserializer);
+
+@protected SlotDiff sse_decode_slot_diff(SseDeserializer deserializer);
+
+@protected SlotEvent sse_decode_slot_event(SseDeserializer deserializer);
+
+@protected SlotEventKind sse_decode_slot_event_kind(SseDeserializer deserializer);
+
+@protected SlotHandle sse_decode_slot_handle(SseDeserializer deserializer);
+
+@protected SlotMaintenance sse_decode_slot_maintenance(SseDeserializer deserializer);
+
+@protected SlotOrder sse_decode_slot_order(SseDeserializer deserializer);
+
+@protected SlotPreview sse_decode_slot_preview(SseDeserializer deserializer);
+
+@protected StatValue sse_decode_stat_value(SseDeserializer deserializer);
+
+@protected StoreHealth sse_decode_store_health(SseDeserializer deserializer);
+
+@protected TrashedSlot sse_decode_trashed_slot(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_archive_progress_Sse(RustStreamSink<ArchiveProgress> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_import_progress_Sse(RustStreamSink<ImportProgress> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_slot_event_Sse(RustStreamSink<SlotEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_aggregate_stats(AggregateStats self, SseSerializer serializer);
+
+@protected void sse_encode_archive_progress(ArchiveProgress self, SseSerializer serializer);
+
+@protected void sse_encode_autosave_info(AutosaveInfo self, SseSerializer serializer);
+
+@protected void sse_encode_autosave_usage(AutosaveUsage self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_player_data(PlayerData self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_save_slot_metadata(SaveSlotMetadata self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_slot_handle(SlotHandle self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_stat_value(StatValue self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_change_entry(ChangeEntry self, SseSerializer serializer);
+
+@protected void sse_encode_checkpoint_info(CheckpointInfo self, SseSerializer serializer);
+
+@protected void sse_encode_export_format(ExportFormat self, SseSerializer serializer);
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_import_preview(ImportPreview self, SseSerializer serializer);
+
+@protected void sse_encode_import_progress(ImportProgress self, SseSerializer serializer);
+
+@protected void sse_encode_imported_slot(ImportedSlot self, SseSerializer serializer);
+
+@protected void sse_encode_integrity_report(IntegrityReport self, SseSerializer serializer);
+
+@protected void sse_encode_item_change(ItemChange self, SseSerializer serializer);
+
+@protected void sse_encode_item_diff(ItemDiff self, SseSerializer serializer);
+
+@protected void sse_encode_item_rename_result(ItemRenameResult self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_autosave_info(List<AutosaveInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_change_entry(List<ChangeEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_list_checkpoint_info(List<CheckpointInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_item_diff(List<ItemDiff> self, SseSerializer serializer);
+
+@protected void sse_encode_list_migration_backup_info(List<MigrationBackupInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_i_32_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_i_32_strict(Int32List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_opt_string(List<(String,String?)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_string(List<(String,String)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_save_op(List<SaveOp> self, SseSerializer serializer);
+
+@protected void sse_encode_list_save_slot_metadata(List<SaveSlotMetadata> self, SseSerializer serializer);
+
+@protected void sse_encode_list_skipped_slot(List<SkippedSlot> self, SseSerializer serializer);
+
+@protected void sse_encode_list_slot_maintenance(List<SlotMaintenance> self, SseSerializer serializer);
+
+@protected void sse_encode_list_trashed_slot(List<TrashedSlot> self, SseSerializer serializer);
+
+@protected void sse_encode_maintenance_report(MaintenanceReport self, SseSerializer serializer);
+
+@protected void sse_encode_merge_strategy(MergeStrategy self, SseSerializer serializer);
+
+@protected void sse_encode_migration_backup_info(MigrationBackupInfo self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_stat_value(StatValue? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_usize(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer);
+
+@protected void sse_encode_player_data(PlayerData self, SseSerializer serializer);
+
+@protected void sse_encode_record_i_32_i_32((int,int) self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_opt_string((String,String?) self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_string((String,String) self, SseSerializer serializer);
+
+@protected void sse_encode_repair_policy(RepairPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_repair_report(RepairReport self, SseSerializer serializer);
+
+@protected void sse_encode_save_error(SaveError self, SseSerializer serializer);
+
+@protected void sse_encode_save_op(SaveOp self, SseSerializer serializer);
+
+@protected void sse_encode_save_result(SaveResult self, SseSerializer serializer);
+
+@protected void sse_encode_save_slot_metadata(SaveSlotMetadata self, SseSerializer serializer);
+
+@protected void sse_encode_schema_info(SchemaInfo self, SseSerializer serializer);
+
+@protected void sse_encode_skipped_slot(SkippedSlot self, SseSerializer serializer);
+
+@protected void sse_encode_slot_diff(SlotDiff self, SseSerializer serializer);
+
+@protected void sse_encode_slot_event(SlotEvent self, SseSerializer serializer);
+
+@protected void sse_encode_slot_event_kind(SlotEventKind self, SseSerializer serializer);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected void sse_encode_slot_handle(SlotHandle self, SseSerializer serializer);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected void sse_encode_slot_maintenance(SlotMaintenance self, SseSerializer serializer);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected void sse_encode_slot_order(SlotOrder self, SseSerializer serializer);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected void sse_encode_slot_preview(SlotPreview self, SseSerializer serializer);
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+@protected void sse_encode_stat_value(StatValue self, SseSerializer serializer);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected void sse_encode_store_health(StoreHealth self, SseSerializer serializer);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected void sse_encode_trashed_slot(TrashedSlot self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected void sse_encode_unit(void self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
 class RustLibWire implements BaseWire {
-  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
-}
+            RustLibWire.fromExternalLibrary(ExternalLibrary lib);
 
-@JS('wasm_bindgen')
-external RustLibWasmModule get wasmModule;
+            
+        }
+        @JS('wasm_bindgen') external RustLibWasmModule get wasmModule;
 
-@JS()
-@anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {}
+        @JS() @anonymous extension type RustLibWasmModule._(JSObject _) implements JSObject {
+            
+        }
+        
\ No newline at end of file
@@ -0,0 +1,28 @@
+//! The experience-to-level curve, kept on the Rust side so Dart doesn't
+//! re-derive levels with its own copy of the formula.
+
+use std::sync::{PoisonError, RwLock};
+
+/// Experience needed for each level, ascending; index `n` is level `n`.
+/// Shared by every caller and not tied to the save system, so it needs no
+/// `init_system`.
+static LEVEL_CURVE: RwLock<Vec<i32>> = RwLock::new(Vec::new());
+
+/// Replaces the leveling curve. `thresholds[n]` is the experience at which
+/// level `n` starts; the list is sorted if it isn't already.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_level_curve(mut thresholds: Vec<i32>) {
+    thresholds.sort_unstable();
+    // A panic can't leave the curve half-written, so poisoning is safe to
+    // ignore.
+    *LEVEL_CURVE.write().unwrap_or_else(PoisonError::into_inner) = thresholds;
+}
+
+/// The index of the highest threshold not above `experience`. Experience
+/// below the first threshold, or an empty curve, counts as level 0.
+#[flutter_rust_bridge::frb(sync)]
+pub fn level_for_experience(experience: i32) -> i32 {
+    let curve = LEVEL_CURVE.read().unwrap_or_else(PoisonError::into_inner);
+    let reached = curve.partition_point(|&threshold| threshold <= experience);
+    i32::try_from(reached.saturating_sub(1)).unwrap_or(i32::MAX)
+}
@@ -1 +1,3 @@
+pub mod leveling;
+pub mod saves;
 pub mod simple;
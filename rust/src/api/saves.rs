@@ -0,0 +1,1768 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+use crate::frb_generated::StreamSink;
+use crate::save_manager::autosave_timer;
+use crate::save_manager::worker::{self, WorkerError};
+use crate::save_manager::{
+    self, SaveConfig, SaveManager, SaveManagerError, SaveManagerResult, DEFAULT_HANDLE,
+};
+
+pub struct PlayerData {
+    pub health: i32,
+    pub experience: i32,
+    pub inventory: Vec<String>,
+}
+
+/// A player stat stored with `set_stat`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum StatValue {
+    Int(i64),
+    Real(f64),
+    Text(String),
+    Blob(Vec<u8>),
+}
+
+/// One change in an `apply_batch` call.
+pub enum SaveOp {
+    SetStats {
+        health: i32,
+        experience: i32,
+    },
+    /// Appends `quantity` copies of `item` to the inventory.
+    AddItem {
+        item: String,
+        quantity: i32,
+    },
+    /// Removes the item at `position`; later items move up one.
+    RemoveItem {
+        position: i64,
+    },
+    SetFlag {
+        key: String,
+        value: String,
+    },
+}
+
+/// What a save actually wrote, so callers can assert the data landed.
+pub struct SaveResult {
+    pub stats_updated: bool,
+    /// Inventory rows written; equals the inventory length on success.
+    pub inventory_rows: usize,
+    /// The slot's revision after this save, to pass to the next
+    /// `save_player_data_checked`.
+    pub revision: i64,
+}
+
+/// Opaque reference to a slot opened with `open_slot`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SlotHandle {
+    pub value: u32,
+}
+
+pub struct SaveSlotMetadata {
+    pub id: String,
+    pub name: String,
+    /// `%Y-%m-%d %H:%M:%S` in UTC.
+    pub last_played: String,
+    /// The same moment as Unix epoch milliseconds, which Dart can turn into a
+    /// `DateTime` without parsing.
+    pub last_played_epoch_ms: i64,
+    pub created_at: String,
+    /// `created_at` as Unix epoch milliseconds, or 0 if the stored value
+    /// isn't a valid timestamp.
+    pub created_at_epoch_ms: i64,
+    pub file_path: String,
+    pub play_time_seconds: i64,
+    /// Player-written notes, empty if none were left.
+    pub description: String,
+    /// Filled in by `get_slot` only; list queries leave it empty to stay
+    /// cheap.
+    pub tags: Vec<String>,
+}
+
+impl SaveSlotMetadata {
+    /// `last_played` as ISO 8601 in UTC with millisecond precision, e.g.
+    /// `2024-05-01T18:30:00.250Z`, for display.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn last_played_iso8601(&self) -> String {
+        save_manager::iso8601_from_epoch_ms(self.last_played_epoch_ms)
+    }
+}
+
+/// Sort orders accepted by `get_slots_page`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SlotOrder {
+    LastPlayedDesc,
+    LastPlayedAsc,
+    NameAsc,
+    NameDesc,
+    CreatedDesc,
+    CreatedAsc,
+}
+
+/// Encodings produced by the export functions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    Binary,
+}
+
+/// A slot created by `import_slot`, and what the import turned out to be.
+pub struct ImportedSlot {
+    pub slot: SaveSlotMetadata,
+    pub format: ExportFormat,
+    pub compressed: bool,
+}
+
+/// Where the saves directory and the slot metadata disagree, as found by
+/// `scan_integrity`.
+pub struct IntegrityReport {
+    /// Slot database files that no slot row points at.
+    pub orphaned_files: Vec<String>,
+    /// Ids of slot rows whose database file is missing.
+    pub dangling_rows: Vec<String>,
+}
+
+/// What `repair_integrity` does with orphaned files. Dangling rows are always
+/// removed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RepairPolicy {
+    DeleteOrphans,
+    /// Adds each readable orphan back to the slot list as a new slot.
+    RegisterOrphans,
+}
+
+pub struct RepairReport {
+    pub files_deleted: usize,
+    pub files_registered: usize,
+    pub rows_removed: usize,
+}
+
+/// What `run_maintenance` did to one slot.
+pub struct SlotMaintenance {
+    pub slot_id: String,
+    pub bytes_reclaimed: u64,
+    pub vacuumed: bool,
+    /// Why the slot was skipped, e.g. because it is corrupt.
+    pub error: Option<String>,
+}
+
+pub struct MaintenanceReport {
+    pub slots: Vec<SlotMaintenance>,
+    /// Sum over all slots.
+    pub bytes_reclaimed: u64,
+}
+
+/// Totals over every slot, from `aggregate_stats`.
+pub struct AggregateStats {
+    /// Live slots, whether or not they were ever saved.
+    pub slot_count: usize,
+    /// Slots that hold player data and went into the totals below.
+    pub saved_slot_count: usize,
+    pub highest_experience: i32,
+    pub highest_health: i32,
+    /// Inventory items summed over every slot.
+    pub total_items: usize,
+    pub total_play_time_seconds: i64,
+    /// Slots that couldn't be read and were left out.
+    pub skipped: Vec<SkippedSlot>,
+}
+
+/// A slot an all-slots scan had to leave out, and why.
+pub struct SkippedSlot {
+    pub slot_id: String,
+    pub error: String,
+}
+
+/// What `rename_item_everywhere` changed.
+pub struct ItemRenameResult {
+    /// Inventory entries renamed, across all slots.
+    pub rows_changed: u64,
+    /// Slots that couldn't be updated; the others were.
+    pub skipped: Vec<SkippedSlot>,
+}
+
+/// How two slots differ, from `diff_slots`. Stats are `None` for a slot that
+/// was never saved.
+pub struct SlotDiff {
+    pub health_a: Option<i32>,
+    pub health_b: Option<i32>,
+    pub experience_a: Option<i32>,
+    pub experience_b: Option<i32>,
+    /// Only items whose count differs, sorted by name.
+    pub items: Vec<ItemDiff>,
+}
+
+/// How `merge_inventories` adds one slot's items to another's.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MergeStrategy {
+    /// Every incoming item goes on the end, in its original order.
+    Append,
+    /// Incoming items the inventory already holds are dropped, as are
+    /// repeats among the incoming ones.
+    DedupeByItem,
+    /// Every incoming item is kept, next to the existing copies of the same
+    /// item so each item's count simply adds up; new items go on the end.
+    SumQuantities,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ItemChange {
+    /// Only slot `b` has the item.
+    Added,
+    /// Only slot `a` has the item.
+    Removed,
+    /// Both have it, in different numbers.
+    QuantityChanged,
+}
+
+pub struct ItemDiff {
+    pub item: String,
+    pub change: ItemChange,
+    pub count_a: u32,
+    pub count_b: u32,
+}
+
+/// A slot in the trash, and when it was put there.
+pub struct TrashedSlot {
+    pub slot: SaveSlotMetadata,
+    pub deleted_at: String,
+}
+
+/// A slot's headline numbers from `preview_slot`. Stats are `None` for a slot
+/// that was never saved.
+pub struct SlotPreview {
+    pub health: Option<i32>,
+    pub experience: Option<i32>,
+    pub item_count: usize,
+}
+
+/// The store's state for a diagnostics panel, from `store_health`. Values
+/// that couldn't be read, or that need an initialized system, are `None`.
+pub struct StoreHealth {
+    pub initialized: bool,
+    /// The loaded slot, if any.
+    pub active_slot_id: Option<String>,
+    pub saves_directory: Option<String>,
+    /// Space left for saves on their filesystem.
+    pub free_disk_bytes: Option<u64>,
+    /// Slots outside the trash.
+    pub slot_count: Option<usize>,
+}
+
+/// The build's schema versions, from `schema_info`.
+pub struct SchemaInfo {
+    /// The version slots are migrated to when loaded.
+    pub slot_schema_version: usize,
+    pub metadata_schema_version: usize,
+    pub crate_version: String,
+}
+
+/// What a JSON export holds, as reported by `inspect_import`.
+pub struct ImportPreview {
+    pub schema_version: i64,
+    pub health: i32,
+    pub experience: i32,
+    pub item_count: usize,
+}
+
+/// One file finished by `import_directory`.
+pub struct ImportProgress {
+    /// 1-based position of this file among the `total` being imported.
+    pub current: usize,
+    pub total: usize,
+    pub file_name: String,
+    /// The new slot, or `None` if this file failed.
+    pub slot_id: Option<String>,
+    pub error: Option<String>,
+}
+
+/// One file written by `export_all` or slot restored by `import_all`.
+pub struct ArchiveProgress {
+    /// 1-based position of this file among the `total` being handled.
+    pub current: usize,
+    pub total: usize,
+    /// The file's name inside the archive.
+    pub file_name: String,
+}
+
+/// How much space a slot's autosave backups take up.
+pub struct AutosaveUsage {
+    pub count: u32,
+    /// Including the backups' `-wal` and `-shm` files.
+    pub total_bytes: u64,
+}
+
+/// One rotating backup of a slot; index 0 is the most recent.
+pub struct AutosaveInfo {
+    pub index: u32,
+    pub saved_at: String,
+    pub size_bytes: u64,
+    pub file_path: String,
+}
+
+/// A labelled save point taken with `create_checkpoint`.
+pub struct CheckpointInfo {
+    pub id: String,
+    pub slot_id: String,
+    pub label: String,
+    pub created_at: String,
+    pub size_bytes: u64,
+    pub file_path: String,
+}
+
+/// What happened to a slot, as reported to slot event subscribers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SlotEventKind {
+    Created,
+    Renamed,
+    Deleted,
+    /// Player data was written to the slot.
+    Saved,
+    /// A trashed slot was brought back by `restore_slot`.
+    Restored,
+    /// A save to the slot is about to run while free disk space is below the
+    /// low disk space threshold. Only emitted, never journaled.
+    LowDiskSpace,
+}
+
+#[derive(Clone, Debug)]
+pub struct SlotEvent {
+    pub slot_id: String,
+    pub kind: SlotEventKind,
+}
+
+/// One entry in the change journal read by `changes_since`.
+pub struct ChangeEntry {
+    /// Increases with every entry, so it also orders entries that share a
+    /// timestamp.
+    pub sequence: i64,
+    /// When the change was committed, in Unix epoch milliseconds.
+    pub timestamp_ms: i64,
+    pub slot_id: String,
+    pub operation: SlotEventKind,
+    /// The slot's revision after a save; `None` for other operations.
+    pub revision: Option<i64>,
+}
+
+/// A copy of a slot taken before a schema upgrade that didn't complete.
+pub struct MigrationBackupInfo {
+    pub slot_id: String,
+    pub saved_at: String,
+    pub size_bytes: u64,
+    pub file_path: String,
+}
+
+/// Error surfaced to Dart. Each variant maps to a distinct exception case so
+/// callers can branch on the kind instead of matching message text.
+#[derive(Debug)]
+pub enum SaveError {
+    NotInitialized,
+    AlreadyInitialized { base_path: String },
+    WorkerUnavailable,
+    OperationPanicked,
+    NoActiveSlot,
+    UnknownHandle { handle: u32 },
+    SlotNotFound { id: String },
+    InvalidSlotName { reason: String },
+    CorruptSlot { id: String },
+    InvalidTag { reason: String },
+    NoPlayerData,
+    InvalidPlayerData { field: String, reason: String },
+    UnsupportedFormat { reason: String },
+    ThumbnailTooLarge { size: u64, max: u64 },
+    AutosaveNotConfigured,
+    AutosaveNotFound { slot_id: String, index: u32 },
+    CheckpointNotFound { id: String },
+    SlotInUse { id: String },
+    DecryptionFailed,
+    InvalidConfig { reason: String },
+    ChecksumMismatch { id: String },
+    RevisionConflict { current: i64 },
+    SlotLocked { id: String },
+    SlotBusy { id: String },
+    DiskFull,
+    TransactionInProgress { id: String },
+    NoTransaction,
+    NothingToUndo,
+    SchemaMismatch { missing: Vec<String> },
+    Io { message: String },
+    Database { message: String },
+    Migration { message: String },
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotInitialized => SaveManagerError::NotInitialized.fmt(f),
+            Self::AlreadyInitialized { base_path } => {
+                write!(f, "Save system is already initialized for {base_path}")
+            }
+            Self::WorkerUnavailable => write!(f, "Save worker thread is not running"),
+            Self::OperationPanicked => write!(f, "Save operation panicked"),
+            Self::NoActiveSlot => SaveManagerError::NoActiveSlot.fmt(f),
+            Self::UnknownHandle { handle } => SaveManagerError::UnknownHandle(*handle).fmt(f),
+            Self::SlotNotFound { id } => write!(f, "Save slot not found: {id}"),
+            Self::InvalidSlotName { reason } => write!(f, "Invalid slot name: {reason}"),
+            Self::CorruptSlot { id } => write!(f, "Save slot {id} is corrupt"),
+            Self::InvalidTag { reason } => write!(f, "Invalid tag: {reason}"),
+            Self::NoPlayerData => SaveManagerError::NoPlayerData.fmt(f),
+            Self::InvalidPlayerData { field, reason } => {
+                write!(f, "Invalid player data in {field}: {reason}")
+            }
+            Self::UnsupportedFormat { reason } => write!(f, "Unsupported save format: {reason}"),
+            Self::ThumbnailTooLarge { size, max } => {
+                write!(f, "Thumbnail is {size} bytes, the limit is {max}")
+            }
+            Self::AutosaveNotConfigured => SaveManagerError::AutosaveNotConfigured.fmt(f),
+            Self::AutosaveNotFound { slot_id, index } => {
+                write!(f, "Autosave {index} not found for slot {slot_id}")
+            }
+            Self::CheckpointNotFound { id } => write!(f, "Checkpoint not found: {id}"),
+            Self::SlotInUse { id } => write!(f, "Save slot {id} is open; close it first"),
+            Self::DecryptionFailed => SaveManagerError::DecryptionFailed.fmt(f),
+            Self::InvalidConfig { reason } => write!(f, "Invalid save configuration: {reason}"),
+            Self::ChecksumMismatch { id } => write!(f, "Save slot {id} failed its integrity check"),
+            Self::RevisionConflict { current } => {
+                write!(
+                    f,
+                    "Save slot was changed elsewhere; it is now at revision {current}"
+                )
+            }
+            Self::SlotLocked { id } => write!(f, "Save slot {id} is locked by another connection"),
+            Self::SlotBusy { id } => write!(f, "Save slot {id} is being migrated"),
+            Self::DiskFull => SaveManagerError::DiskFull.fmt(f),
+            Self::TransactionInProgress { id } => {
+                write!(f, "Save slot {id} has a transaction in progress")
+            }
+            Self::NoTransaction => SaveManagerError::NoTransaction.fmt(f),
+            Self::NothingToUndo => SaveManagerError::NothingToUndo.fmt(f),
+            Self::SchemaMismatch { missing } => write!(
+                f,
+                "Metadata database is missing save_slots columns: {}",
+                missing.join(", ")
+            ),
+            Self::Io { message } => write!(f, "IO error: {message}"),
+            Self::Database { message } => write!(f, "Database error: {message}"),
+            Self::Migration { message } => write!(f, "Migration error: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<SaveManagerError> for SaveError {
+    fn from(err: SaveManagerError) -> Self {
+        match err {
+            SaveManagerError::NotInitialized => Self::NotInitialized,
+            SaveManagerError::AlreadyInitialized(base_path) => {
+                Self::AlreadyInitialized { base_path }
+            }
+            SaveManagerError::NoActiveSlot => Self::NoActiveSlot,
+            SaveManagerError::UnknownHandle(handle) => Self::UnknownHandle { handle },
+            SaveManagerError::SlotNotFound(id) => Self::SlotNotFound { id },
+            SaveManagerError::InvalidSlotName(reason) => Self::InvalidSlotName { reason },
+            SaveManagerError::CorruptSlot(id) => Self::CorruptSlot { id },
+            SaveManagerError::InvalidTag(reason) => Self::InvalidTag { reason },
+            SaveManagerError::NoPlayerData => Self::NoPlayerData,
+            SaveManagerError::InvalidPlayerData { field, reason } => Self::InvalidPlayerData {
+                field: field.to_owned(),
+                reason,
+            },
+            SaveManagerError::UnsupportedFormat(reason) => Self::UnsupportedFormat { reason },
+            SaveManagerError::ThumbnailTooLarge { size, max } => Self::ThumbnailTooLarge {
+                size: size as u64,
+                max: max as u64,
+            },
+            SaveManagerError::AutosaveNotConfigured => Self::AutosaveNotConfigured,
+            SaveManagerError::AutosaveNotFound(slot_id, index) => {
+                Self::AutosaveNotFound { slot_id, index }
+            }
+            SaveManagerError::CheckpointNotFound(id) => Self::CheckpointNotFound { id },
+            SaveManagerError::SlotInUse(id) => Self::SlotInUse { id },
+            SaveManagerError::DecryptionFailed => Self::DecryptionFailed,
+            SaveManagerError::InvalidConfig(reason) => Self::InvalidConfig { reason },
+            SaveManagerError::ChecksumMismatch(id) => Self::ChecksumMismatch { id },
+            SaveManagerError::RevisionConflict { current } => Self::RevisionConflict { current },
+            SaveManagerError::SlotLocked(id) => Self::SlotLocked { id },
+            SaveManagerError::SlotBusy(id) => Self::SlotBusy { id },
+            SaveManagerError::DiskFull => Self::DiskFull,
+            SaveManagerError::TransactionInProgress(id) => Self::TransactionInProgress { id },
+            SaveManagerError::NoTransaction => Self::NoTransaction,
+            SaveManagerError::NothingToUndo => Self::NothingToUndo,
+            SaveManagerError::SchemaMismatch { missing } => Self::SchemaMismatch { missing },
+            SaveManagerError::Io(err) => Self::Io {
+                message: err.to_string(),
+            },
+            SaveManagerError::Database(err) => Self::Database {
+                message: err.to_string(),
+            },
+            SaveManagerError::Migration(message) => Self::Migration { message },
+        }
+    }
+}
+
+impl From<WorkerError> for SaveError {
+    fn from(err: WorkerError) -> Self {
+        match err {
+            WorkerError::Unavailable => Self::WorkerUnavailable,
+            WorkerError::Panicked => Self::OperationPanicked,
+        }
+    }
+}
+
+/// Display text of the most recent failed save call; cleared by the next one
+/// that succeeds.
+static LAST_ERROR: Mutex<Option<String>> = Mutex::new(None);
+
+/// Runs `f` on the save worker thread, which owns the global manager, and
+/// records the outcome for `last_error`.
+fn on_worker<T: Send + 'static>(
+    f: impl FnOnce(&mut Option<SaveManager>) -> SaveManagerResult<T> + Send + 'static,
+) -> Result<T, SaveError> {
+    let result = worker::run(move |manager| {
+        let result = f(manager);
+        if let (Err(_), Some(manager)) = (&result, manager.as_ref()) {
+            manager.discard_metadata_connection();
+        }
+        result
+    })
+    .map_err(SaveError::from)
+    .and_then(|result| result.map_err(SaveError::from));
+    // Only ever holds a finished string, so poisoning is safe to ignore.
+    *LAST_ERROR.lock().unwrap_or_else(PoisonError::into_inner) =
+        result.as_ref().err().map(ToString::to_string);
+    result
+}
+
+fn with_save_manager<T: Send + 'static>(
+    f: impl FnOnce(&SaveManager) -> SaveManagerResult<T> + Send + 'static,
+) -> Result<T, SaveError> {
+    on_worker(move |manager| f(manager.as_ref().ok_or(SaveManagerError::NotInitialized)?))
+}
+
+fn with_save_manager_mut<T: Send + 'static>(
+    f: impl FnOnce(&mut SaveManager) -> SaveManagerResult<T> + Send + 'static,
+) -> Result<T, SaveError> {
+    on_worker(move |manager| f(manager.as_mut().ok_or(SaveManagerError::NotInitialized)?))
+}
+
+/// The message of the most recent save call that failed, or `None` if the
+/// latest call succeeded. Meant for a debug overlay, including failures from
+/// calls whose result nobody awaited.
+#[flutter_rust_bridge::frb(sync)]
+pub fn last_error() -> Option<String> {
+    LAST_ERROR
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+/// Sets up the save directory under `base_path` (the app documents directory
+/// on the Flutter side). Must be called before any other save function.
+///
+/// Calling it again with the same `base_path` and configuration is a no-op
+/// that keeps the loaded slot. Anything different is rejected; use
+/// `force_init_system` to switch.
+#[flutter_rust_bridge::frb(sync)]
+pub fn init_system(base_path: String) -> Result<(), SaveError> {
+    initialize_system(PathBuf::from(base_path), SaveConfig::default(), false)
+}
+
+/// Like `init_system`, but keeps saves in `<base_path>/<app_name>/<saves_subdir>`
+/// instead of `my_app/saves`, so several products or isolated test
+/// instances can share a base path. Each name must be a single directory
+/// name.
+#[flutter_rust_bridge::frb(sync)]
+pub fn init_system_with_config(
+    base_path: String,
+    app_name: String,
+    saves_subdir: String,
+) -> Result<(), SaveError> {
+    let config = SaveConfig {
+        app_name,
+        saves_subdirectory: saves_subdir,
+        ..SaveConfig::default()
+    };
+    initialize_system(PathBuf::from(base_path), config, false)
+}
+
+/// Like `init_system`, but every save database is encrypted with `key` via
+/// SQLCipher. Opening saves with a different key fails with
+/// `DecryptionFailed`. Existing unencrypted saves are not converted and
+/// can't be opened this way.
+#[flutter_rust_bridge::frb(sync)]
+pub fn init_system_encrypted(base_path: String, key: String) -> Result<(), SaveError> {
+    let config = SaveConfig {
+        encryption_key: Some(key),
+        ..SaveConfig::default()
+    };
+    initialize_system(PathBuf::from(base_path), config, false)
+}
+
+/// Like `init_system`, but keeps saves in `my_app/profiles/<profile>/saves`
+/// with their own metadata, so players sharing a device never see each
+/// other's slots. `profile` must be a single directory name.
+#[flutter_rust_bridge::frb(sync)]
+pub fn init_system_for_profile(base_path: String, profile: String) -> Result<(), SaveError> {
+    let config = SaveConfig {
+        profile: Some(profile),
+        ..SaveConfig::default()
+    };
+    initialize_system(PathBuf::from(base_path), config, false)
+}
+
+/// The profiles with saves under `base_path`, sorted by name. Works whether
+/// or not the system is initialized.
+#[flutter_rust_bridge::frb(sync)]
+pub fn list_profiles(base_path: String) -> Result<Vec<String>, SaveError> {
+    on_worker(move |_| {
+        save_manager::list_profiles(Path::new(&base_path), &SaveConfig::default().app_name)
+    })
+}
+
+/// Shuts the save system down and initializes it again for `profile`, under
+/// the same base path and otherwise the same configuration. Slot ids belong
+/// to one profile, so nothing loaded carries over. If the new profile can't
+/// be initialized the current one stays active.
+#[flutter_rust_bridge::frb(sync)]
+pub fn switch_profile(profile: String) -> Result<(), SaveError> {
+    on_worker(move |current| {
+        let existing = current.as_ref().ok_or(SaveManagerError::NotInitialized)?;
+        let config = SaveConfig {
+            profile: Some(profile),
+            ..existing.config().clone()
+        };
+        if existing.is_configured_for(existing.base_path(), &config) {
+            return Ok(());
+        }
+        let manager = SaveManager::initialize(existing.base_path(), config)?;
+        match current.replace(manager) {
+            Some(mut previous) => previous.shutdown(),
+            None => Ok(()),
+        }
+    })
+}
+
+/// Replaces the save system unconditionally. The previous one is shut down
+/// the way `close_system` does it, so nothing written through its slots is
+/// lost; the new system stays active even if that final flush fails, and the
+/// error is still returned.
+#[flutter_rust_bridge::frb(sync)]
+pub fn force_init_system(base_path: String) -> Result<(), SaveError> {
+    initialize_system(PathBuf::from(base_path), SaveConfig::default(), true)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn is_initialized() -> bool {
+    on_worker(|manager| Ok(manager.is_some())).unwrap_or(false)
+}
+
+fn initialize_system(base_path: PathBuf, config: SaveConfig, force: bool) -> Result<(), SaveError> {
+    on_worker(move |current| {
+        if let Some(existing) = current.as_ref() {
+            if !force {
+                if existing.is_configured_for(&base_path, &config) {
+                    return Ok(());
+                }
+                return Err(SaveManagerError::AlreadyInitialized(
+                    existing.base_path().display().to_string(),
+                ));
+            }
+        }
+
+        // Build the replacement first so a failed init leaves the old one
+        // working.
+        let manager = SaveManager::initialize(&base_path, config)?;
+        match current.replace(manager) {
+            Some(mut previous) => previous.shutdown(),
+            None => Ok(()),
+        }
+    })
+}
+
+/// Closes every slot and the metadata database and shuts the save system
+/// down, e.g. on logout or an account switch, releasing every file lock.
+/// Other save functions then fail with `NotInitialized` until `init_system`
+/// is called again. Does nothing if the system isn't initialized. The system
+/// is shut down even if the final flush fails; that error is still returned.
+#[flutter_rust_bridge::frb(sync)]
+pub fn close_system() -> Result<(), SaveError> {
+    autosave_timer::stop();
+    on_worker(|current| match current.take() {
+        Some(mut manager) => manager.shutdown(),
+        None => Ok(()),
+    })
+}
+
+/// Replaces the built-in slot schema with the app's own migrations, each an
+/// `(up, down)` SQL pair; step `n` takes a slot to schema version `n`, and a
+/// step without down SQL can't be rolled back. An empty list restores the
+/// built-in schema. Must be called before `init_system`: once the system is
+/// initialized this fails with `AlreadyInitialized`.
+///
+/// Player data, inventory, flag and export functions rely on the built-in
+/// `player_stats`, `inventory` and `game_flags` tables, so keep those in a
+/// custom schema to go on using them.
+#[flutter_rust_bridge::frb(sync)]
+pub fn register_slot_migrations(
+    migrations: Vec<(String, Option<String>)>,
+) -> Result<(), SaveError> {
+    on_worker(move |current| {
+        if let Some(existing) = current.as_ref() {
+            return Err(SaveManagerError::AlreadyInitialized(
+                existing.base_path().display().to_string(),
+            ));
+        }
+        save_manager::register_slot_migrations(migrations);
+        Ok(())
+    })
+}
+
+/// Everything a diagnostics panel needs in one call. Never fails: an
+/// uninitialized system reports `initialized: false` and no other values.
+#[flutter_rust_bridge::frb(sync)]
+pub fn store_health() -> StoreHealth {
+    // Straight to the worker rather than through `on_worker`, so checking on
+    // the store doesn't clear `last_error`.
+    worker::run(|manager| manager.as_ref().map(SaveManager::store_health))
+        .ok()
+        .flatten()
+        .unwrap_or(StoreHealth {
+            initialized: false,
+            active_slot_id: None,
+            saves_directory: None,
+            free_disk_bytes: None,
+            slot_count: None,
+        })
+}
+
+/// Where slot databases are written, e.g. for an "open folder" button.
+#[flutter_rust_bridge::frb(sync)]
+pub fn saves_directory() -> Result<String, SaveError> {
+    with_save_manager(|manager| Ok(manager.saves_dir().display().to_string()))
+}
+
+/// Where the slot metadata database is written.
+#[flutter_rust_bridge::frb(sync)]
+pub fn metadata_db_path() -> Result<String, SaveError> {
+    with_save_manager(|manager| Ok(manager.metadata_db_path().display().to_string()))
+}
+
+/// Creates an empty slot and returns its complete metadata, including
+/// `created_at`, so the new card can be shown without calling `get_slot`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn create_new_slot(display_name: String) -> Result<SaveSlotMetadata, SaveError> {
+    with_save_manager(move |manager| manager.create_slot(&display_name))
+}
+
+/// Creates a slot already holding `data`, e.g. a New Game+ or difficulty
+/// preset, in one call instead of create, load and save.
+#[flutter_rust_bridge::frb(sync)]
+pub fn create_slot_with_data(
+    display_name: String,
+    data: PlayerData,
+) -> Result<SaveSlotMetadata, SaveError> {
+    with_save_manager_mut(move |manager| manager.create_slot_with_data(&display_name, &data))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_all_slots() -> Result<Vec<SaveSlotMetadata>, SaveError> {
+    with_save_manager(move |manager| manager.get_all_slots())
+}
+
+/// Fetches one page of slots, for save browsers with many entries.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_slots_page(
+    offset: u32,
+    limit: u32,
+    order: SlotOrder,
+) -> Result<Vec<SaveSlotMetadata>, SaveError> {
+    with_save_manager(move |manager| manager.get_slots_page(offset, limit, order))
+}
+
+/// How many slots `get_slots_page` can page through, trash excluded, e.g.
+/// for "Showing 20 of 137". 0 when there are none.
+#[flutter_rust_bridge::frb(sync)]
+pub fn count_slots() -> Result<u64, SaveError> {
+    with_save_manager(|manager| Ok(manager.slot_count()? as u64))
+}
+
+/// Cheaply checks that a slot can be loaded: it is registered and its file
+/// is on disk. Returns `false` if either is missing.
+#[flutter_rust_bridge::frb(sync)]
+pub fn slot_exists(slot_id: String) -> Result<bool, SaveError> {
+    with_save_manager(move |manager| manager.slot_exists(&slot_id))
+}
+
+/// Reads a slot's health, experience and item count for the Continue
+/// button without loading it: nothing is migrated and the slot isn't marked
+/// as played.
+#[flutter_rust_bridge::frb(sync)]
+pub fn preview_slot(slot_id: String) -> Result<SlotPreview, SaveError> {
+    with_save_manager(move |manager| manager.preview_slot(&slot_id))
+}
+
+/// Slots whose name contains `query`, ignoring case, most recently played
+/// first, for a save browser's search box. No matches is an empty list.
+#[flutter_rust_bridge::frb(sync)]
+pub fn search_slots(query: String) -> Result<Vec<SaveSlotMetadata>, SaveError> {
+    with_save_manager(move |manager| manager.search_slots(&query))
+}
+
+/// Fetches one slot's metadata, e.g. to refresh a single card after a change.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_slot(slot_id: String) -> Result<SaveSlotMetadata, SaveError> {
+    with_save_manager(move |manager| manager.get_slot(&slot_id))
+}
+
+/// Streams an event after every committed slot creation, rename, deletion
+/// and save, so UIs can refresh instead of polling `get_all_slots`. The
+/// subscription ends once Dart cancels the stream.
+pub fn subscribe_slot_events(sink: StreamSink<SlotEvent>) {
+    save_manager::events::subscribe(move |event| sink.add(event.clone()).is_ok());
+}
+
+/// Every slot change journaled after `timestamp_ms` (Unix epoch
+/// milliseconds), oldest first, so a sync layer can upload just the slots
+/// that changed since its last run. Creation, renames, deletion, restores and
+/// saves are recorded.
+#[flutter_rust_bridge::frb(sync)]
+pub fn changes_since(timestamp_ms: i64) -> Result<Vec<ChangeEntry>, SaveError> {
+    with_save_manager(move |manager| manager.changes_since(timestamp_ms))
+}
+
+/// Drops journal entries older than `timestamp_ms`, e.g. once they have been
+/// synced, and returns how many were removed.
+#[flutter_rust_bridge::frb(sync)]
+pub fn prune_change_log(timestamp_ms: i64) -> Result<usize, SaveError> {
+    with_save_manager(move |manager| manager.prune_change_log(timestamp_ms))
+}
+
+/// Stores a global setting such as volume or language. Settings live in the
+/// metadata database and don't need a loaded slot.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_setting(key: String, value: String) -> Result<(), SaveError> {
+    with_save_manager(move |manager| manager.set_setting(&key, &value))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_setting(key: String) -> Result<Option<String>, SaveError> {
+    with_save_manager(move |manager| manager.get_setting(&key))
+}
+
+/// Every stored setting as `(key, value)` pairs, sorted by key.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_all_settings() -> Result<Vec<(String, String)>, SaveError> {
+    with_save_manager(|manager| manager.get_all_settings())
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn add_slot_tag(slot_id: String, tag: String) -> Result<(), SaveError> {
+    with_save_manager(move |manager| manager.add_slot_tag(&slot_id, &tag))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn remove_slot_tag(slot_id: String, tag: String) -> Result<(), SaveError> {
+    with_save_manager(move |manager| manager.remove_slot_tag(&slot_id, &tag))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_slots_by_tag(tag: String) -> Result<Vec<SaveSlotMetadata>, SaveError> {
+    with_save_manager(move |manager| manager.get_slots_by_tag(&tag))
+}
+
+/// Renames a slot and returns its refreshed metadata so the UI can update the
+/// card in place.
+#[flutter_rust_bridge::frb(sync)]
+pub fn rename_slot(slot_id: String, new_name: String) -> Result<SaveSlotMetadata, SaveError> {
+    with_save_manager(move |manager| manager.rename_slot(&slot_id, &new_name))
+}
+
+/// Copies an existing slot into a new one so the player can branch their save.
+#[flutter_rust_bridge::frb(sync)]
+pub fn duplicate_slot(slot_id: String, new_name: String) -> Result<SaveSlotMetadata, SaveError> {
+    with_save_manager(move |manager| manager.duplicate_slot(&slot_id, &new_name))
+}
+
+/// Starts New Game Plus from a finished slot: a copy named "<name> NG+"
+/// with health reset to the starting value (see `set_new_game_plus_health`),
+/// `experience_carryover_pct` percent of the experience (0 to 100), and the
+/// inventory only if `keep_inventory`. The finished slot is left untouched.
+#[flutter_rust_bridge::frb(sync)]
+pub fn start_new_game_plus(
+    slot_id: String,
+    keep_inventory: bool,
+    experience_carryover_pct: f32,
+) -> Result<SaveSlotMetadata, SaveError> {
+    with_save_manager_mut(move |manager| {
+        manager.start_new_game_plus(&slot_id, keep_inventory, experience_carryover_pct)
+    })
+}
+
+/// Sets the health New Game Plus runs start with (default 100).
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_new_game_plus_health(health: i32) -> Result<(), SaveError> {
+    with_save_manager_mut(move |manager| manager.set_new_game_plus_health(health))
+}
+
+/// Compares two slots for a "what changed" screen: their stats and which
+/// items were added, removed or changed in number going from `slot_a` to
+/// `slot_b`. Neither slot needs to be loaded.
+#[flutter_rust_bridge::frb(sync)]
+pub fn diff_slots(slot_a: String, slot_b: String) -> Result<SlotDiff, SaveError> {
+    with_save_manager(move |manager| manager.diff_slots(&slot_a, &slot_b))
+}
+
+/// Renames an item in every save, e.g. after a content update turned
+/// "Potion" into "Health Potion", and reports how many entries changed. Open
+/// slots are updated in place. A slot that can't be updated is reported in
+/// `skipped` without stopping the others.
+#[flutter_rust_bridge::frb(sync)]
+pub fn rename_item_everywhere(old: String, new: String) -> Result<ItemRenameResult, SaveError> {
+    with_save_manager(move |manager| manager.rename_item_everywhere(&old, &new))
+}
+
+/// Adds the items of slot `from` to slot `into`, e.g. to import a friend's
+/// items, and returns `into`'s new item count. `from` is left as it is and
+/// `into` keeps its stats. `into` must have been saved before; fails with
+/// `InvalidPlayerData` if the result would exceed the inventory limit.
+#[flutter_rust_bridge::frb(sync)]
+pub fn merge_inventories(
+    into: String,
+    from: String,
+    strategy: MergeStrategy,
+) -> Result<usize, SaveError> {
+    with_save_manager(move |manager| manager.merge_inventories(&into, &from, strategy))
+}
+
+/// Marks several slots as just played in one transaction, e.g. after a
+/// batch import, and returns the ids that were found.
+#[flutter_rust_bridge::frb(sync)]
+pub fn touch_slots(slot_ids: Vec<String>) -> Result<Vec<String>, SaveError> {
+    with_save_manager(move |manager| manager.touch_slots(&slot_ids))
+}
+
+/// Adds to a slot's total play time. Meant to be called periodically or when
+/// the game is paused.
+#[flutter_rust_bridge::frb(sync)]
+pub fn add_play_time(slot_id: String, seconds: i64) -> Result<(), SaveError> {
+    with_save_manager(move |manager| manager.add_play_time(&slot_id, seconds))
+}
+
+/// Sets the notes shown on a slot's card, e.g. "before the boss". An empty
+/// string clears them.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_slot_description(slot_id: String, text: String) -> Result<(), SaveError> {
+    with_save_manager(move |manager| manager.set_slot_description(&slot_id, &text))
+}
+
+/// Sets the preview image shown on a slot's card (2 MB max).
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_slot_thumbnail(slot_id: String, png_bytes: Vec<u8>) -> Result<(), SaveError> {
+    with_save_manager(move |manager| manager.set_slot_thumbnail(&slot_id, &png_bytes))
+}
+
+/// Returns the slot's preview image, or `None` if it never had one.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_slot_thumbnail(slot_id: String) -> Result<Option<Vec<u8>>, SaveError> {
+    with_save_manager(move |manager| manager.get_slot_thumbnail(&slot_id))
+}
+
+/// Checks a slot's database for damage without loading it, so the UI can
+/// flag broken saves up front.
+#[flutter_rust_bridge::frb(sync)]
+pub fn verify_slot(slot_id: String) -> Result<bool, SaveError> {
+    with_save_manager(move |manager| manager.verify_slot(&slot_id))
+}
+
+/// The schema version of a slot's file, without loading it. Lower than
+/// `latest_schema_version` means loading the slot will upgrade it.
+#[flutter_rust_bridge::frb(sync)]
+pub fn slot_schema_version(slot_id: String) -> Result<usize, SaveError> {
+    with_save_manager(move |manager| manager.slot_schema_version(&slot_id))
+}
+
+/// The schema version this build migrates slots to.
+#[flutter_rust_bridge::frb(sync)]
+pub fn latest_schema_version() -> usize {
+    save_manager::latest_slot_schema_version()
+}
+
+/// Which schemas and crate version this build runs, for support tickets.
+/// Needs no initialization and touches no files.
+#[flutter_rust_bridge::frb(sync)]
+pub fn schema_info() -> SchemaInfo {
+    SchemaInfo {
+        slot_schema_version: save_manager::latest_slot_schema_version(),
+        metadata_schema_version: save_manager::latest_metadata_schema_version(),
+        crate_version: env!("CARGO_PKG_VERSION").to_owned(),
+    }
+}
+
+/// Migrates a slot that isn't loaded or open to schema version `target`,
+/// upgrading or rolling back as needed. Rolling back discards data stored
+/// by the reverted versions. The next `load_slot` upgrades it again.
+#[flutter_rust_bridge::frb(sync)]
+pub fn migrate_slot_to_version(slot_id: String, target: usize) -> Result<(), SaveError> {
+    with_save_manager(move |manager| manager.migrate_slot_to_version(&slot_id, target))
+}
+
+/// Slot backups left over from schema upgrades that failed or were cut short
+/// by a crash, for a support screen to surface.
+#[flutter_rust_bridge::frb(sync)]
+pub fn list_migration_backups() -> Result<Vec<MigrationBackupInfo>, SaveError> {
+    with_save_manager(|manager| manager.list_migration_backups())
+}
+
+/// Clears WAL locks a crashed process left on a slot, for when loading or
+/// saving it fails with `SlotLocked`. The slot must not be loaded or open
+/// through a handle. Still fails with `SlotLocked` if another process is
+/// actually using it.
+#[flutter_rust_bridge::frb(sync)]
+pub fn recover_slot_locks(slot_id: String) -> Result<(), SaveError> {
+    with_save_manager(move |manager| manager.recover_slot_locks(&slot_id))
+}
+
+/// Compacts a slot's database file. The slot must not be loaded or open
+/// through a handle.
+#[flutter_rust_bridge::frb(sync)]
+pub fn vacuum_slot(slot_id: String) -> Result<(), SaveError> {
+    with_save_manager(move |manager| manager.vacuum_slot(&slot_id))
+}
+
+/// Finds slot files with no metadata row and rows whose file is gone,
+/// without changing anything.
+#[flutter_rust_bridge::frb(sync)]
+pub fn scan_integrity() -> Result<IntegrityReport, SaveError> {
+    with_save_manager(|manager| manager.scan_integrity())
+}
+
+/// Fixes what `scan_integrity` reports: dangling rows are removed and
+/// orphaned files are deleted or registered as new slots, per `policy`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn repair_integrity(policy: RepairPolicy) -> Result<RepairReport, SaveError> {
+    with_save_manager_mut(move |manager| manager.repair_integrity(policy))
+}
+
+/// Checkpoints and optimizes every slot and vacuums the ones that aren't open
+/// and take more than `vacuum_above_bytes` (none if `None`). Per-slot
+/// failures are listed in the report instead of failing the run.
+#[flutter_rust_bridge::frb(sync)]
+pub fn run_maintenance(vacuum_above_bytes: Option<u64>) -> Result<MaintenanceReport, SaveError> {
+    with_save_manager(move |manager| manager.run_maintenance(vacuum_above_bytes))
+}
+
+/// Totals across every slot for a profile screen: highest experience and
+/// health, items held, play time and slot counts. Unreadable slots are
+/// skipped and listed in the result. Reads every slot file, so call it when
+/// the screen opens rather than on every frame.
+#[flutter_rust_bridge::frb(sync)]
+pub fn aggregate_stats() -> Result<AggregateStats, SaveError> {
+    with_save_manager(|manager| manager.aggregate_stats())
+}
+
+/// Compacts the metadata database.
+#[flutter_rust_bridge::frb(sync)]
+pub fn vacuum_metadata() -> Result<(), SaveError> {
+    with_save_manager(|manager| manager.vacuum_metadata())
+}
+
+/// Moves a slot's database file into `new_dir`, such as external storage,
+/// and updates its `file_path`. The slot must be unloaded first; handles
+/// from `open_slot` are closed.
+#[flutter_rust_bridge::frb(sync)]
+pub fn move_slot(slot_id: String, new_dir: String) -> Result<(), SaveError> {
+    with_save_manager_mut(move |manager| manager.move_slot(&slot_id, Path::new(&new_dir)))
+}
+
+/// Bytes used by a slot's database files. Sidecar files that don't exist
+/// count as zero.
+#[flutter_rust_bridge::frb(sync)]
+pub fn slot_disk_usage(slot_id: String) -> Result<u64, SaveError> {
+    with_save_manager(move |manager| manager.slot_disk_usage(&slot_id))
+}
+
+/// Bytes used by the whole saves directory, including autosave backups.
+#[flutter_rust_bridge::frb(sync)]
+pub fn total_saves_disk_usage() -> Result<u64, SaveError> {
+    with_save_manager(|manager| manager.total_saves_disk_usage())
+}
+
+/// Moves the given slots to the trash together and returns the ids that were
+/// actually trashed, so callers can spot ones that were already gone. The loaded slot
+/// is unloaded if it is among them.
+#[flutter_rust_bridge::frb(sync)]
+pub fn delete_slots(slot_ids: Vec<String>) -> Result<Vec<String>, SaveError> {
+    with_save_manager_mut(move |manager| manager.delete_slots(&slot_ids))
+}
+
+/// Permanently deletes every slot and its backups, trash included. Global
+/// settings are kept.
+#[flutter_rust_bridge::frb(sync)]
+pub fn delete_all_slots() -> Result<(), SaveError> {
+    with_save_manager_mut(|manager| manager.delete_all_slots())
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn load_slot(slot_id: String) -> Result<(), SaveError> {
+    with_save_manager_mut(move |manager| manager.load_slot(&slot_id))
+}
+
+/// Sets the limits every save, autosave and import is checked against:
+/// at most `max_inventory` items and `max_health` health. Negative health or
+/// experience is always rejected. Limits reset when the system is
+/// re-initialized.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_validation_limits(max_inventory: u32, max_health: i32) -> Result<(), SaveError> {
+    with_save_manager_mut(move |manager| manager.set_validation_limits(max_inventory, max_health))
+}
+
+/// Sets the longest slot name `create_new_slot` and `rename_slot` accept,
+/// counted in characters (default 100).
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_max_slot_name_length(max_length: u32) -> Result<(), SaveError> {
+    with_save_manager_mut(move |manager| manager.set_max_slot_name_length(max_length))
+}
+
+/// Tunes connections opened from now on: how long to wait on a locked
+/// database before failing (default 5000 ms), and `PRAGMA synchronous`,
+/// `"FULL"` (the default) or `"NORMAL"`. NORMAL is faster and still safe
+/// from corruption in WAL mode, but a power cut can lose the latest saves.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_sqlite_options(busy_timeout_ms: u32, synchronous: String) -> Result<(), SaveError> {
+    with_save_manager_mut(move |manager| manager.set_sqlite_options(busy_timeout_ms, &synchronous))
+}
+
+/// Sets the WAL size, in pages, at which SQLite checkpoints on its own
+/// (default 1000). Smaller means more frequent writes to storage; larger
+/// batches them, which suits flaky flash, but keeps recent data only in the
+/// `-wal` file for longer. 0 disables automatic checkpoints so durability is
+/// left to saves, which checkpoint their slot, and `checkpoint_active_slot`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_wal_autocheckpoint(pages: i32) -> Result<(), SaveError> {
+    with_save_manager_mut(move |manager| manager.set_wal_autocheckpoint(pages))
+}
+
+/// Sets how many times a save is retried when another connection, e.g. a
+/// running autosave, keeps the slot locked past the busy timeout (default 2).
+/// Each retry waits twice as long as the last, starting at 50 ms; once they
+/// run out the save fails with `SlotLocked`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_busy_retries(retries: u32) -> Result<(), SaveError> {
+    with_save_manager_mut(move |manager| {
+        manager.set_busy_retries(retries);
+        Ok(())
+    })
+}
+
+/// Sets how little free space, in bytes, makes a save emit a `LowDiskSpace`
+/// slot event before it runs (default 50 MiB); 0 turns the warning off. The
+/// save is still attempted, and fails with `DiskFull` if space does run out.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_low_disk_space_threshold(bytes: u64) -> Result<(), SaveError> {
+    with_save_manager_mut(move |manager| {
+        manager.set_low_disk_space_threshold(bytes);
+        Ok(())
+    })
+}
+
+/// Turns save checksums on (the default) or off. With checks on, every save
+/// stores a SHA-256 of its data and `load_player_data` fails with
+/// `ChecksumMismatch` if the file was edited since.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_integrity_check_enabled(enabled: bool) -> Result<(), SaveError> {
+    with_save_manager_mut(move |manager| {
+        manager.set_integrity_check_enabled(enabled);
+        Ok(())
+    })
+}
+
+/// Sets the app's own save format version, stamped into every slot saved
+/// from now on (default 0). It is independent of the database schema, so the
+/// app can migrate save contents itself by comparing it with
+/// `slot_app_version`. Resets when the system is re-initialized.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_app_save_version(version: u32) -> Result<(), SaveError> {
+    with_save_manager_mut(move |manager| {
+        manager.set_app_save_version(version);
+        Ok(())
+    })
+}
+
+/// The app save version `slot_id` was last saved with, without loading it.
+/// Slots never saved, or saved before versions were stored, report 0.
+#[flutter_rust_bridge::frb(sync)]
+pub fn slot_app_version(slot_id: String) -> Result<u32, SaveError> {
+    with_save_manager(move |manager| manager.slot_app_version(&slot_id))
+}
+
+/// Keeps the slot metadata database open between calls (the default) instead
+/// of reopening it for every call, which saves the open and key setup on
+/// every metadata read. Turn it off only to release the file between calls.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_keep_metadata_connection(enabled: bool) -> Result<(), SaveError> {
+    with_save_manager_mut(move |manager| {
+        manager.set_keep_metadata_connection(enabled);
+        Ok(())
+    })
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn save_player_data(data: PlayerData) -> Result<SaveResult, SaveError> {
+    with_save_manager_mut(move |manager| manager.save_player_data(DEFAULT_HANDLE, &data))
+}
+
+/// Saves only if the loaded slot is still at `expected_revision`; otherwise
+/// fails with `RevisionConflict` carrying the current one, so the UI can
+/// offer to merge or overwrite.
+#[flutter_rust_bridge::frb(sync)]
+pub fn save_player_data_checked(
+    data: PlayerData,
+    expected_revision: i64,
+) -> Result<SaveResult, SaveError> {
+    with_save_manager_mut(move |manager| {
+        manager.save_player_data_checked(DEFAULT_HANDLE, &data, expected_revision)
+    })
+}
+
+/// The loaded slot's revision, which every save bumps. 0 if it was never
+/// saved.
+#[flutter_rust_bridge::frb(sync)]
+pub fn player_data_revision() -> Result<i64, SaveError> {
+    with_save_manager(|manager| manager.player_data_revision(DEFAULT_HANDLE))
+}
+
+/// Same as `save_player_data`, but without `frb(sync)`: flutter_rust_bridge
+/// runs it on its worker pool and hands Dart a `Future`, so writing a large
+/// inventory doesn't block the isolate.
+///
+/// It is intentionally a plain function rather than an `async fn`: the pool
+/// thread just blocks until the save worker, which owns every `Connection`,
+/// has run the write. Nothing non-`Send` ever crosses threads, and the write
+/// is serialized with the sync API by the worker's queue.
+pub fn save_player_data_async(data: PlayerData) -> Result<SaveResult, SaveError> {
+    with_save_manager_mut(move |manager| manager.save_player_data(DEFAULT_HANDLE, &data))
+}
+
+/// Saves the loaded slot and updates its thumbnail together. A failure in
+/// either part rolls back the other; the only gap is an I/O error while
+/// undoing the save after the thumbnail failed to commit.
+#[flutter_rust_bridge::frb(sync)]
+pub fn save_player_data_with_thumbnail(
+    data: PlayerData,
+    png_bytes: Vec<u8>,
+) -> Result<SaveResult, SaveError> {
+    with_save_manager_mut(move |manager| {
+        manager.save_player_data_with_thumbnail(DEFAULT_HANDLE, &data, &png_bytes)
+    })
+}
+
+/// Forces the loaded slot's pending WAL writes into its `.db` file so the file
+/// is consistent on its own. Useful right before the app is backgrounded.
+#[flutter_rust_bridge::frb(sync)]
+pub fn checkpoint_active_slot() -> Result<(), SaveError> {
+    with_save_manager(move |manager| manager.checkpoint_slot(DEFAULT_HANDLE))
+}
+
+/// Applies `ops` to the loaded slot atomically: either every change is kept
+/// or, if one fails, none are. The slot must have been saved once.
+#[flutter_rust_bridge::frb(sync)]
+pub fn apply_batch(ops: Vec<SaveOp>) -> Result<(), SaveError> {
+    with_save_manager(move |manager| manager.apply_batch(DEFAULT_HANDLE, &ops))
+}
+
+/// Reads only the loaded slot's inventory, in order, e.g. for an inventory
+/// screen.
+#[flutter_rust_bridge::frb(sync)]
+pub fn load_inventory() -> Result<Vec<String>, SaveError> {
+    with_save_manager(|manager| manager.load_inventory(DEFAULT_HANDLE))
+}
+
+/// Reads only the loaded slot's health and experience, in that order.
+#[flutter_rust_bridge::frb(sync)]
+pub fn load_player_stats() -> Result<(i32, i32), SaveError> {
+    with_save_manager(|manager| manager.load_player_stats(DEFAULT_HANDLE))
+}
+
+/// Appends `quantity` copies of `item` to the loaded slot's inventory
+/// without rewriting the rest of it. The slot must have been saved once.
+#[flutter_rust_bridge::frb(sync)]
+pub fn add_inventory_item(item: String, quantity: i32) -> Result<(), SaveError> {
+    with_save_manager(move |manager| manager.add_inventory_item(DEFAULT_HANDLE, &item, quantity))
+}
+
+/// Removes the item at `position`; later items move up one, so positions
+/// stay contiguous from 0. The last 20 removals can be undone with
+/// `undo_last_inventory_change`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn remove_inventory_item(position: i64) -> Result<(), SaveError> {
+    with_save_manager(move |manager| manager.remove_inventory_item(DEFAULT_HANDLE, position))
+}
+
+/// Puts the most recently removed item back at its old position. Up to 20
+/// removals per loaded slot are remembered, in memory only: they are
+/// forgotten when the slot is unloaded or the app restarts. Fails with
+/// `NothingToUndo` when there is none left.
+#[flutter_rust_bridge::frb(sync)]
+pub fn undo_last_inventory_change() -> Result<(), SaveError> {
+    with_save_manager(|manager| manager.undo_last_inventory_change(DEFAULT_HANDLE))
+}
+
+/// Replaces the item at `position` in the loaded slot's inventory.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_inventory_item(position: i64, item: String) -> Result<(), SaveError> {
+    with_save_manager(move |manager| manager.set_inventory_item(DEFAULT_HANDLE, position, &item))
+}
+
+/// Reads back the loaded slot's data. A slot that was created but never saved
+/// yields a "no player data" error, which the UI can treat as a new game.
+#[flutter_rust_bridge::frb(sync)]
+pub fn load_player_data() -> Result<PlayerData, SaveError> {
+    with_save_manager(move |manager| manager.load_player_data(DEFAULT_HANDLE))
+}
+
+/// Checkpoints and closes the loaded slot, e.g. when returning to the main
+/// menu. This releases its file handles, which matters on Windows where open
+/// WAL files block deletion. A no-op if nothing is loaded. A transaction
+/// still open on the slot is rolled back.
+#[flutter_rust_bridge::frb(sync)]
+pub fn unload_slot() -> Result<(), SaveError> {
+    with_save_manager_mut(|manager| {
+        manager.unload_slot();
+        Ok(())
+    })
+}
+
+/// Opens a transaction on the loaded slot that stays open across calls.
+/// Until `commit_transaction` or `rollback_transaction`, flag writes,
+/// inventory edits and batches join it, reads see only committed data, and
+/// `save_player_data` fails with `TransactionInProgress`. Unloading the slot
+/// rolls back whatever is still uncommitted.
+#[flutter_rust_bridge::frb(sync)]
+pub fn begin_transaction() -> Result<(), SaveError> {
+    with_save_manager(|manager| manager.begin_transaction(DEFAULT_HANDLE))
+}
+
+/// Commits the loaded slot's transaction. Fails with `NoTransaction` if
+/// none is open.
+#[flutter_rust_bridge::frb(sync)]
+pub fn commit_transaction() -> Result<(), SaveError> {
+    with_save_manager(|manager| manager.commit_transaction(DEFAULT_HANDLE))
+}
+
+/// Discards every change made since `begin_transaction`. Fails with
+/// `NoTransaction` if none is open.
+#[flutter_rust_bridge::frb(sync)]
+pub fn rollback_transaction() -> Result<(), SaveError> {
+    with_save_manager(|manager| manager.rollback_transaction(DEFAULT_HANDLE))
+}
+
+/// The id of the loaded slot, or `None` if nothing is loaded or the system
+/// isn't initialized. Useful for restoring UI state after a hot reload.
+#[flutter_rust_bridge::frb(sync)]
+pub fn active_slot_id() -> Option<String> {
+    on_worker(|manager| {
+        Ok(manager
+            .as_ref()
+            .and_then(|manager| manager.active_slot_id().map(str::to_owned)))
+    })
+    .unwrap_or(None)
+}
+
+/// Stores a custom flag (quest state, unlocked levels, ...) in the loaded
+/// slot, replacing any previous value for `key`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_flag(key: String, value: String) -> Result<(), SaveError> {
+    with_save_manager(move |manager| manager.set_flag(DEFAULT_HANDLE, &key, &value))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_flag(key: String) -> Result<Option<String>, SaveError> {
+    with_save_manager(move |manager| manager.get_flag(DEFAULT_HANDLE, &key))
+}
+
+/// Stores a stat of any type (a float multiplier, a serialized skill tree,
+/// ...) in the loaded slot under `key`. `health` and `experience` are stats
+/// too, and `set_stat("health", ...)` is the same as editing health, so
+/// those two only accept `Int`. The slot must have been saved once.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_stat(key: String, value: StatValue) -> Result<(), SaveError> {
+    with_save_manager(move |manager| manager.set_stat(DEFAULT_HANDLE, &key, &value))
+}
+
+/// The stat stored under `key` in the loaded slot, including `health` and
+/// `experience`, or `None` if there is none.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_stat(key: String) -> Result<Option<StatValue>, SaveError> {
+    with_save_manager(move |manager| manager.get_stat(DEFAULT_HANDLE, &key))
+}
+
+/// Every flag in the loaded slot as `(key, value)` pairs, sorted by key.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_all_flags() -> Result<Vec<(String, String)>, SaveError> {
+    with_save_manager(|manager| manager.get_all_flags(DEFAULT_HANDLE))
+}
+
+/// Opens a slot independently of the loaded one, e.g. for split-screen or a
+/// comparison view. Pass the returned handle to the `*_for` functions.
+#[flutter_rust_bridge::frb(sync)]
+pub fn open_slot(slot_id: String) -> Result<SlotHandle, SaveError> {
+    with_save_manager_mut(move |manager| manager.open_slot(&slot_id))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn close_slot(handle: SlotHandle) -> Result<(), SaveError> {
+    with_save_manager_mut(move |manager| manager.close_slot(handle))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn save_player_data_for(handle: SlotHandle, data: PlayerData) -> Result<SaveResult, SaveError> {
+    with_save_manager_mut(move |manager| manager.save_player_data(handle, &data))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn load_player_data_for(handle: SlotHandle) -> Result<PlayerData, SaveError> {
+    with_save_manager(move |manager| manager.load_player_data(handle))
+}
+
+/// Moves a slot to the trash; `restore_slot` undoes it until `purge_trash`
+/// removes its files. Deleting the loaded slot unloads it.
+#[flutter_rust_bridge::frb(sync)]
+pub fn delete_slot(slot_id: String) -> Result<(), SaveError> {
+    with_save_manager_mut(move |manager| manager.delete_slot(&slot_id))
+}
+
+/// Slots in the trash, most recently deleted first.
+#[flutter_rust_bridge::frb(sync)]
+pub fn list_trashed_slots() -> Result<Vec<TrashedSlot>, SaveError> {
+    with_save_manager(|manager| manager.list_trashed_slots())
+}
+
+/// Takes a slot back out of the trash and returns its metadata.
+#[flutter_rust_bridge::frb(sync)]
+pub fn restore_slot(slot_id: String) -> Result<SaveSlotMetadata, SaveError> {
+    with_save_manager(move |manager| manager.restore_slot(&slot_id))
+}
+
+/// Permanently deletes the files of slots that have been in the trash for
+/// at least `older_than_days` days and returns their ids. Zero empties the
+/// trash.
+#[flutter_rust_bridge::frb(sync)]
+pub fn purge_trash(older_than_days: i64) -> Result<Vec<String>, SaveError> {
+    with_save_manager_mut(move |manager| manager.purge_trash(older_than_days))
+}
+
+/// Points autosave at `slot_id`, keeping up to `max_backups` rotating backups.
+#[flutter_rust_bridge::frb(sync)]
+pub fn configure_autosave(slot_id: String, max_backups: u32) -> Result<(), SaveError> {
+    with_save_manager_mut(move |manager| manager.configure_autosave(&slot_id, max_backups))
+}
+
+/// Writes a crash-recovery backup of the configured slot without touching the
+/// slot itself.
+#[flutter_rust_bridge::frb(sync)]
+pub fn autosave(data: PlayerData) -> Result<(), SaveError> {
+    with_save_manager(move |manager| manager.autosave(&data))
+}
+
+/// Starts autosaving every `interval_secs` seconds from a Rust thread,
+/// replacing any timer already running. Each tick writes the latest data
+/// passed to `update_autosave_payload` as a backup of the configured slot,
+/// and skips the write if nothing new arrived since the previous tick. Tick
+/// failures show up in `last_error`. Runs until `stop_autosave_timer` or
+/// `close_system`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn start_autosave_timer(interval_secs: u64) -> Result<(), SaveError> {
+    if interval_secs == 0 {
+        return Err(SaveError::InvalidConfig {
+            reason: "autosave interval must be at least 1 second".to_owned(),
+        });
+    }
+    autosave_timer::start(Duration::from_secs(interval_secs), || {
+        let _ = on_worker(|current| match current.as_mut() {
+            Some(manager) => manager.autosave_pending_payload().map(drop),
+            None => Ok(()),
+        });
+    })
+    .map_err(SaveError::from)
+}
+
+/// Stops the autosave timer, waiting for a tick in progress to finish. Does
+/// nothing if it isn't running.
+#[flutter_rust_bridge::frb(sync)]
+pub fn stop_autosave_timer() {
+    autosave_timer::stop();
+}
+
+/// Hands the autosave timer the current player data; its next tick saves it.
+#[flutter_rust_bridge::frb(sync)]
+pub fn update_autosave_payload(data: PlayerData) -> Result<(), SaveError> {
+    with_save_manager_mut(move |manager| {
+        manager.update_autosave_payload(data);
+        Ok(())
+    })
+}
+
+/// Prunes autosaves by count and age every time one is written: at most
+/// `max_count` are kept, and ones older than `max_age_days` days are
+/// removed, except the newest. 0 turns either limit off.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_autosave_retention(max_count: u32, max_age_days: u32) -> Result<(), SaveError> {
+    with_save_manager_mut(move |manager| {
+        manager.set_autosave_retention(max_count, max_age_days);
+        Ok(())
+    })
+}
+
+/// How many autosaves `slot_id` has and their total size on disk.
+#[flutter_rust_bridge::frb(sync)]
+pub fn autosave_usage(slot_id: String) -> Result<AutosaveUsage, SaveError> {
+    with_save_manager(move |manager| manager.autosave_usage(&slot_id))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn list_autosaves(slot_id: String) -> Result<Vec<AutosaveInfo>, SaveError> {
+    with_save_manager(move |manager| manager.list_autosaves(&slot_id))
+}
+
+/// Promotes backup `index` back to being the slot's main save.
+#[flutter_rust_bridge::frb(sync)]
+pub fn restore_autosave(slot_id: String, index: u32) -> Result<(), SaveError> {
+    with_save_manager_mut(move |manager| manager.restore_autosave(&slot_id, index))
+}
+
+/// Saves a labelled copy of the loaded slot, e.g. "before the boss", and
+/// returns its id. Checkpoints are kept until the slot is permanently
+/// deleted.
+#[flutter_rust_bridge::frb(sync)]
+pub fn create_checkpoint(label: String) -> Result<String, SaveError> {
+    with_save_manager(move |manager| manager.create_checkpoint(DEFAULT_HANDLE, &label))
+}
+
+/// A slot's checkpoints, newest first.
+#[flutter_rust_bridge::frb(sync)]
+pub fn list_checkpoints(slot_id: String) -> Result<Vec<CheckpointInfo>, SaveError> {
+    with_save_manager(move |manager| manager.list_checkpoints(&slot_id))
+}
+
+/// Puts a checkpoint back as its slot's save. If the slot is loaded it is
+/// reloaded from the checkpoint.
+#[flutter_rust_bridge::frb(sync)]
+pub fn restore_checkpoint(checkpoint_id: String) -> Result<(), SaveError> {
+    with_save_manager_mut(move |manager| manager.restore_checkpoint(&checkpoint_id))
+}
+
+/// Serializes a slot to a versioned JSON document for sharing or backup.
+/// Works on any slot without disturbing the loaded one.
+#[flutter_rust_bridge::frb(sync)]
+pub fn export_slot_to_json(slot_id: String) -> Result<String, SaveError> {
+    with_save_manager(move |manager| manager.export_slot_to_json(&slot_id))
+}
+
+/// Creates a new slot from a document produced by `export_slot_to_json`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn import_slot_from_json(
+    json: String,
+    display_name: String,
+) -> Result<SaveSlotMetadata, SaveError> {
+    with_save_manager_mut(move |manager| manager.import_slot_from_json(&json, &display_name))
+}
+
+/// Reports what `import_slot_from_json` would import from `json`, for a
+/// confirmation dialog, without creating a slot. Fails with the same errors
+/// the import would.
+#[flutter_rust_bridge::frb(sync)]
+pub fn inspect_import(json: String) -> Result<ImportPreview, SaveError> {
+    with_save_manager(move |manager| manager.inspect_import(&json))
+}
+
+/// Imports every `.json` export directly inside `path` as a new slot named
+/// after its file, streaming one `ImportProgress` per file for a progress
+/// bar. Files that fail are reported in the stream and skipped; the call
+/// itself only fails if the directory can't be read. The stream closes once
+/// every file has been handled.
+///
+/// Not `frb(sync)`, so a large directory doesn't block the isolate; like
+/// `save_player_data_async`, the pool thread just waits on the save worker.
+pub fn import_directory(path: String, sink: StreamSink<ImportProgress>) -> Result<(), SaveError> {
+    with_save_manager_mut(move |manager| {
+        manager.import_directory(Path::new(&path), |progress| {
+            // Keep importing even if Dart stopped listening.
+            let _ = sink.add(progress);
+        })
+    })
+}
+
+/// Backs up the whole store to a tar archive at `path`: the metadata
+/// database and every slot outside the trash, each checkpointed first.
+/// Checkpoints and autosaves aren't included. Streams one
+/// `ArchiveProgress` per file written; the stream closes when the archive is
+/// complete.
+pub fn export_all(path: String, sink: StreamSink<ArchiveProgress>) -> Result<(), SaveError> {
+    with_save_manager(move |manager| {
+        manager.export_all(Path::new(&path), |progress| {
+            let _ = sink.add(progress);
+        })
+    })
+}
+
+/// Restores slots from an `export_all` archive. With `merge` they join the
+/// existing slots under new ids; otherwise every current slot is deleted
+/// first and the archive's slots and settings replace them. The archive is
+/// checked in full before anything is changed, and must have been written
+/// with the same encryption key. Streams one `ArchiveProgress` per slot
+/// restored; the stream closes once the import is done. The last event's
+/// `total` is how many slots were imported, and an archive without slots
+/// closes the stream without any.
+pub fn import_all(
+    path: String,
+    merge: bool,
+    sink: StreamSink<ArchiveProgress>,
+) -> Result<(), SaveError> {
+    with_save_manager_mut(move |manager| {
+        manager
+            .import_all(Path::new(&path), merge, |progress| {
+                let _ = sink.add(progress);
+            })
+            .map(drop)
+    })
+}
+
+/// Serializes a slot to a compact, versioned binary format. Much smaller
+/// than JSON for large inventories.
+#[flutter_rust_bridge::frb(sync)]
+pub fn export_slot_binary(slot_id: String) -> Result<Vec<u8>, SaveError> {
+    with_save_manager(move |manager| manager.export_slot_binary(&slot_id))
+}
+
+/// Creates a new slot from bytes produced by `export_slot_binary`. Foreign
+/// or newer files are rejected with `UnsupportedFormat`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn import_slot_binary(
+    bytes: Vec<u8>,
+    display_name: String,
+) -> Result<SaveSlotMetadata, SaveError> {
+    with_save_manager_mut(move |manager| manager.import_slot_binary(&bytes, &display_name))
+}
+
+/// Exports a slot as JSON or binary and gzips it, for cloud backups with a
+/// quota.
+#[flutter_rust_bridge::frb(sync)]
+pub fn export_slot_compressed(slot_id: String, format: ExportFormat) -> Result<Vec<u8>, SaveError> {
+    with_save_manager(move |manager| manager.export_slot_compressed(&slot_id, format))
+}
+
+/// Creates a new slot from any export: JSON or binary, gzipped or not. The
+/// result reports which one it was.
+#[flutter_rust_bridge::frb(sync)]
+pub fn import_slot(bytes: Vec<u8>, display_name: String) -> Result<ImportedSlot, SaveError> {
+    with_save_manager_mut(move |manager| manager.import_slot(&bytes, &display_name))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    const WRITERS: i32 = 4;
+    const SAVES_PER_WRITER: i32 = 25;
+
+    /// Player data whose fields all derive from `n`, so a load that mixes two
+    /// saves is easy to spot.
+    fn stamped_data(n: i32) -> PlayerData {
+        PlayerData {
+            health: n,
+            experience: n,
+            inventory: (0..n % 5).map(|_| format!("item-{n}")).collect(),
+        }
+    }
+
+    fn assert_not_torn(data: &PlayerData) {
+        let n = data.health;
+        assert_eq!(data.experience, n, "stats from different saves");
+        assert_eq!(
+            data.inventory.len() as i32,
+            n % 5,
+            "inventory from another save"
+        );
+        assert!(
+            data.inventory
+                .iter()
+                .all(|item| *item == format!("item-{n}")),
+            "inventory from another save: {:?}",
+            data.inventory
+        );
+    }
+
+    /// Manual saves, autosaves and loads racing on one slot through the
+    /// public API, the way the UI isolate, the FRB pool and the autosave
+    /// timer call in.
+    #[test]
+    fn concurrent_saves_and_loads_on_one_slot_stay_consistent() {
+        let _global = worker::GLOBAL_MANAGER_TEST_LOCK
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let base = std::env::temp_dir().join(format!(
+            "save_manager_api_concurrency_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&base);
+        init_system(base.display().to_string()).unwrap();
+        let slot = create_new_slot("Race".to_owned()).unwrap();
+        load_slot(slot.id.clone()).unwrap();
+        configure_autosave(slot.id.clone(), 3).unwrap();
+        save_player_data(stamped_data(0)).unwrap();
+
+        let writers: Vec<_> = (1..=WRITERS)
+            .map(|writer| {
+                thread::spawn(move || {
+                    for save in 0..SAVES_PER_WRITER {
+                        let data = stamped_data(writer * 1_000 + save);
+                        if save % 2 == 0 {
+                            save_player_data(data).unwrap();
+                        } else {
+                            save_player_data_async(data).unwrap();
+                        }
+                    }
+                })
+            })
+            .collect();
+        let autosaver = thread::spawn(|| {
+            for n in 0..10 {
+                autosave(stamped_data(90_000 + n)).unwrap();
+            }
+        });
+        let readers: Vec<_> = (0..2)
+            .map(|_| {
+                thread::spawn(|| {
+                    for _ in 0..50 {
+                        assert_not_torn(&load_player_data().unwrap());
+                    }
+                })
+            })
+            .collect();
+        for handle in writers.into_iter().chain([autosaver]).chain(readers) {
+            handle.join().unwrap();
+        }
+
+        // No save was lost or applied twice, and the slot ends on one
+        // writer's final save.
+        assert_eq!(
+            player_data_revision().unwrap(),
+            i64::from(1 + WRITERS * SAVES_PER_WRITER)
+        );
+        let last = load_player_data().unwrap();
+        assert_not_torn(&last);
+        assert!((1..=WRITERS).any(|writer| last.health == writer * 1_000 + SAVES_PER_WRITER - 1));
+        assert!(verify_slot(slot.id.clone()).unwrap());
+        assert_eq!(list_autosaves(slot.id).unwrap().len(), 3);
+
+        close_system().unwrap();
+        let _ = std::fs::remove_dir_all(&base);
+    }
+}
@@ -0,0 +1,46 @@
+//! The app's own save format version, kept apart from the slot schema.
+//!
+//! The schema version only tracks tables and columns. Games also change what
+//! the data means, e.g. rebalanced item names or a new experience curve, and
+//! migrate that content in Dart. Every save stamps the version set here into
+//! the slot so the app can tell which of those migrations a slot still needs.
+
+use std::path::Path;
+
+use rusqlite::{Connection, OptionalExtension};
+
+use super::{slot_file_path, table_has_column, SaveManager, SaveManagerResult};
+
+impl SaveManager {
+    /// Sets the version stamped into every slot saved from now on (default
+    /// 0). Resets when the system is re-initialized.
+    pub fn set_app_save_version(&mut self, version: u32) {
+        self.app_save_version = version;
+    }
+
+    /// The app save version `slot_id` was last saved with, read without
+    /// loading the slot. Slots never saved, or last saved before versions
+    /// were stored, report 0.
+    pub fn slot_app_version(&self, slot_id: &str) -> SaveManagerResult<u32> {
+        let file_path = slot_file_path(&*self.metadata_connection()?, slot_id)?;
+        let conn = self.open_readonly(Path::new(&file_path))?;
+        Ok(stored_app_save_version(&conn)?)
+    }
+}
+
+/// The version stored in the slot open on `conn`, or 0 if there is none.
+pub(super) fn stored_app_save_version(conn: &Connection) -> rusqlite::Result<u32> {
+    // A slot that hasn't been loaded since the column was added isn't
+    // migrated yet.
+    if !table_has_column(conn, "player_stats", "app_save_version")? {
+        return Ok(0);
+    }
+    Ok(conn
+        .query_row(
+            "SELECT app_save_version FROM player_stats WHERE id = 1",
+            [],
+            |row| row.get(0),
+        )
+        .optional()?
+        .unwrap_or(0))
+}
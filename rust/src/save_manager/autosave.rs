@@ -0,0 +1,326 @@
+//! Rotating crash-recovery backups kept next to a slot's main file.
+//!
+//! Backups are named `<slot_id>.autosave.<n>.db`, where `0` is always the most
+//! recent. Writing a backup never touches the slot's own database.
+//!
+//! A retention policy can prune backups further, by count and by age, every
+//! time a new one is written. Backup 0 is never pruned, however old.
+//!
+//! Each backup records when it was written, by the manager's clock, in a
+//! one-row `backup_info` table of its own. Ages are measured against that
+//! rather than the file's modification time, which comes from another clock
+//! and changes whenever the file is copied.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use rusqlite::OptionalExtension;
+
+use super::{
+    checkpoint, current_revision, format_timestamp, move_slot_files, remove_file_if_exists,
+    remove_slot_files, run_migrations, slot_file_path, slot_files, slot_files_size,
+    write_player_data, SaveManager, SaveManagerError, SaveManagerResult,
+};
+use crate::api::saves::{AutosaveInfo, AutosaveUsage, PlayerData, SlotEventKind};
+
+pub(super) struct AutosaveConfig {
+    slot_id: String,
+    max_backups: u32,
+}
+
+/// Limits on the backups kept, applied on every autosave. 0 means no limit.
+#[derive(Default)]
+pub(super) struct AutosaveRetention {
+    max_count: u32,
+    max_age_days: u32,
+}
+
+impl SaveManager {
+    /// Directs subsequent `autosave` calls at `slot_id`, keeping at most
+    /// `max_backups` (minimum one) rotating backups. Excess backups left from
+    /// a larger previous setting are pruned straight away.
+    pub fn configure_autosave(&mut self, slot_id: &str, max_backups: u32) -> SaveManagerResult<()> {
+        slot_file_path(&*self.metadata_connection()?, slot_id)?;
+
+        let max_backups = max_backups.max(1);
+        self.prune_autosaves(slot_id, max_backups)?;
+        self.autosave = Some(AutosaveConfig {
+            slot_id: slot_id.to_owned(),
+            max_backups,
+        });
+        Ok(())
+    }
+
+    /// Sets the retention policy applied on every autosave from now on: keep
+    /// at most `max_count` backups, fewer than `configure_autosave` allows if
+    /// smaller, and drop backups older than `max_age_days` days, except the
+    /// newest. 0 turns either limit off (the default). Resets when the save
+    /// system is re-initialized.
+    pub fn set_autosave_retention(&mut self, max_count: u32, max_age_days: u32) {
+        self.autosave_retention = AutosaveRetention {
+            max_count,
+            max_age_days,
+        };
+    }
+
+    /// How many backups `slot_id` has and the bytes they take up on disk,
+    /// sidecar files included.
+    pub fn autosave_usage(&self, slot_id: &str) -> SaveManagerResult<AutosaveUsage> {
+        slot_file_path(&*self.metadata_connection()?, slot_id)?;
+
+        let autosaves = self.existing_autosaves(slot_id)?;
+        let mut total_bytes = 0;
+        for (_, path) in &autosaves {
+            total_bytes += slot_files_size(path)?;
+        }
+        Ok(AutosaveUsage {
+            count: autosaves.len() as u32,
+            total_bytes,
+        })
+    }
+
+    /// Writes `data` as the newest backup of the configured slot, shifting
+    /// older backups down and dropping the oldest once the limit is reached,
+    /// then prunes whatever the retention policy no longer allows.
+    pub fn autosave(&self, data: &PlayerData) -> SaveManagerResult<()> {
+        let config = self
+            .autosave
+            .as_ref()
+            .ok_or(SaveManagerError::AutosaveNotConfigured)?;
+        let slot_id = config.slot_id.as_str();
+        let max_backups = match self.autosave_retention.max_count {
+            0 => config.max_backups,
+            max_count => config.max_backups.min(max_count),
+        };
+        self.validate_player_data(data)?;
+
+        // Build the backup off to the side so a failed write never costs us an
+        // existing one.
+        let pending = self
+            .saves_dir
+            .join(format!("{slot_id}.autosave.pending.db"));
+        remove_slot_files(&pending)?;
+        {
+            let mut conn = self.open_connection(&pending)?;
+            run_migrations(&self.slot_migrations, &mut conn)?;
+            write_player_data(
+                &mut conn,
+                data,
+                self.checksum_for(data).as_deref(),
+                self.app_save_version,
+                None,
+            )?;
+            let (_, written_at_ms) = self.now_timestamp();
+            conn.execute(
+                "CREATE TABLE backup_info (written_at_ms INTEGER NOT NULL)",
+                [],
+            )?;
+            conn.execute(
+                "INSERT INTO backup_info (written_at_ms) VALUES (?1)",
+                [written_at_ms],
+            )?;
+            checkpoint(&conn)?;
+        }
+
+        remove_slot_files(&self.autosave_path(slot_id, max_backups - 1))?;
+        for index in (0..max_backups - 1).rev() {
+            let from = self.autosave_path(slot_id, index);
+            if from.exists() {
+                move_slot_files(&from, &self.autosave_path(slot_id, index + 1))?;
+            }
+        }
+        move_slot_files(&pending, &self.autosave_path(slot_id, 0))?;
+        self.prune_autosaves(slot_id, max_backups)?;
+        self.prune_expired_autosaves(slot_id)
+    }
+
+    /// Removes backups older than the retention policy's age limit, sparing
+    /// the newest. Backups written before write times were recorded have no
+    /// known age and are left to the count limit.
+    fn prune_expired_autosaves(&self, slot_id: &str) -> SaveManagerResult<()> {
+        let max_age_days = self.autosave_retention.max_age_days;
+        if max_age_days == 0 {
+            return Ok(());
+        }
+        let max_age = Duration::from_secs(u64::from(max_age_days) * 24 * 60 * 60);
+        let now = self.clock.now();
+        for (index, path) in self.existing_autosaves(slot_id)? {
+            if index == 0 {
+                continue;
+            }
+            let expired = self.backup_written_at(&path)?.is_some_and(|written_at| {
+                now.duration_since(written_at)
+                    .is_ok_and(|age| age > max_age)
+            });
+            if expired {
+                remove_slot_files(&path)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Holds `data` for the autosave timer, replacing whatever it was going to
+    /// write next.
+    pub fn update_autosave_payload(&mut self, data: PlayerData) {
+        self.autosave_payload = Some(data);
+    }
+
+    /// Autosaves the payload from `update_autosave_payload`, if one arrived
+    /// since the last call, and reports whether anything was written. A
+    /// payload that fails to save is kept for the next try unless a newer one
+    /// replaces it.
+    pub fn autosave_pending_payload(&mut self) -> SaveManagerResult<bool> {
+        let Some(data) = self.autosave_payload.take() else {
+            return Ok(false);
+        };
+        if let Err(err) = self.autosave(&data) {
+            self.autosave_payload = Some(data);
+            return Err(err);
+        }
+        Ok(true)
+    }
+
+    /// Lists a slot's backups, newest (index 0) first. `saved_at` falls back
+    /// to the file's modification time for backups that predate recorded
+    /// write times.
+    pub fn list_autosaves(&self, slot_id: &str) -> SaveManagerResult<Vec<AutosaveInfo>> {
+        slot_file_path(&*self.metadata_connection()?, slot_id)?;
+
+        let mut autosaves = Vec::new();
+        for (index, path) in self.existing_autosaves(slot_id)? {
+            let file = fs::metadata(&path)?;
+            let saved_at = match self.backup_written_at(&path)? {
+                Some(written_at) => written_at,
+                None => file.modified()?,
+            };
+            autosaves.push(AutosaveInfo {
+                index,
+                saved_at: format_timestamp(saved_at),
+                size_bytes: file.len(),
+                file_path: path.to_string_lossy().into_owned(),
+            });
+        }
+        autosaves.sort_by_key(|autosave| autosave.index);
+        Ok(autosaves)
+    }
+
+    /// Replaces the slot's main database with backup `index`. Handles that
+    /// have the slot open are closed for the swap and reopened afterwards.
+    ///
+    /// The backup is copied next to the slot first and then renamed over it,
+    /// so a failed copy leaves the slot as it was.
+    pub fn restore_autosave(&mut self, slot_id: &str, index: u32) -> SaveManagerResult<()> {
+        let file_path = PathBuf::from(slot_file_path(&*self.metadata_connection()?, slot_id)?);
+        let backup = self.autosave_path(slot_id, index);
+        if !backup.exists() {
+            return Err(SaveManagerError::AutosaveNotFound(
+                slot_id.to_owned(),
+                index,
+            ));
+        }
+
+        let pending = file_path.with_file_name(format!("{slot_id}.restore.pending.db"));
+        remove_slot_files(&pending)?;
+        if let Err(err) = fs::copy(&backup, &pending)
+            .map_err(SaveManagerError::from)
+            .and_then(|_| self.drop_backup_info(&pending))
+        {
+            let _ = remove_slot_files(&pending);
+            return Err(err);
+        }
+
+        let open_handles = self.handles_for_path(&file_path);
+        for handle in &open_handles {
+            self.close_connection(*handle);
+        }
+        let replaced = replace_slot_file(&pending, &file_path);
+        for handle in open_handles {
+            self.open_slot_as(handle, slot_id)?;
+        }
+        replaced?;
+
+        let revision = current_revision(&self.open_readonly(&file_path)?)?;
+        self.record_change(slot_id, SlotEventKind::Saved, Some(revision))
+    }
+
+    /// Deletes every backup belonging to `slot_id`.
+    pub(super) fn remove_autosaves(&self, slot_id: &str) -> SaveManagerResult<()> {
+        self.prune_autosaves(slot_id, 0)
+    }
+
+    fn prune_autosaves(&self, slot_id: &str, keep: u32) -> SaveManagerResult<()> {
+        for (index, path) in self.existing_autosaves(slot_id)? {
+            if index >= keep {
+                remove_slot_files(&path)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn existing_autosaves(&self, slot_id: &str) -> SaveManagerResult<Vec<(u32, PathBuf)>> {
+        let prefix = format!("{slot_id}.autosave.");
+        let mut found = Vec::new();
+        for entry in fs::read_dir(&self.saves_dir)? {
+            let path = entry?.path();
+            let index = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_prefix(&prefix))
+                .and_then(|rest| rest.strip_suffix(".db"))
+                .and_then(|index| index.parse().ok());
+            if let Some(index) = index {
+                found.push((index, path));
+            }
+        }
+        Ok(found)
+    }
+
+    /// When the backup at `path` was written, if it recorded that.
+    fn backup_written_at(&self, path: &Path) -> SaveManagerResult<Option<SystemTime>> {
+        let conn = self.open_readonly(path)?;
+        let recorded: bool = conn.query_row(
+            "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'backup_info')",
+            [],
+            |row| row.get(0),
+        )?;
+        if !recorded {
+            return Ok(None);
+        }
+        let written_at_ms: Option<i64> = conn
+            .query_row("SELECT written_at_ms FROM backup_info", [], |row| {
+                row.get(0)
+            })
+            .optional()?;
+        Ok(written_at_ms.map(|ms| UNIX_EPOCH + Duration::from_millis(ms.max(0) as u64)))
+    }
+
+    /// Strips the backup bookkeeping from a restored copy, so the slot ends
+    /// up with only its own tables.
+    fn drop_backup_info(&self, path: &Path) -> SaveManagerResult<()> {
+        let conn = self.open_connection(path)?;
+        conn.execute("DROP TABLE IF EXISTS backup_info", [])?;
+        checkpoint(&conn)?;
+        Ok(())
+    }
+
+    fn autosave_path(&self, slot_id: &str, index: u32) -> PathBuf {
+        self.saves_dir
+            .join(format!("{slot_id}.autosave.{index}.db"))
+    }
+}
+
+/// Moves the database at `pending` over `file_path`. The slot's handles are
+/// closed by now, so its sidecars hold nothing the new file needs, and a
+/// leftover WAL would be replayed onto it.
+fn replace_slot_file(pending: &Path, file_path: &Path) -> io::Result<()> {
+    for sidecar in &slot_files(file_path)[1..] {
+        remove_file_if_exists(sidecar)?;
+    }
+    let moved = move_slot_files(pending, file_path);
+    if moved.is_err() {
+        let _ = remove_slot_files(pending);
+    }
+    moved
+}
@@ -0,0 +1,58 @@
+//! The background thread behind `start_autosave_timer`.
+//!
+//! The timer never touches a `SaveManager` itself: each tick is a callback
+//! that the API layer points at the worker thread, so timed autosaves queue
+//! up behind other save calls like any other command. Only one timer runs at
+//! a time, process-wide.
+
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use super::SaveManagerResult;
+
+struct Timer {
+    /// Dropping this wakes the thread and tells it to finish.
+    stop: Sender<()>,
+    thread: JoinHandle<()>,
+}
+
+static TIMER: Mutex<Option<Timer>> = Mutex::new(None);
+
+/// Calls `tick` every `interval` on a new thread until `stop` is called,
+/// replacing any timer already running.
+pub fn start(interval: Duration, mut tick: impl FnMut() + Send + 'static) -> SaveManagerResult<()> {
+    let mut timer = timer();
+    stop_locked(&mut timer);
+    let (stop, stopped) = mpsc::channel::<()>();
+    let thread = thread::Builder::new()
+        .name("autosave-timer".to_owned())
+        .spawn(move || {
+            while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+                tick();
+            }
+        })?;
+    *timer = Some(Timer { stop, thread });
+    Ok(())
+}
+
+/// Stops the running timer, if any, and waits for a tick in progress to
+/// finish. Must not be called from the worker thread, which that tick may be
+/// waiting on.
+pub fn stop() {
+    stop_locked(&mut timer());
+}
+
+fn stop_locked(timer: &mut Option<Timer>) {
+    if let Some(Timer { stop, thread }) = timer.take() {
+        drop(stop);
+        // A tick that panicked already ended the thread; nothing to clean up.
+        let _ = thread.join();
+    }
+}
+
+// Only ever holds a complete `Option<Timer>`, so poisoning is safe to ignore.
+fn timer() -> MutexGuard<'static, Option<Timer>> {
+    TIMER.lock().unwrap_or_else(PoisonError::into_inner)
+}
@@ -0,0 +1,34 @@
+//! Several player-data and flag changes applied as one transaction, for game
+//! events that must update stats, inventory and flags together.
+
+use rusqlite::params;
+
+use super::{flags, inventory, SaveManager, SaveManagerResult, SlotHandle};
+use crate::api::saves::SaveOp;
+
+impl SaveManager {
+    /// Applies `ops` in order inside a single transaction on the slot. If any
+    /// of them fails, none are kept. Like the single-item inventory edits,
+    /// this needs a slot that has been saved at least once.
+    pub fn apply_batch(&self, handle: SlotHandle, ops: &[SaveOp]) -> SaveManagerResult<()> {
+        self.edit_player_data(handle, |tx| {
+            for op in ops {
+                match op {
+                    SaveOp::SetStats { health, experience } => {
+                        self.validate_stats(*health, *experience)?;
+                        tx.execute(
+                            "UPDATE player_stats SET health = ?1, experience = ?2 WHERE id = 1",
+                            params![health, experience],
+                        )?;
+                    }
+                    SaveOp::AddItem { item, quantity } => {
+                        self.append_items(tx, item, *quantity)?;
+                    }
+                    SaveOp::RemoveItem { position } => inventory::remove_item(tx, *position)?,
+                    SaveOp::SetFlag { key, value } => flags::write_flag(tx, key, value)?,
+                }
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,124 @@
+//! Compact binary export and import, for saves too large to pass around as
+//! JSON.
+//!
+//! Layout, little-endian throughout:
+//!
+//! ```text
+//! magic      4 bytes   b"SSAV"
+//! version    u8        BINARY_FORMAT_VERSION
+//! health     i32
+//! experience i32
+//! count      u32       number of inventory items
+//! items      count × (u32 byte length, UTF-8 bytes)
+//! ```
+
+use std::path::Path;
+
+use super::{read_player_data, slot_file_path, SaveManager, SaveManagerError, SaveManagerResult};
+use crate::api::saves::{PlayerData, SaveSlotMetadata};
+
+const MAGIC: &[u8; 4] = b"SSAV";
+
+/// Version written into exported files and the only one import accepts.
+const BINARY_FORMAT_VERSION: u8 = 1;
+
+impl SaveManager {
+    /// Serializes any slot's stats and ordered inventory in the binary format,
+    /// reading it on its own read-only connection.
+    pub fn export_slot_binary(&self, slot_id: &str) -> SaveManagerResult<Vec<u8>> {
+        let file_path = slot_file_path(&*self.metadata_connection()?, slot_id)?;
+        let conn = self.open_readonly(Path::new(&file_path))?;
+        let data = read_player_data(&conn)?.ok_or(SaveManagerError::NoPlayerData)?;
+        Ok(encode(&data))
+    }
+
+    /// Creates a brand-new slot from bytes produced by `export_slot_binary`.
+    pub fn import_slot_binary(
+        &mut self,
+        bytes: &[u8],
+        display_name: &str,
+    ) -> SaveManagerResult<SaveSlotMetadata> {
+        let data = decode(bytes)?;
+        self.create_slot_with_data(display_name, &data)
+    }
+}
+
+/// Whether `bytes` start like a binary export, of any version.
+pub(super) fn is_binary_export(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
+
+pub(super) fn encode(data: &PlayerData) -> Vec<u8> {
+    let items_len: usize = data.inventory.iter().map(|item| 4 + item.len()).sum();
+    let mut bytes = Vec::with_capacity(MAGIC.len() + 13 + items_len);
+    bytes.extend_from_slice(MAGIC);
+    bytes.push(BINARY_FORMAT_VERSION);
+    bytes.extend_from_slice(&data.health.to_le_bytes());
+    bytes.extend_from_slice(&data.experience.to_le_bytes());
+    bytes.extend_from_slice(&(data.inventory.len() as u32).to_le_bytes());
+    for item in &data.inventory {
+        bytes.extend_from_slice(&(item.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(item.as_bytes());
+    }
+    bytes
+}
+
+fn decode(bytes: &[u8]) -> SaveManagerResult<PlayerData> {
+    let unsupported = |reason: &str| SaveManagerError::UnsupportedFormat(reason.to_owned());
+
+    let mut reader = Reader(bytes);
+    if reader.take(MAGIC.len()).ok() != Some(MAGIC.as_slice()) {
+        return Err(unsupported("not a binary save export"));
+    }
+    match reader.take(1)?[0] {
+        BINARY_FORMAT_VERSION => {}
+        version => return Err(unsupported(&format!("unknown format version {version}"))),
+    }
+
+    let health = i32::from_le_bytes(reader.array()?);
+    let experience = i32::from_le_bytes(reader.array()?);
+    let count = u32::from_le_bytes(reader.array()?);
+    // Every item needs at least its length prefix, which bounds `count`
+    // before anything is allocated for it.
+    if count as usize > reader.0.len() / 4 {
+        return Err(unsupported("file is truncated"));
+    }
+    let mut inventory = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let len = u32::from_le_bytes(reader.array()?) as usize;
+        let item = std::str::from_utf8(reader.take(len)?)
+            .map_err(|_| unsupported("inventory items must be UTF-8"))?;
+        inventory.push(item.to_owned());
+    }
+    if !reader.0.is_empty() {
+        return Err(unsupported("unexpected data after the inventory"));
+    }
+
+    Ok(PlayerData {
+        health,
+        experience,
+        inventory,
+    })
+}
+
+/// Cursor over the remaining input.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> SaveManagerResult<&'a [u8]> {
+        if self.0.len() < len {
+            return Err(SaveManagerError::UnsupportedFormat(
+                "file is truncated".to_owned(),
+            ));
+        }
+        let (head, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(head)
+    }
+
+    fn array<const N: usize>(&mut self) -> SaveManagerResult<[u8; N]> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+}
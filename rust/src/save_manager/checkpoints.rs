@@ -0,0 +1,138 @@
+//! Labelled save points the player takes by hand during a long session.
+//!
+//! Unlike autosaves, checkpoints are never rotated out: each is a full copy
+//! of the slot's database named `<slot_id>.checkpoint.<checkpoint_id>.db`,
+//! with its label and time kept in the metadata database.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rusqlite::{params, OptionalExtension};
+
+use super::{
+    checkpoint, file_size, remove_slot_files, slot_file_path, SaveManager, SaveManagerError,
+    SaveManagerResult, SlotHandle,
+};
+use crate::api::saves::CheckpointInfo;
+
+impl SaveManager {
+    /// Copies the slot open on `handle` to a new checkpoint and returns its
+    /// id. The slot is checkpointed first so the copy includes everything
+    /// still sitting in its WAL.
+    pub fn create_checkpoint(&self, handle: SlotHandle, label: &str) -> SaveManagerResult<String> {
+        let slot = self.slot_entry(handle)?;
+        let meta_conn = self.metadata_connection()?;
+        let checkpoint_id = self.new_id(&meta_conn)?;
+        let file_path = self.checkpoint_path(&slot.slot_id, &checkpoint_id);
+
+        checkpoint(&slot.conn)?;
+        fs::copy(slot.conn.path().unwrap_or_default(), &file_path)?;
+
+        let (now, _) = self.now_timestamp();
+        if let Err(err) = meta_conn.execute(
+            "INSERT INTO slot_checkpoints (id, slot_id, label, created_at, file_path)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                checkpoint_id,
+                slot.slot_id,
+                label,
+                now,
+                file_path.to_string_lossy()
+            ],
+        ) {
+            // Don't leave an unregistered copy behind.
+            let _ = remove_slot_files(&file_path);
+            return Err(err.into());
+        }
+        Ok(checkpoint_id)
+    }
+
+    /// A slot's checkpoints, newest first.
+    pub fn list_checkpoints(&self, slot_id: &str) -> SaveManagerResult<Vec<CheckpointInfo>> {
+        let conn = self.metadata_connection()?;
+        slot_file_path(&conn, slot_id)?;
+        let mut stmt = conn.prepare(
+            "SELECT id, slot_id, label, created_at, file_path FROM slot_checkpoints
+             WHERE slot_id = ?1
+             ORDER BY created_at DESC, rowid DESC",
+        )?;
+        let rows = stmt
+            .query_map([slot_id], |row| {
+                Ok(CheckpointInfo {
+                    id: row.get(0)?,
+                    slot_id: row.get(1)?,
+                    label: row.get(2)?,
+                    created_at: row.get(3)?,
+                    size_bytes: 0,
+                    file_path: row.get(4)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        rows.into_iter()
+            .map(|mut info| {
+                info.size_bytes = file_size(Path::new(&info.file_path))?;
+                Ok(info)
+            })
+            .collect()
+    }
+
+    /// Replaces a slot's database with one of its checkpoints. Handles that
+    /// have the slot open are closed for the copy and reopened afterwards.
+    /// The checkpoint itself is kept, so it can be restored again.
+    pub fn restore_checkpoint(&mut self, checkpoint_id: &str) -> SaveManagerResult<()> {
+        let meta_conn = self.metadata_connection()?;
+        let (slot_id, backup): (String, String) = meta_conn
+            .query_row(
+                "SELECT slot_id, file_path FROM slot_checkpoints WHERE id = ?1",
+                params![checkpoint_id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?
+            .ok_or_else(|| SaveManagerError::CheckpointNotFound(checkpoint_id.to_owned()))?;
+        let file_path = PathBuf::from(slot_file_path(&meta_conn, &slot_id)?);
+        if !Path::new(&backup).exists() {
+            return Err(SaveManagerError::CheckpointNotFound(
+                checkpoint_id.to_owned(),
+            ));
+        }
+
+        let open_handles = self.handles_for_path(&file_path);
+        for handle in &open_handles {
+            self.close_connection(*handle);
+        }
+
+        remove_slot_files(&file_path)?;
+        fs::copy(&backup, &file_path)?;
+
+        for handle in open_handles {
+            self.open_slot_as(handle, &slot_id)?;
+        }
+        Ok(())
+    }
+
+    /// Deletes every checkpoint belonging to `slot_id`, files and rows.
+    pub(super) fn remove_checkpoints(&self, slot_id: &str) -> SaveManagerResult<()> {
+        let meta_conn = self.metadata_connection()?;
+        let files = {
+            let mut stmt =
+                meta_conn.prepare("SELECT file_path FROM slot_checkpoints WHERE slot_id = ?1")?;
+            let files = stmt
+                .query_map([slot_id], |row| row.get::<_, String>(0))?
+                .collect::<Result<Vec<_>, _>>()?;
+            files
+        };
+        for file in files {
+            remove_slot_files(Path::new(&file))?;
+        }
+        meta_conn.execute(
+            "DELETE FROM slot_checkpoints WHERE slot_id = ?1",
+            params![slot_id],
+        )?;
+        Ok(())
+    }
+
+    fn checkpoint_path(&self, slot_id: &str, checkpoint_id: &str) -> PathBuf {
+        self.saves_dir
+            .join(format!("{slot_id}.checkpoint.{checkpoint_id}.db"))
+    }
+}
@@ -0,0 +1,127 @@
+//! Tamper detection for saved player data.
+//!
+//! Each save stores a SHA-256 of the data in its binary export encoding,
+//! which is canonical: the same data always hashes the same way. The digest
+//! is written by hand because it is the only hashing the crate needs.
+
+use rusqlite::Connection;
+
+use super::{binary, SaveManager, SaveManagerError, SaveManagerResult, SlotHandle};
+use crate::api::saves::PlayerData;
+
+impl SaveManager {
+    /// Turns checksum writing and verification on or off. Saves made while
+    /// it is off carry no checksum and are accepted later either way.
+    pub fn set_integrity_check_enabled(&mut self, enabled: bool) {
+        self.integrity_check = enabled;
+    }
+
+    /// The checksum to store with `data`, or `None` when checks are off.
+    pub(super) fn checksum_for(&self, data: &PlayerData) -> Option<String> {
+        self.integrity_check.then(|| player_data_checksum(data))
+    }
+
+    /// Fails with `ChecksumMismatch` if `data`, just read from `handle`'s
+    /// slot through `conn`, doesn't match the checksum stored alongside it.
+    pub(super) fn verify_checksum(
+        &self,
+        handle: SlotHandle,
+        conn: &Connection,
+        data: &PlayerData,
+    ) -> SaveManagerResult<()> {
+        if !self.integrity_check {
+            return Ok(());
+        }
+        let stored: Option<String> = conn.query_row(
+            "SELECT save_checksum FROM player_stats WHERE id = 1",
+            [],
+            |row| row.get(0),
+        )?;
+        match stored {
+            Some(stored) if stored != player_data_checksum(data) => {
+                let slot_id = self.slot_entry(handle)?.slot_id.clone();
+                Err(SaveManagerError::ChecksumMismatch(slot_id))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+fn player_data_checksum(data: &PlayerData) -> String {
+    sha256(&binary::encode(data))
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// SHA-256 as specified in FIPS 180-4.
+fn sha256(data: &[u8]) -> [u8; 32] {
+    let mut state: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ];
+
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((data.len() as u64) * 8).to_be_bytes());
+
+    for block in message.chunks_exact(64) {
+        let mut w = [0u32; 64];
+        for (i, word) in block.chunks_exact(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[i - 7])
+                .wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+        for i in 0..64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = h
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(K[i])
+                .wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(maj);
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+        for (word, value) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *word = word.wrapping_add(value);
+        }
+    }
+
+    let mut digest = [0; 32];
+    for (chunk, word) in digest.chunks_exact_mut(4).zip(state) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    digest
+}
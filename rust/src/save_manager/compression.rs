@@ -0,0 +1,141 @@
+//! Gzip-wrapped exports and an import that accepts any export format.
+//!
+//! Gzip is framed by hand around `miniz_oxide`'s raw deflate so the files open
+//! with standard tools without pulling in a larger compression crate.
+
+use super::{binary, SaveManager, SaveManagerError, SaveManagerResult};
+use crate::api::saves::{ExportFormat, ImportedSlot};
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Deflate level; 6 is gzip's own default.
+const COMPRESSION_LEVEL: u8 = 6;
+
+/// Upper bound on a decompressed export, so a tiny malicious file can't
+/// expand into gigabytes.
+const MAX_DECOMPRESSED_BYTES: usize = 64 * 1024 * 1024;
+
+impl SaveManager {
+    /// Exports a slot in `format` and gzips the result.
+    pub fn export_slot_compressed(
+        &self,
+        slot_id: &str,
+        format: ExportFormat,
+    ) -> SaveManagerResult<Vec<u8>> {
+        let raw = match format {
+            ExportFormat::Json => self.export_slot_to_json(slot_id)?.into_bytes(),
+            ExportFormat::Binary => self.export_slot_binary(slot_id)?,
+        };
+        Ok(gzip(&raw))
+    }
+
+    /// Imports any export, gzipped or not, working out its format from the
+    /// leading bytes.
+    pub fn import_slot(
+        &mut self,
+        bytes: &[u8],
+        display_name: &str,
+    ) -> SaveManagerResult<ImportedSlot> {
+        let compressed = bytes.starts_with(&GZIP_MAGIC);
+        let decompressed;
+        let raw = if compressed {
+            decompressed = gunzip(bytes)?;
+            decompressed.as_slice()
+        } else {
+            bytes
+        };
+
+        let (slot, format) = if binary::is_binary_export(raw) {
+            (
+                self.import_slot_binary(raw, display_name)?,
+                ExportFormat::Binary,
+            )
+        } else {
+            let json = std::str::from_utf8(raw).map_err(|_| {
+                SaveManagerError::UnsupportedFormat("not a recognised export".to_owned())
+            })?;
+            (
+                self.import_slot_from_json(json, display_name)?,
+                ExportFormat::Json,
+            )
+        };
+        Ok(ImportedSlot {
+            slot,
+            format,
+            compressed,
+        })
+    }
+}
+
+fn gzip(data: &[u8]) -> Vec<u8> {
+    let deflated = miniz_oxide::deflate::compress_to_vec(data, COMPRESSION_LEVEL);
+    let mut out = Vec::with_capacity(deflated.len() + 18);
+    // No optional fields, no mtime, "unknown" OS.
+    out.extend_from_slice(&[GZIP_MAGIC[0], GZIP_MAGIC[1], 8, 0, 0, 0, 0, 0, 0, 255]);
+    out.extend_from_slice(&deflated);
+    out.extend_from_slice(&crc32(data).to_le_bytes());
+    out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    out
+}
+
+fn gunzip(bytes: &[u8]) -> SaveManagerResult<Vec<u8>> {
+    let invalid = |reason: &str| SaveManagerError::UnsupportedFormat(format!("gzip: {reason}"));
+
+    const FHCRC: u8 = 0x02;
+    const FEXTRA: u8 = 0x04;
+    const FNAME: u8 = 0x08;
+    const FCOMMENT: u8 = 0x10;
+
+    if bytes.len() < 18 || !bytes.starts_with(&GZIP_MAGIC) || bytes[2] != 8 {
+        return Err(invalid("not a deflate stream"));
+    }
+    let flags = bytes[3];
+    let mut pos = 10;
+    if flags & FEXTRA != 0 {
+        let len = bytes
+            .get(pos..pos + 2)
+            .ok_or_else(|| invalid("truncated header"))?;
+        pos += 2 + u16::from_le_bytes([len[0], len[1]]) as usize;
+    }
+    for flag in [FNAME, FCOMMENT] {
+        if flags & flag != 0 {
+            let end = bytes
+                .get(pos..)
+                .and_then(|rest| rest.iter().position(|&b| b == 0))
+                .ok_or_else(|| invalid("truncated header"))?;
+            pos += end + 1;
+        }
+    }
+    if flags & FHCRC != 0 {
+        pos += 2;
+    }
+    if pos > bytes.len() - 8 {
+        return Err(invalid("truncated header"));
+    }
+
+    let (body, trailer) = bytes[pos..].split_at(bytes.len() - pos - 8);
+    let data = miniz_oxide::inflate::decompress_to_vec_with_limit(body, MAX_DECOMPRESSED_BYTES)
+        .map_err(|_| invalid("corrupt or oversized data"))?;
+    let expected_crc = u32::from_le_bytes([trailer[0], trailer[1], trailer[2], trailer[3]]);
+    let expected_len = u32::from_le_bytes([trailer[4], trailer[5], trailer[6], trailer[7]]);
+    if crc32(&data) != expected_crc || data.len() as u32 != expected_len {
+        return Err(invalid("checksum mismatch"));
+    }
+    Ok(data)
+}
+
+/// CRC-32 (IEEE), as gzip requires.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
@@ -0,0 +1,54 @@
+//! Side-by-side comparison of two slots, e.g. a save and a branch duplicated
+//! from it.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use super::{read_player_data, slot_file_path, SaveManager, SaveManagerResult};
+use crate::api::saves::{ItemChange, ItemDiff, PlayerData, SlotDiff};
+
+impl SaveManager {
+    /// Compares two slots' stats and inventories, reading each on its own
+    /// read-only connection. A slot that was never saved has no stats and
+    /// an empty inventory. Inventory order is ignored: items are compared by
+    /// how many of each a slot holds.
+    pub fn diff_slots(&self, slot_a: &str, slot_b: &str) -> SaveManagerResult<SlotDiff> {
+        let a = self.read_slot_data(slot_a)?;
+        let b = self.read_slot_data(slot_b)?;
+
+        let mut counts: BTreeMap<&str, (u32, u32)> = BTreeMap::new();
+        for item in a.iter().flat_map(|data| &data.inventory) {
+            counts.entry(item).or_default().0 += 1;
+        }
+        for item in b.iter().flat_map(|data| &data.inventory) {
+            counts.entry(item).or_default().1 += 1;
+        }
+        let items = counts
+            .into_iter()
+            .filter(|(_, (count_a, count_b))| count_a != count_b)
+            .map(|(item, (count_a, count_b))| ItemDiff {
+                item: item.to_owned(),
+                change: match (count_a, count_b) {
+                    (0, _) => ItemChange::Added,
+                    (_, 0) => ItemChange::Removed,
+                    _ => ItemChange::QuantityChanged,
+                },
+                count_a,
+                count_b,
+            })
+            .collect();
+
+        Ok(SlotDiff {
+            health_a: a.as_ref().map(|data| data.health),
+            health_b: b.as_ref().map(|data| data.health),
+            experience_a: a.as_ref().map(|data| data.experience),
+            experience_b: b.as_ref().map(|data| data.experience),
+            items,
+        })
+    }
+
+    pub(super) fn read_slot_data(&self, slot_id: &str) -> SaveManagerResult<Option<PlayerData>> {
+        let file_path = slot_file_path(&*self.metadata_connection()?, slot_id)?;
+        read_player_data(&self.open_readonly(Path::new(&file_path))?)
+    }
+}
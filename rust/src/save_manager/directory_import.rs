@@ -0,0 +1,65 @@
+//! Bulk import of a directory of JSON exports, e.g. when migrating saves
+//! from an older version of the app.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{SaveManager, SaveManagerResult};
+use crate::api::saves::ImportProgress;
+
+impl SaveManager {
+    /// Imports every `.json` file directly inside `dir` as a new slot named
+    /// after the file, in file name order, and reports each one to
+    /// `on_progress` as it finishes. A file that can't be read or imported is
+    /// reported with its error and skipped; only failing to list `dir` stops
+    /// the import.
+    pub fn import_directory(
+        &mut self,
+        dir: &Path,
+        mut on_progress: impl FnMut(ImportProgress),
+    ) -> SaveManagerResult<()> {
+        let files = json_files(dir)?;
+        let total = files.len();
+        for (index, file) in files.iter().enumerate() {
+            let display_name = file
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .into_owned();
+            let imported = fs::read_to_string(file)
+                .map_err(Into::into)
+                .and_then(|json| self.import_slot_from_json(&json, &display_name));
+            let (slot_id, error) = match imported {
+                Ok(slot) => (Some(slot.id), None),
+                Err(err) => (None, Some(err.to_string())),
+            };
+            on_progress(ImportProgress {
+                current: index + 1,
+                total,
+                file_name: file
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .into_owned(),
+                slot_id,
+                error,
+            });
+        }
+        Ok(())
+    }
+}
+
+fn json_files(dir: &Path) -> SaveManagerResult<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let is_json = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+        if is_json && path.is_file() {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
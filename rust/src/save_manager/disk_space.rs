@@ -0,0 +1,48 @@
+//! Early warning before the saves filesystem runs out of space.
+//!
+//! A write that hits `ENOSPC` is rolled back, so the slot itself survives,
+//! but the player loses the save. Warning while there is still room gives the
+//! UI a chance to ask them to free some up.
+
+use super::{events, SaveManager, SaveManagerError};
+use crate::api::saves::SlotEventKind;
+
+/// Free space, in bytes, below which saves warn by default.
+pub(super) const DEFAULT_LOW_DISK_SPACE_BYTES: u64 = 50 * 1024 * 1024;
+
+impl SaveManager {
+    /// Sets how little free space, in bytes, makes a save emit
+    /// `LowDiskSpace` first (default 50 MiB). 0 turns the warning off. Resets
+    /// when the save system is re-initialized.
+    pub fn set_low_disk_space_threshold(&mut self, bytes: u64) {
+        self.low_disk_space_bytes = bytes;
+    }
+
+    /// Emits `LowDiskSpace` for `slot_id` if the saves directory's free space
+    /// is below the threshold. The save goes ahead either way; this is only a
+    /// warning, and free space that can't be determined isn't reported.
+    pub(super) fn warn_if_low_on_disk(&self, slot_id: &str) {
+        let low = self
+            .disk_space
+            .free_bytes(&self.saves_dir)
+            .is_some_and(|free| free < self.low_disk_space_bytes);
+        if low {
+            events::emit(slot_id, SlotEventKind::LowDiskSpace);
+        }
+    }
+}
+
+/// Reports a write that ran out of space as `DiskFull`.
+pub(super) fn full_error(err: SaveManagerError) -> SaveManagerError {
+    match err {
+        SaveManagerError::Database(ref db_err)
+            if db_err.sqlite_error_code() == Some(rusqlite::ErrorCode::DiskFull) =>
+        {
+            SaveManagerError::DiskFull
+        }
+        SaveManagerError::Io(ref io_err) if io_err.kind() == std::io::ErrorKind::StorageFull => {
+            SaveManagerError::DiskFull
+        }
+        err => err,
+    }
+}
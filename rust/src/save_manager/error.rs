@@ -0,0 +1,138 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum SaveManagerError {
+    NotInitialized,
+    AlreadyInitialized(String),
+    NoActiveSlot,
+    UnknownHandle(u32),
+    SlotNotFound(String),
+    InvalidSlotName(String),
+    CorruptSlot(String),
+    InvalidTag(String),
+    /// The active slot exists but has never been saved to.
+    NoPlayerData,
+    InvalidPlayerData {
+        field: &'static str,
+        reason: String,
+    },
+    UnsupportedFormat(String),
+    ThumbnailTooLarge {
+        size: usize,
+        max: usize,
+    },
+    AutosaveNotConfigured,
+    AutosaveNotFound(String, u32),
+    CheckpointNotFound(String),
+    /// The operation needs exclusive access but a handle has the slot open.
+    SlotInUse(String),
+    /// A database could not be read with the configured encryption key.
+    DecryptionFailed,
+    InvalidConfig(String),
+    /// Stored player data no longer matches the checksum saved with it.
+    ChecksumMismatch(String),
+    /// Another connection kept the slot locked past the busy timeout.
+    SlotLocked(String),
+    /// The slot's schema is being migrated; try again once it is loaded.
+    SlotBusy(String),
+    /// The filesystem holding the saves ran out of space mid-write.
+    DiskFull,
+    /// A manual transaction is open on the slot; commit or roll it back
+    /// first.
+    TransactionInProgress(String),
+    /// Commit or rollback was asked for without a manual transaction open.
+    NoTransaction,
+    /// The slot has no removed items left to put back.
+    NothingToUndo,
+    /// The metadata database lacks columns this version needs, listed in
+    /// `missing`.
+    SchemaMismatch {
+        missing: Vec<String>,
+    },
+    /// A checked save expected an older revision than the slot now holds.
+    RevisionConflict {
+        current: i64,
+    },
+    Io(std::io::Error),
+    Database(rusqlite::Error),
+    Migration(String),
+}
+
+impl fmt::Display for SaveManagerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotInitialized => write!(f, "Save system has not been initialized"),
+            Self::AlreadyInitialized(base_path) => {
+                write!(f, "Save system is already initialized for {base_path}")
+            }
+            Self::NoActiveSlot => write!(f, "No save slot is currently loaded"),
+            Self::UnknownHandle(handle) => write!(f, "No slot is open for handle {handle}"),
+            Self::SlotNotFound(id) => write!(f, "Save slot not found: {id}"),
+            Self::InvalidSlotName(reason) => write!(f, "Invalid slot name: {reason}"),
+            Self::CorruptSlot(id) => write!(f, "Save slot {id} is corrupt"),
+            Self::InvalidTag(reason) => write!(f, "Invalid tag: {reason}"),
+            Self::NoPlayerData => write!(f, "The loaded save slot has no player data yet"),
+            Self::InvalidPlayerData { field, reason } => {
+                write!(f, "Invalid player data in {field}: {reason}")
+            }
+            Self::UnsupportedFormat(reason) => write!(f, "Unsupported save format: {reason}"),
+            Self::ThumbnailTooLarge { size, max } => {
+                write!(f, "Thumbnail is {size} bytes, the limit is {max}")
+            }
+            Self::AutosaveNotConfigured => write!(f, "Autosave has not been configured"),
+            Self::AutosaveNotFound(id, index) => {
+                write!(f, "Autosave {index} not found for slot {id}")
+            }
+            Self::CheckpointNotFound(id) => write!(f, "Checkpoint not found: {id}"),
+            Self::SlotInUse(id) => write!(f, "Save slot {id} is open; close it first"),
+            Self::DecryptionFailed => {
+                write!(
+                    f,
+                    "Save data could not be decrypted with the configured key"
+                )
+            }
+            Self::InvalidConfig(reason) => write!(f, "Invalid save configuration: {reason}"),
+            Self::ChecksumMismatch(id) => {
+                write!(f, "Save slot {id} failed its integrity check")
+            }
+            Self::SlotLocked(id) => write!(f, "Save slot {id} is locked by another connection"),
+            Self::SlotBusy(id) => write!(f, "Save slot {id} is being migrated"),
+            Self::DiskFull => write!(f, "Not enough disk space to save"),
+            Self::TransactionInProgress(id) => {
+                write!(f, "Save slot {id} has a transaction in progress")
+            }
+            Self::NoTransaction => write!(f, "No transaction is in progress"),
+            Self::NothingToUndo => write!(f, "No removed item to restore"),
+            Self::SchemaMismatch { missing } => write!(
+                f,
+                "Metadata database is missing save_slots columns: {}",
+                missing.join(", ")
+            ),
+            Self::RevisionConflict { current } => {
+                write!(
+                    f,
+                    "Save slot was changed elsewhere; it is now at revision {current}"
+                )
+            }
+            Self::Io(err) => write!(f, "IO error: {err}"),
+            Self::Database(err) => write!(f, "Database error: {err}"),
+            Self::Migration(message) => write!(f, "Migration error: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for SaveManagerError {}
+
+impl From<std::io::Error> for SaveManagerError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<rusqlite::Error> for SaveManagerError {
+    fn from(err: rusqlite::Error) -> Self {
+        Self::Database(err)
+    }
+}
+
+pub type SaveManagerResult<T> = Result<T, SaveManagerError>;
@@ -0,0 +1,44 @@
+//! Change notifications for slot lists and cards.
+//!
+//! Subscribers are plain callbacks so the save manager doesn't depend on the
+//! bridge; the API layer adapts them to whatever the UI listens on.
+//! Subscriptions outlive re-initialization, since they describe the UI rather
+//! than a particular save directory.
+
+use std::sync::{Mutex, PoisonError};
+
+use crate::api::saves::{SlotEvent, SlotEventKind};
+
+/// Returns `false` once it can no longer deliver events, e.g. because the
+/// listener went away, and is dropped.
+type Subscriber = Box<dyn FnMut(&SlotEvent) -> bool + Send>;
+
+static SUBSCRIBERS: Mutex<Vec<Subscriber>> = Mutex::new(Vec::new());
+
+pub fn subscribe(subscriber: impl FnMut(&SlotEvent) -> bool + Send + 'static) {
+    subscribers().push(Box::new(subscriber));
+}
+
+/// Tells every subscriber that `slot_id` changed. Call only after the change
+/// has been committed; warnings about a change still to come are the
+/// exception.
+pub(super) fn emit(slot_id: &str, kind: SlotEventKind) {
+    let event = SlotEvent {
+        slot_id: slot_id.to_owned(),
+        kind,
+    };
+    // Deliver without holding the lock, so a subscriber can subscribe again
+    // or trigger another change from its callback. Events emitted from
+    // inside a callback skip the subscribers still being delivered to.
+    let mut delivering = std::mem::take(&mut *subscribers());
+    delivering.retain_mut(|subscriber| subscriber(&event));
+    let mut current = subscribers();
+    let added = std::mem::replace(&mut *current, delivering);
+    current.extend(added);
+}
+
+// A subscriber that panicked mid-delivery leaves the list itself intact, so
+// poisoning is safe to ignore.
+fn subscribers() -> std::sync::MutexGuard<'static, Vec<Subscriber>> {
+    SUBSCRIBERS.lock().unwrap_or_else(PoisonError::into_inner)
+}
@@ -0,0 +1,141 @@
+//! Plain-text export and import of a slot's contents.
+//!
+//! Documents are built by SQLite's JSON functions so the crate doesn't need a
+//! serialization dependency. Every document carries `schema_version` so later
+//! formats can be told apart.
+
+use std::path::Path;
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+use super::{slot_file_path, SaveManager, SaveManagerError, SaveManagerResult};
+use crate::api::saves::{ImportPreview, PlayerData, SaveSlotMetadata};
+
+/// Version written into exported documents and the only one import accepts.
+const EXPORT_SCHEMA_VERSION: i64 = 1;
+
+impl SaveManager {
+    /// Serializes any slot's stats and ordered inventory to JSON. The slot is
+    /// opened read-only on its own connection, so the active slot is left
+    /// alone and sees no extra writes.
+    pub fn export_slot_to_json(&self, slot_id: &str) -> SaveManagerResult<String> {
+        let file_path = slot_file_path(&*self.metadata_connection()?, slot_id)?;
+        let conn = self.open_readonly(Path::new(&file_path))?;
+
+        conn.query_row(
+            "SELECT json_object(
+                'schema_version', ?1,
+                'health', health,
+                'experience', experience,
+                'inventory', (
+                    SELECT json_group_array(item ORDER BY position) FROM inventory
+                )
+            )
+            FROM player_stats WHERE id = 1",
+            [EXPORT_SCHEMA_VERSION],
+            |row| row.get(0),
+        )
+        .optional()?
+        .ok_or(SaveManagerError::NoPlayerData)
+    }
+
+    /// Creates a brand-new slot holding the contents of an exported document.
+    pub fn import_slot_from_json(
+        &mut self,
+        json: &str,
+        display_name: &str,
+    ) -> SaveManagerResult<SaveSlotMetadata> {
+        let data = parse_export(json)?;
+        self.create_slot_with_data(display_name, &data)
+    }
+
+    /// Checks an exported document the way `import_slot_from_json` would and
+    /// summarizes it, without creating a slot or touching disk.
+    pub fn inspect_import(&self, json: &str) -> SaveManagerResult<ImportPreview> {
+        let data = parse_export(json)?;
+        self.validate_player_data(&data)?;
+        Ok(ImportPreview {
+            schema_version: EXPORT_SCHEMA_VERSION,
+            health: data.health,
+            experience: data.experience,
+            item_count: data.inventory.len(),
+        })
+    }
+}
+
+/// Parses and validates an exported document without touching any slot.
+fn parse_export(json: &str) -> SaveManagerResult<PlayerData> {
+    let unsupported = |reason: &str| SaveManagerError::UnsupportedFormat(reason.to_owned());
+
+    let conn = Connection::open_in_memory()?;
+    let is_object: bool = conn.query_row(
+        "SELECT CASE WHEN json_valid(?1) THEN json_type(?1) = 'object' ELSE 0 END",
+        [json],
+        |row| row.get(0),
+    )?;
+    if !is_object {
+        return Err(unsupported("document is not a JSON object"));
+    }
+
+    let field = |path: &str| -> SaveManagerResult<Option<i64>> {
+        let (kind, value): (Option<String>, Option<i64>) = conn.query_row(
+            "SELECT json_type(?1, ?2), json_extract(?1, ?2)",
+            params![json, path],
+            |row| Ok((row.get(0)?, row.get(1).ok())),
+        )?;
+        Ok(value.filter(|_| kind.as_deref() == Some("integer")))
+    };
+
+    match field("$.schema_version")? {
+        Some(EXPORT_SCHEMA_VERSION) => {}
+        Some(version) => return Err(unsupported(&format!("unknown schema version {version}"))),
+        None => return Err(unsupported("schema_version must be an integer")),
+    }
+
+    let mut stats = [("health", 0), ("experience", 0)];
+    for (name, value) in &mut stats {
+        let parsed = field(&format!("$.{name}"))?
+            .ok_or_else(|| unsupported(&format!("{name} must be an integer")))?;
+        if parsed < 0 {
+            return Err(SaveManagerError::InvalidPlayerData {
+                field: name,
+                reason: "must not be negative".to_owned(),
+            });
+        }
+        *value = i32::try_from(parsed).map_err(|_| SaveManagerError::InvalidPlayerData {
+            field: name,
+            reason: "is out of range".to_owned(),
+        })?;
+    }
+
+    let inventory_kind: Option<String> =
+        conn.query_row("SELECT json_type(?1, '$.inventory')", [json], |row| {
+            row.get(0)
+        })?;
+    if inventory_kind.as_deref() != Some("array") {
+        return Err(unsupported("inventory must be an array"));
+    }
+    let mut stmt =
+        conn.prepare("SELECT type, value FROM json_each(?1, '$.inventory') ORDER BY key")?;
+    let items = stmt
+        .query_map([json], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, rusqlite::types::Value>(1)?,
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    let inventory = items
+        .into_iter()
+        .map(|(kind, value)| match value {
+            rusqlite::types::Value::Text(item) if kind == "text" => Ok(item),
+            _ => Err(unsupported("inventory items must be strings")),
+        })
+        .collect::<SaveManagerResult<Vec<_>>>()?;
+
+    Ok(PlayerData {
+        health: stats[0].1,
+        experience: stats[1].1,
+        inventory,
+    })
+}
@@ -0,0 +1,45 @@
+//! Free-form per-slot game state (quest flags, unlocked levels, ...) stored
+//! as text pairs, so new kinds of state don't need a schema change.
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+use super::{SaveManager, SaveManagerResult, SlotHandle};
+
+impl SaveManager {
+    /// Stores `value` under `key` in the slot, replacing any previous value.
+    pub fn set_flag(&self, handle: SlotHandle, key: &str, value: &str) -> SaveManagerResult<()> {
+        Ok(write_flag(self.connection(handle)?, key, value)?)
+    }
+
+    pub fn get_flag(&self, handle: SlotHandle, key: &str) -> SaveManagerResult<Option<String>> {
+        self.with_reader(handle, |conn| {
+            Ok(conn
+                .query_row(
+                    "SELECT value FROM game_flags WHERE key = ?1",
+                    params![key],
+                    |row| row.get(0),
+                )
+                .optional()?)
+        })
+    }
+
+    /// Every flag in the slot, sorted by key.
+    pub fn get_all_flags(&self, handle: SlotHandle) -> SaveManagerResult<Vec<(String, String)>> {
+        self.with_reader(handle, |conn| {
+            let mut stmt = conn.prepare("SELECT key, value FROM game_flags ORDER BY key")?;
+            let flags = stmt
+                .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(flags)
+        })
+    }
+}
+
+pub(super) fn write_flag(conn: &Connection, key: &str, value: &str) -> rusqlite::Result<()> {
+    conn.execute(
+        "INSERT INTO game_flags (key, value) VALUES (?1, ?2)
+         ON CONFLICT (key) DO UPDATE SET value = excluded.value",
+        params![key, value],
+    )?;
+    Ok(())
+}
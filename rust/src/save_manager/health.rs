@@ -0,0 +1,18 @@
+//! One snapshot of the store's state for a diagnostics panel.
+
+use super::SaveManager;
+use crate::api::saves::StoreHealth;
+
+impl SaveManager {
+    /// Everything a status panel shows, gathered without failing: anything
+    /// that can't be read is left as `None`.
+    pub fn store_health(&self) -> StoreHealth {
+        StoreHealth {
+            initialized: true,
+            active_slot_id: self.active_slot_id().map(str::to_owned),
+            saves_directory: Some(self.saves_dir.display().to_string()),
+            free_disk_bytes: self.disk_space.free_bytes(&self.saves_dir),
+            slot_count: self.slot_count().ok(),
+        }
+    }
+}
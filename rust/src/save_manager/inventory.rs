@@ -0,0 +1,171 @@
+//! Partial inventory and stats reads, and targeted inventory edits for
+//! callers that change one item at a time and don't want `save_player_data`
+//! to rewrite the whole list.
+//!
+//! Positions stay contiguous from 0: removing an item shifts the ones after
+//! it down by one. Each edit runs in its own transaction that also bumps the
+//! revision and refreshes the stored checksum, so the slot reads back exactly
+//! as if the full list had been saved.
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+use super::{
+    checkpoint, current_revision, read_inventory, read_player_data, read_player_stats, transaction,
+    SaveManager, SaveManagerError, SaveManagerResult, SlotHandle,
+};
+use crate::api::saves::SlotEventKind;
+
+impl SaveManager {
+    /// Just the inventory, in order, for screens that don't need the stats.
+    /// Partial reads can't be checked against the slot's checksum; use
+    /// `load_player_data` when that matters.
+    pub fn load_inventory(&self, handle: SlotHandle) -> SaveManagerResult<Vec<String>> {
+        self.with_reader(handle, |conn| {
+            read_player_stats(conn)?.ok_or(SaveManagerError::NoPlayerData)?;
+            Ok(read_inventory(conn)?)
+        })
+    }
+
+    /// Just health and experience. Like `load_inventory`, this skips the
+    /// checksum check.
+    pub fn load_player_stats(&self, handle: SlotHandle) -> SaveManagerResult<(i32, i32)> {
+        self.with_reader(handle, |conn| {
+            read_player_stats(conn)?.ok_or(SaveManagerError::NoPlayerData)
+        })
+    }
+
+    /// Appends `quantity` copies of `item` to the end of the inventory.
+    pub fn add_inventory_item(
+        &self,
+        handle: SlotHandle,
+        item: &str,
+        quantity: i32,
+    ) -> SaveManagerResult<()> {
+        self.edit_player_data(handle, |tx| self.append_items(tx, item, quantity))
+    }
+
+    /// Replaces the item at `position`.
+    pub fn set_inventory_item(
+        &self,
+        handle: SlotHandle,
+        position: i64,
+        item: &str,
+    ) -> SaveManagerResult<()> {
+        self.edit_player_data(handle, |tx| set_item(tx, position, item))
+    }
+
+    /// Runs `edit` in a transaction on the slot's writer, then bumps the
+    /// revision and recomputes the checksum before committing. Nothing `edit`
+    /// did is kept if it fails. Inside a manual transaction the edit joins it
+    /// and is committed, and reported, along with it.
+    pub(super) fn edit_player_data(
+        &self,
+        handle: SlotHandle,
+        edit: impl FnOnce(&Connection) -> SaveManagerResult<()>,
+    ) -> SaveManagerResult<()> {
+        let slot = self.slot_entry(handle)?;
+        if self.in_transaction(handle)? {
+            transaction::in_savepoint(&slot.conn, |conn| self.apply_edit(conn, edit))?;
+            return Ok(());
+        }
+        let tx = slot.conn.unchecked_transaction()?;
+        let revision = self.apply_edit(&tx, edit)?;
+        tx.commit()?;
+        checkpoint(&slot.conn)?;
+        self.record_change(&slot.slot_id, SlotEventKind::Saved, Some(revision))
+    }
+
+    /// Runs `edit` on `conn`, which is already inside a transaction, then
+    /// bumps the revision and refreshes the checksum. Returns the new
+    /// revision.
+    fn apply_edit(
+        &self,
+        conn: &Connection,
+        edit: impl FnOnce(&Connection) -> SaveManagerResult<()>,
+    ) -> SaveManagerResult<i64> {
+        let saved = conn
+            .query_row("SELECT 1 FROM player_stats WHERE id = 1", [], |_| Ok(()))
+            .optional()?;
+        if saved.is_none() {
+            return Err(SaveManagerError::NoPlayerData);
+        }
+
+        edit(conn)?;
+
+        let checksum = self.stored_checksum_for(conn)?;
+        conn.execute(
+            "UPDATE player_stats
+             SET revision = revision + 1, save_checksum = ?1, app_save_version = ?2
+             WHERE id = 1",
+            params![checksum, self.app_save_version],
+        )?;
+        Ok(current_revision(conn)?)
+    }
+
+    /// The checksum to store for the player data `tx` now holds, after an
+    /// edit made in place.
+    pub(super) fn stored_checksum_for(&self, tx: &Connection) -> SaveManagerResult<Option<String>> {
+        if !self.integrity_check {
+            return Ok(None);
+        }
+        Ok(read_player_data(tx)?.and_then(|data| self.checksum_for(&data)))
+    }
+
+    /// Appends `quantity` copies of `item` on `tx`, within the inventory
+    /// limit.
+    pub(super) fn append_items(
+        &self,
+        tx: &Connection,
+        item: &str,
+        quantity: i32,
+    ) -> SaveManagerResult<()> {
+        if quantity < 1 {
+            return Err(SaveManagerError::InvalidPlayerData {
+                field: "quantity",
+                reason: "must be at least 1".to_owned(),
+            });
+        }
+        let len: i64 = tx.query_row("SELECT count(*) FROM inventory", [], |row| row.get(0))?;
+        self.validate_inventory_len(len as usize + quantity as usize)?;
+        let mut stmt = tx.prepare("INSERT INTO inventory (position, item) VALUES (?1, ?2)")?;
+        for position in len..len + i64::from(quantity) {
+            stmt.execute(params![position, item])?;
+        }
+        Ok(())
+    }
+}
+
+pub(super) fn remove_item(tx: &Connection, position: i64) -> SaveManagerResult<()> {
+    if tx.execute("DELETE FROM inventory WHERE position = ?1", [position])? == 0 {
+        return Err(missing_position(position));
+    }
+    // Shifting in place could collide with a not-yet-moved row, so park the
+    // moved rows on negative positions first.
+    tx.execute(
+        "UPDATE inventory SET position = -(position - 1) WHERE position > ?1",
+        [position],
+    )?;
+    tx.execute(
+        "UPDATE inventory SET position = -position WHERE position < 0",
+        [],
+    )?;
+    Ok(())
+}
+
+fn set_item(tx: &Connection, position: i64, item: &str) -> SaveManagerResult<()> {
+    let rows_affected = tx.execute(
+        "UPDATE inventory SET item = ?2 WHERE position = ?1",
+        params![position, item],
+    )?;
+    if rows_affected == 0 {
+        return Err(missing_position(position));
+    }
+    Ok(())
+}
+
+pub(super) fn missing_position(position: i64) -> SaveManagerError {
+    SaveManagerError::InvalidPlayerData {
+        field: "position",
+        reason: format!("no inventory item at position {position}"),
+    }
+}
@@ -0,0 +1,110 @@
+//! Undo for items removed by mistake, e.g. a misplaced "discard".
+//!
+//! Each open slot remembers its last `UNDO_BUFFER_SIZE` removals in memory
+//! only: the buffer is gone once the slot is unloaded or the app restarts,
+//! and a rolled-back manual transaction empties it too.
+
+use std::collections::VecDeque;
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+use super::{inventory, SaveManager, SaveManagerError, SaveManagerResult, SlotHandle};
+
+/// How many removals each open slot can undo.
+const UNDO_BUFFER_SIZE: usize = 20;
+
+/// An item taken out by `remove_inventory_item`, and where it was.
+pub(super) struct RemovedItem {
+    position: i64,
+    item: String,
+}
+
+/// The most recent removals on one open slot, oldest first.
+#[derive(Default)]
+pub(super) struct UndoBuffer(VecDeque<RemovedItem>);
+
+impl UndoBuffer {
+    fn push(&mut self, removed: RemovedItem) {
+        if self.0.len() == UNDO_BUFFER_SIZE {
+            self.0.pop_front();
+        }
+        self.0.push_back(removed);
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+impl SaveManager {
+    /// Removes the item at `position` and moves the ones after it up. The
+    /// removal can be taken back with `undo_last_inventory_change`.
+    pub fn remove_inventory_item(
+        &self,
+        handle: SlotHandle,
+        position: i64,
+    ) -> SaveManagerResult<()> {
+        let mut removed = None;
+        self.edit_player_data(handle, |tx| {
+            removed = Some(RemovedItem {
+                position,
+                item: item_at(tx, position)?,
+            });
+            inventory::remove_item(tx, position)
+        })?;
+        if let Some(removed) = removed {
+            self.slot_entry(handle)?.undo.borrow_mut().push(removed);
+        }
+        Ok(())
+    }
+
+    /// Puts the most recently removed item back where it was, moving the
+    /// items from there on down. If the inventory has since shrunk below
+    /// that position, it goes at the end. Fails with `NothingToUndo` once
+    /// the buffer is empty.
+    pub fn undo_last_inventory_change(&self, handle: SlotHandle) -> SaveManagerResult<()> {
+        let slot = self.slot_entry(handle)?;
+        let removed = slot
+            .undo
+            .borrow_mut()
+            .0
+            .pop_back()
+            .ok_or(SaveManagerError::NothingToUndo)?;
+        let restored = self.edit_player_data(handle, |tx| {
+            let len: i64 = tx.query_row("SELECT count(*) FROM inventory", [], |row| row.get(0))?;
+            self.validate_inventory_len(len as usize + 1)?;
+            insert_item(tx, removed.position.min(len), &removed.item)
+        });
+        if restored.is_err() {
+            slot.undo.borrow_mut().0.push_back(removed);
+        }
+        restored
+    }
+}
+
+fn item_at(tx: &Connection, position: i64) -> SaveManagerResult<String> {
+    tx.query_row(
+        "SELECT item FROM inventory WHERE position = ?1",
+        [position],
+        |row| row.get(0),
+    )
+    .optional()?
+    .ok_or_else(|| inventory::missing_position(position))
+}
+
+fn insert_item(tx: &Connection, position: i64, item: &str) -> SaveManagerResult<()> {
+    // Parked on negative positions first, as in `remove_item`.
+    tx.execute(
+        "UPDATE inventory SET position = -(position + 1) WHERE position >= ?1",
+        [position],
+    )?;
+    tx.execute(
+        "UPDATE inventory SET position = -position WHERE position < 0",
+        [],
+    )?;
+    tx.execute(
+        "INSERT INTO inventory (position, item) VALUES (?1, ?2)",
+        params![position, item],
+    )?;
+    Ok(())
+}
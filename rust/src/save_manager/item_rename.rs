@@ -0,0 +1,94 @@
+//! Renaming an inventory item in every save at once, for content updates
+//! that rename an item.
+
+use std::path::Path;
+
+use rusqlite::{params, Connection};
+
+use super::{checkpoint, current_revision, SaveManager, SaveManagerError, SaveManagerResult};
+use crate::api::saves::{ItemRenameResult, SkippedSlot, SlotEventKind};
+
+impl SaveManager {
+    /// Replaces every `old` item with `new` in every slot, trashed ones
+    /// included so a restored slot isn't left behind. A slot that is open
+    /// is updated through its own connection so it stays consistent;
+    /// the rest are opened, and migrated if needed, one at a time.
+    ///
+    /// Each slot is updated in its own transaction, with its revision bumped
+    /// and checksum refreshed as for any other edit, but its app save
+    /// version left alone. A slot that fails is listed in `skipped` and the
+    /// others go ahead.
+    pub fn rename_item_everywhere(
+        &self,
+        old: &str,
+        new: &str,
+    ) -> SaveManagerResult<ItemRenameResult> {
+        let slots = {
+            let conn = self.metadata_connection()?;
+            let mut stmt = conn.prepare("SELECT id, file_path FROM save_slots ORDER BY id")?;
+            let rows = stmt
+                .query_map([], |row| {
+                    Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+            rows
+        };
+
+        let mut result = ItemRenameResult {
+            rows_changed: 0,
+            skipped: Vec::new(),
+        };
+        for (slot_id, file_path) in slots {
+            let file_path = Path::new(&file_path);
+            let renamed = match self.handles_for_path(file_path).first() {
+                Some(handle) => self.rename_item_in(&self.open_slots[handle].conn, old, new),
+                // Opening a missing file would create an empty slot.
+                None if !file_path.is_file() => {
+                    Err(SaveManagerError::SlotNotFound(slot_id.clone()))
+                }
+                None => self
+                    .open_migrated_slot(&slot_id, file_path)
+                    .and_then(|conn| self.rename_item_in(&conn, old, new)),
+            };
+            match renamed {
+                Ok(None) => {}
+                Ok(Some((rows, revision))) => {
+                    result.rows_changed += rows;
+                    self.record_change(&slot_id, SlotEventKind::Saved, Some(revision))?;
+                }
+                Err(err) => result.skipped.push(SkippedSlot {
+                    slot_id,
+                    error: err.to_string(),
+                }),
+            }
+        }
+        Ok(result)
+    }
+
+    /// Renames the item on `conn` and returns how many rows changed along
+    /// with the new revision, or `None` if the slot holds no `old` items.
+    fn rename_item_in(
+        &self,
+        conn: &Connection,
+        old: &str,
+        new: &str,
+    ) -> SaveManagerResult<Option<(u64, i64)>> {
+        let tx = conn.unchecked_transaction()?;
+        let rows = tx.execute(
+            "UPDATE inventory SET item = ?2 WHERE item = ?1",
+            params![old, new],
+        )?;
+        if rows == 0 {
+            return Ok(None);
+        }
+        let checksum = self.stored_checksum_for(&tx)?;
+        tx.execute(
+            "UPDATE player_stats SET revision = revision + 1, save_checksum = ?1 WHERE id = 1",
+            params![checksum],
+        )?;
+        let revision = current_revision(&tx)?;
+        tx.commit()?;
+        checkpoint(conn)?;
+        Ok(Some((rows as u64, revision)))
+    }
+}
@@ -0,0 +1,118 @@
+//! An append-only log of slot changes in the metadata database, so a sync
+//! layer can upload only what changed since its last run instead of diffing
+//! every slot.
+//!
+//! Every change reported to event subscribers is journaled before the event
+//! goes out, stamped in Unix epoch milliseconds. Entries are never rewritten;
+//! `prune_change_log` is the only thing that removes them.
+
+use rusqlite::{params, Connection};
+
+use super::{events, SaveManager, SaveManagerError, SaveManagerResult};
+use crate::api::saves::{ChangeEntry, SlotEventKind};
+
+impl SaveManager {
+    /// Entries recorded after `timestamp_ms`, oldest first.
+    pub fn changes_since(&self, timestamp_ms: i64) -> SaveManagerResult<Vec<ChangeEntry>> {
+        let conn = self.metadata_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, timestamp_ms, slot_id, operation, revision FROM change_log
+             WHERE timestamp_ms > ?1
+             ORDER BY id",
+        )?;
+        let rows = stmt
+            .query_map([timestamp_ms], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, Option<i64>>(4)?,
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        rows.into_iter()
+            .map(|(sequence, timestamp_ms, slot_id, operation, revision)| {
+                Ok(ChangeEntry {
+                    sequence,
+                    timestamp_ms,
+                    slot_id,
+                    operation: parse_operation(&operation)?,
+                    revision,
+                })
+            })
+            .collect()
+    }
+
+    /// Deletes entries recorded before `timestamp_ms` and returns how many
+    /// were removed.
+    pub fn prune_change_log(&self, timestamp_ms: i64) -> SaveManagerResult<usize> {
+        Ok(self.metadata_connection()?.execute(
+            "DELETE FROM change_log WHERE timestamp_ms < ?1",
+            [timestamp_ms],
+        )?)
+    }
+
+    /// Journals a committed change, then tells event subscribers about it.
+    /// `revision` is the slot's player-data revision after a save, and `None`
+    /// for changes that don't touch player data.
+    pub(super) fn record_change(
+        &self,
+        slot_id: &str,
+        kind: SlotEventKind,
+        revision: Option<i64>,
+    ) -> SaveManagerResult<()> {
+        let (_, now_ms) = self.now_timestamp();
+        append(
+            &*self.metadata_connection()?,
+            now_ms,
+            slot_id,
+            kind,
+            revision,
+        )?;
+        events::emit(slot_id, kind);
+        Ok(())
+    }
+}
+
+/// Adds one entry on `conn`, e.g. inside the metadata transaction that made
+/// the change. Callers emit the matching event once that has committed.
+pub(super) fn append(
+    conn: &Connection,
+    timestamp_ms: i64,
+    slot_id: &str,
+    kind: SlotEventKind,
+    revision: Option<i64>,
+) -> rusqlite::Result<()> {
+    conn.execute(
+        "INSERT INTO change_log (timestamp_ms, slot_id, operation, revision)
+         VALUES (?1, ?2, ?3, ?4)",
+        params![timestamp_ms, slot_id, operation_name(kind), revision],
+    )?;
+    Ok(())
+}
+
+fn operation_name(kind: SlotEventKind) -> &'static str {
+    match kind {
+        SlotEventKind::Created => "created",
+        SlotEventKind::Renamed => "renamed",
+        SlotEventKind::Deleted => "deleted",
+        SlotEventKind::Saved => "saved",
+        SlotEventKind::Restored => "restored",
+        SlotEventKind::LowDiskSpace => "low_disk_space",
+    }
+}
+
+fn parse_operation(name: &str) -> SaveManagerResult<SlotEventKind> {
+    match name {
+        "created" => Ok(SlotEventKind::Created),
+        "renamed" => Ok(SlotEventKind::Renamed),
+        "deleted" => Ok(SlotEventKind::Deleted),
+        "saved" => Ok(SlotEventKind::Saved),
+        "restored" => Ok(SlotEventKind::Restored),
+        "low_disk_space" => Ok(SlotEventKind::LowDiskSpace),
+        _ => Err(SaveManagerError::UnsupportedFormat(format!(
+            "unknown change log operation {name:?}"
+        ))),
+    }
+}
@@ -0,0 +1,118 @@
+//! Recovery from WAL locks left behind by a process that crashed while it had
+//! a slot open.
+//!
+//! SQLite normally cleans up a dead process's `-wal` and `-shm` files on the
+//! next open, but a damaged `-shm` can keep every connection waiting until it
+//! gives up with `SQLITE_BUSY`.
+//!
+//! Saves also retry a few times when another connection outlasts the busy
+//! timeout, e.g. an autosave racing a manual save.
+
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use rusqlite::{Connection, OpenFlags};
+
+use super::{
+    checkpoint, remove_file_if_exists, slot_file_path, slot_files, unlock, SaveManager,
+    SaveManagerError, SaveManagerResult,
+};
+
+/// How many times a save is retried after `SQLITE_BUSY` by default, for
+/// three attempts in all.
+pub(super) const DEFAULT_BUSY_RETRIES: u32 = 2;
+
+/// Wait before the first retry; every further retry waits twice as long.
+const BUSY_RETRY_BASE_DELAY: Duration = Duration::from_millis(50);
+
+impl SaveManager {
+    /// Sets how many times a save is retried when the slot stays locked past
+    /// the busy timeout (default 2), waiting 50 ms before the first retry and
+    /// doubling that each time. 0 fails on the first busy error. Resets when
+    /// the save system is re-initialized.
+    pub fn set_busy_retries(&mut self, retries: u32) {
+        self.busy_retries = retries;
+    }
+
+    /// Clears stale WAL state on a slot that isn't open here. The slot is
+    /// switched to rollback journaling, which needs every lock and folds the
+    /// WAL into the main file, then back to WAL.
+    ///
+    /// If the switch can't get the locks while there is a `-shm` file but no
+    /// `-wal`, the `-shm` is a leftover (SQLite removes both together) and is
+    /// deleted before trying once more. Otherwise another process is most
+    /// likely using the slot, and this fails with `SlotLocked`.
+    pub fn recover_slot_locks(&self, slot_id: &str) -> SaveManagerResult<()> {
+        let file_path = PathBuf::from(slot_file_path(&*self.metadata_connection()?, slot_id)?);
+        if !self.handles_for_path(&file_path).is_empty() {
+            return Err(SaveManagerError::SlotInUse(slot_id.to_owned()));
+        }
+
+        let [_, wal, shm] = slot_files(&file_path);
+        match self.reset_journal(&file_path) {
+            Err(SaveManagerError::Database(ref err))
+                if is_busy(err) && shm.exists() && !wal.exists() =>
+            {
+                remove_file_if_exists(&shm)?;
+                self.reset_journal(&file_path)
+            }
+            result => result,
+        }
+        .map_err(|err| locked_error(slot_id, err))
+    }
+
+    fn reset_journal(&self, path: &Path) -> SaveManagerResult<()> {
+        // Without CREATE, a missing file is an error instead of a new empty
+        // slot.
+        let conn = Connection::open_with_flags(
+            path,
+            OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )?;
+        unlock(&conn, self.config.encryption_key.as_deref())?;
+        self.sqlite_options.apply_busy_timeout(&conn)?;
+        conn.pragma_update(None, "journal_mode", "DELETE")?;
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        Ok(checkpoint(&conn)?)
+    }
+}
+
+/// Whether SQLite gave up waiting for another connection's lock.
+pub(super) fn is_busy(err: &rusqlite::Error) -> bool {
+    matches!(
+        err.sqlite_error_code(),
+        Some(rusqlite::ErrorCode::DatabaseBusy | rusqlite::ErrorCode::DatabaseLocked)
+    )
+}
+
+/// Runs `write`, running it again up to `retries` times with exponential
+/// backoff while it fails with `SQLITE_BUSY` or `SQLITE_LOCKED`. Any other
+/// result, or the last busy error, is returned as is. `write` must leave
+/// nothing behind when it fails, e.g. by rolling back its transaction.
+pub(super) fn retry_on_busy<T>(
+    retries: u32,
+    mut write: impl FnMut() -> SaveManagerResult<T>,
+) -> SaveManagerResult<T> {
+    let mut delay = BUSY_RETRY_BASE_DELAY;
+    for _ in 0..retries {
+        match write() {
+            Err(SaveManagerError::Database(ref err)) if is_busy(err) => {
+                thread::sleep(delay);
+                delay *= 2;
+            }
+            result => return result,
+        }
+    }
+    write()
+}
+
+/// Reports a lock that outlasted the busy timeout as `SlotLocked`, so the UI
+/// can offer `recover_slot_locks`.
+pub(super) fn locked_error(slot_id: &str, err: SaveManagerError) -> SaveManagerError {
+    match err {
+        SaveManagerError::Database(ref db_err) if is_busy(db_err) => {
+            SaveManagerError::SlotLocked(slot_id.to_owned())
+        }
+        err => err,
+    }
+}
@@ -0,0 +1,102 @@
+//! Storage clean-up across every slot, for a "clean up storage" button.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use rusqlite::Connection;
+
+use super::{checkpoint, slot_files_size, vacuum, SaveManager, SaveManagerResult};
+use crate::api::saves::{MaintenanceReport, SlotMaintenance};
+
+impl SaveManager {
+    /// Checkpoints and optimizes every slot, and vacuums closed slots whose
+    /// files take more than `vacuum_above_bytes` (never, if `None`). Open
+    /// slots only get the non-destructive steps, on their existing
+    /// connection. A slot that fails, e.g. because it is corrupt, gets its
+    /// error in the report and the run moves on.
+    pub fn run_maintenance(
+        &self,
+        vacuum_above_bytes: Option<u64>,
+    ) -> SaveManagerResult<MaintenanceReport> {
+        let slots = {
+            let conn = self.metadata_connection()?;
+            let mut stmt = conn.prepare(
+                "SELECT id, file_path FROM save_slots WHERE deleted_at IS NULL ORDER BY id",
+            )?;
+            let rows = stmt
+                .query_map([], |row| {
+                    Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+            rows
+        };
+
+        let mut report = MaintenanceReport {
+            slots: Vec::with_capacity(slots.len()),
+            bytes_reclaimed: 0,
+        };
+        for (slot_id, file_path) in slots {
+            let file_path = PathBuf::from(file_path);
+            let outcome = self.maintain_slot(&file_path, vacuum_above_bytes);
+            let slot = match outcome {
+                Ok((bytes_reclaimed, vacuumed)) => SlotMaintenance {
+                    slot_id,
+                    bytes_reclaimed,
+                    vacuumed,
+                    error: None,
+                },
+                Err(err) => SlotMaintenance {
+                    slot_id,
+                    bytes_reclaimed: 0,
+                    vacuumed: false,
+                    error: Some(err.to_string()),
+                },
+            };
+            report.bytes_reclaimed += slot.bytes_reclaimed;
+            report.slots.push(slot);
+        }
+        Ok(report)
+    }
+
+    /// Returns the bytes reclaimed and whether the slot was vacuumed.
+    fn maintain_slot(
+        &self,
+        file_path: &Path,
+        vacuum_above_bytes: Option<u64>,
+    ) -> SaveManagerResult<(u64, bool)> {
+        if !file_path.exists() {
+            // Opening it would quietly create an empty database.
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{} is missing", file_path.display()),
+            )
+            .into());
+        }
+        let before = slot_files_size(file_path)?;
+        let vacuumed = match self.handles_for_path(file_path).first() {
+            Some(handle) => {
+                optimize(&self.open_slots[handle].conn)?;
+                false
+            }
+            None => {
+                let conn = self.open_connection(file_path)?;
+                optimize(&conn)?;
+                let vacuum_now = vacuum_above_bytes.is_some_and(|limit| before > limit);
+                if vacuum_now {
+                    vacuum(&conn)?;
+                }
+                vacuum_now
+            }
+        };
+        let after = slot_files_size(file_path)?;
+        Ok((before.saturating_sub(after), vacuumed))
+    }
+}
+
+/// Folds the WAL into the database and refreshes the query planner's
+/// statistics. Safe while the slot is in use.
+fn optimize(conn: &Connection) -> SaveManagerResult<()> {
+    checkpoint(conn)?;
+    conn.execute_batch("PRAGMA optimize")?;
+    Ok(())
+}
@@ -0,0 +1,76 @@
+//! Combining one slot's inventory into another's, e.g. for co-op or a
+//! friend's gift.
+
+use std::path::Path;
+
+use super::{
+    checkpoint, current_revision, read_player_data, slot_file_path, write_player_data, SaveManager,
+    SaveManagerError, SaveManagerResult,
+};
+use crate::api::saves::{MergeStrategy, PlayerData, SlotEventKind};
+
+impl SaveManager {
+    /// Adds `from`'s items to `into`'s inventory as `strategy` says and
+    /// returns how many items `into` holds afterwards. `from` is only read;
+    /// `into` must have been saved before, and keeps its stats. The merged
+    /// inventory is renumbered from 0 and written in one transaction, subject
+    /// to the usual inventory limit.
+    pub fn merge_inventories(
+        &self,
+        into: &str,
+        from: &str,
+        strategy: MergeStrategy,
+    ) -> SaveManagerResult<usize> {
+        let incoming = self
+            .read_slot_data(from)?
+            .map(|data| data.inventory)
+            .unwrap_or_default();
+        let file_path = slot_file_path(&*self.metadata_connection()?, into)?;
+        // A slot that is open keeps its own connection; WAL lets this one
+        // write alongside it, and its next read sees the merge.
+        let mut conn = self.open_migrated_slot(into, Path::new(&file_path))?;
+        let revision = current_revision(&conn)?;
+        let current = read_player_data(&conn)?.ok_or(SaveManagerError::NoPlayerData)?;
+
+        let merged = PlayerData {
+            inventory: merge(current.inventory, incoming, strategy),
+            ..current
+        };
+        self.validate_player_data(&merged)?;
+        let checksum = self.checksum_for(&merged);
+        // Fails rather than overwriting if the slot was saved since it was
+        // read above.
+        let result = write_player_data(
+            &mut conn,
+            &merged,
+            checksum.as_deref(),
+            self.app_save_version,
+            Some(revision),
+        )?;
+        checkpoint(&conn)?;
+        self.record_change(into, SlotEventKind::Saved, Some(result.revision))?;
+        Ok(merged.inventory.len())
+    }
+}
+
+fn merge(mut items: Vec<String>, incoming: Vec<String>, strategy: MergeStrategy) -> Vec<String> {
+    match strategy {
+        MergeStrategy::Append => items.extend(incoming),
+        MergeStrategy::DedupeByItem => {
+            for item in incoming {
+                if !items.contains(&item) {
+                    items.push(item);
+                }
+            }
+        }
+        MergeStrategy::SumQuantities => {
+            for item in incoming {
+                match items.iter().rposition(|existing| *existing == item) {
+                    Some(last) => items.insert(last + 1, item),
+                    None => items.push(item),
+                }
+            }
+        }
+    }
+    items
+}
@@ -0,0 +1,121 @@
+//! The metadata database connection, kept open between calls.
+//!
+//! Opening a connection means opening the file, switching it to WAL and
+//! applying the pragmas again, which costs more than most of the metadata
+//! queries themselves. The manager lives on the save worker, so one cached
+//! connection is never used from two threads.
+//!
+//! Tests can keep the metadata database in memory instead, so they run
+//! faster and leave no `metadata.db` behind; slot files still go to disk.
+
+use std::cell::RefCell;
+#[cfg(test)]
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use rusqlite::Connection;
+
+use super::{SaveManager, SaveManagerResult};
+
+#[derive(Default)]
+pub(super) struct MetadataConnectionCache {
+    conn: RefCell<Option<Rc<Connection>>>,
+    /// Holds an in-memory metadata database open. SQLite frees a shared
+    /// in-memory database when its last connection closes, which the cached
+    /// connection alone doesn't prevent: it is replaced and discarded.
+    memory_anchor: Option<Connection>,
+}
+
+impl MetadataConnectionCache {
+    /// Closes the cached connection once its current users are done, e.g. so
+    /// new SQLite options take effect.
+    pub(super) fn discard(&self) {
+        self.conn.borrow_mut().take();
+    }
+}
+
+impl SaveManager {
+    /// Keeps one metadata connection open and reuses it (the default), or
+    /// opens a new one for every call. Turning it off closes the cached
+    /// connection.
+    ///
+    /// `metadata_connection_benchmark` in the tests lists 5,000 slots both
+    /// ways and prints the mean `get_all_slots` time of each; run it with
+    /// `cargo test --release -- --ignored --nocapture metadata_connection_benchmark`.
+    /// The difference it reports is the cost of opening and configuring a
+    /// connection, paid once per call without the cache.
+    pub fn set_keep_metadata_connection(&mut self, enabled: bool) {
+        self.keep_metadata_connection = enabled;
+        self.metadata_cache.discard();
+    }
+
+    /// Closes the cached metadata connection once its current users are
+    /// done. Called after every failed API call, so a connection an error
+    /// left broken is never reused; the next call opens a fresh one.
+    pub(crate) fn discard_metadata_connection(&self) {
+        self.metadata_cache.discard();
+    }
+
+    /// The cached metadata connection, opened on first use, or a fresh one
+    /// when caching is off.
+    pub(super) fn metadata_connection(&self) -> SaveManagerResult<Rc<Connection>> {
+        if !self.keep_metadata_connection {
+            return Ok(Rc::new(self.open_connection(&self.metadata_db_path)?));
+        }
+
+        let mut cached = self.metadata_cache.conn.borrow_mut();
+        // Only replace the connection while nobody else holds it. A
+        // transaction still open at that point was left behind by a call
+        // that failed half-way, and a vanished file means app storage was
+        // cleared; either way a fresh connection behaves as the uncached
+        // path would.
+        let stale = cached.as_ref().is_some_and(|conn| {
+            Rc::strong_count(conn) == 1 && (!conn.is_autocommit() || self.metadata_db_vanished())
+        });
+        if stale {
+            *cached = None;
+        }
+        match cached.as_ref() {
+            Some(conn) => Ok(Rc::clone(conn)),
+            None => {
+                let conn = Rc::new(self.open_connection(&self.metadata_db_path)?);
+                *cached = Some(Rc::clone(&conn));
+                Ok(conn)
+            }
+        }
+    }
+
+    fn metadata_db_vanished(&self) -> bool {
+        self.metadata_cache.memory_anchor.is_none() && !self.metadata_db_path.exists()
+    }
+}
+
+#[cfg(test)]
+impl SaveManager {
+    /// A manager with the default config whose slots live under
+    /// `base_path` but whose metadata database is in memory, private to this
+    /// manager and gone once it is dropped.
+    pub(super) fn initialize_in_memory(base_path: &Path) -> SaveManagerResult<Self> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static NEXT_DB: AtomicUsize = AtomicUsize::new(0);
+
+        let mut manager = Self::unprepared(
+            base_path,
+            super::SaveConfig::default(),
+            Box::new(super::sources::SystemClock),
+            Box::new(super::sources::RandomIds),
+        )?;
+        // Every connection to this URI shares one database, unlike
+        // `:memory:`, which would give each connection its own empty one.
+        manager.metadata_db_path = PathBuf::from(format!(
+            "file:save_manager_metadata_{}_{}?mode=memory&cache=shared",
+            std::process::id(),
+            NEXT_DB.fetch_add(1, Ordering::Relaxed)
+        ));
+        manager.metadata_cache.memory_anchor =
+            Some(manager.open_connection(&manager.metadata_db_path)?);
+        manager.initialize_metadata_db()?;
+        Ok(manager)
+    }
+}
@@ -0,0 +1,120 @@
+//! Safety copies taken before a slot's schema is upgraded.
+//!
+//! Migrations already run in one transaction, but a crash or a full disk in
+//! the middle of one is exactly when a player can least afford to lose a
+//! save. The copy is named `<slot_id>.pre-migration.db` and only outlives the
+//! upgrade if something went wrong.
+
+use std::cell::RefCell;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rusqlite::Connection;
+
+use super::{
+    checkpoint, format_timestamp, migrations, move_slot_files, remove_slot_files, run_migrations,
+    SaveManager, SaveManagerError, SaveManagerResult,
+};
+use crate::api::saves::MigrationBackupInfo;
+
+const BACKUP_SUFFIX: &str = ".pre-migration.db";
+
+impl SaveManager {
+    /// Opens a slot's database and brings its schema up to date. If that
+    /// means running migrations, the file is backed up first and put back if
+    /// they fail.
+    pub(super) fn open_migrated_slot(
+        &self,
+        slot_id: &str,
+        path: &Path,
+    ) -> SaveManagerResult<Connection> {
+        let mut conn = self.open_connection(path)?;
+        if migrations::current_version(&conn)? >= self.slot_migrations.latest_version() {
+            return Ok(conn);
+        }
+
+        let backup = self.migration_backup_path(slot_id);
+        checkpoint(&conn)?;
+        fs::copy(path, &backup)?;
+
+        let migrated = {
+            let _migrating = MigratingGuard::new(&self.migrating, slot_id);
+            run_migrations(&self.slot_migrations, &mut conn)
+        };
+        match migrated {
+            Ok(()) => {
+                // A leftover copy is harmless and shows up in
+                // `list_migration_backups`, so don't fail the load over it.
+                let _ = remove_slot_files(&backup);
+                Ok(conn)
+            }
+            Err(err) => {
+                drop(conn);
+                // If the restore fails too, the backup stays where support
+                // tooling can find it.
+                let _ = remove_slot_files(path).and_then(|()| move_slot_files(&backup, path));
+                Err(err)
+            }
+        }
+    }
+
+    /// Fails with `SlotBusy` while `slot_id`'s schema is being migrated, so
+    /// a save can't interleave with the upgrade of the file it writes to.
+    pub(super) fn ensure_not_migrating(&self, slot_id: &str) -> SaveManagerResult<()> {
+        if self.migrating.borrow().as_deref() == Some(slot_id) {
+            return Err(SaveManagerError::SlotBusy(slot_id.to_owned()));
+        }
+        Ok(())
+    }
+
+    /// Lists backups left behind by upgrades that failed or were interrupted.
+    pub fn list_migration_backups(&self) -> SaveManagerResult<Vec<MigrationBackupInfo>> {
+        let mut backups = Vec::new();
+        for entry in fs::read_dir(&self.saves_dir)? {
+            let path = entry?.path();
+            let Some(slot_id) = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_suffix(BACKUP_SUFFIX))
+            else {
+                continue;
+            };
+            let file = fs::metadata(&path)?;
+            backups.push(MigrationBackupInfo {
+                slot_id: slot_id.to_owned(),
+                saved_at: format_timestamp(file.modified()?),
+                size_bytes: file.len(),
+                file_path: path.to_string_lossy().into_owned(),
+            });
+        }
+        backups.sort_by(|a, b| a.slot_id.cmp(&b.slot_id));
+        Ok(backups)
+    }
+
+    pub(super) fn remove_migration_backup(&self, slot_id: &str) -> SaveManagerResult<()> {
+        Ok(remove_slot_files(&self.migration_backup_path(slot_id))?)
+    }
+
+    fn migration_backup_path(&self, slot_id: &str) -> PathBuf {
+        self.saves_dir.join(format!("{slot_id}{BACKUP_SUFFIX}"))
+    }
+}
+
+/// Marks a slot as migrating until dropped, so the mark is cleared however
+/// the migration ends, panics included.
+struct MigratingGuard<'a> {
+    migrating: &'a RefCell<Option<String>>,
+}
+
+impl<'a> MigratingGuard<'a> {
+    fn new(migrating: &'a RefCell<Option<String>>, slot_id: &str) -> Self {
+        *migrating.borrow_mut() = Some(slot_id.to_owned());
+        Self { migrating }
+    }
+}
+
+impl Drop for MigratingGuard<'_> {
+    fn drop(&mut self) {
+        self.migrating.borrow_mut().take();
+    }
+}
@@ -0,0 +1,142 @@
+//! Minimal schema migrations keyed on `PRAGMA user_version`.
+//!
+//! Mirrors the small subset of the `rusqlite_migration` API the save system
+//! needs: an ordered list of `M::up` steps, optionally reversible with
+//! `.down(...)`, applied inside one transaction.
+
+use std::borrow::Cow;
+use std::fmt;
+
+use rusqlite::Connection;
+
+/// A single migration step.
+pub(crate) struct M {
+    up: Cow<'static, str>,
+    down: Option<Cow<'static, str>>,
+}
+
+impl M {
+    pub(crate) const fn up(sql: &'static str) -> Self {
+        Self {
+            up: Cow::Borrowed(sql),
+            down: None,
+        }
+    }
+
+    /// SQL that undoes this step, making it possible to migrate below it.
+    pub(crate) fn down(mut self, sql: &'static str) -> Self {
+        self.down = Some(Cow::Borrowed(sql));
+        self
+    }
+
+    /// A step built at runtime, e.g. from SQL the app supplies.
+    pub(crate) fn owned(up: String, down: Option<String>) -> Self {
+        Self {
+            up: Cow::Owned(up),
+            down: down.map(Cow::Owned),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum Error {
+    Database(rusqlite::Error),
+    /// Step `version` has no `down` SQL, so the database can't go below it.
+    Irreversible {
+        version: usize,
+    },
+    /// The requested version is past the last step.
+    UnknownVersion {
+        target: usize,
+        latest: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Database(err) => err.fmt(f),
+            Self::Irreversible { version } => {
+                write!(f, "migration {version} can't be reverted")
+            }
+            Self::UnknownVersion { target, latest } => {
+                write!(f, "version {target} doesn't exist, the latest is {latest}")
+            }
+        }
+    }
+}
+
+impl From<rusqlite::Error> for Error {
+    fn from(err: rusqlite::Error) -> Self {
+        Self::Database(err)
+    }
+}
+
+pub(crate) type Result<T> = std::result::Result<T, Error>;
+
+/// An ordered set of migrations. Version `n` means the first `n` steps have
+/// been applied.
+pub(crate) struct Migrations {
+    steps: Vec<M>,
+}
+
+impl Migrations {
+    pub(crate) fn new(steps: Vec<M>) -> Self {
+        Self { steps }
+    }
+
+    /// The version a database reaches once every step is applied.
+    pub(crate) fn latest_version(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Applies every pending step and records the new version.
+    pub(crate) fn to_latest(&self, conn: &mut Connection) -> Result<()> {
+        // A database written by a newer build is left as it is.
+        if current_version(conn)? >= self.latest_version() {
+            return Ok(());
+        }
+        self.to_version(conn, self.latest_version())
+    }
+
+    /// Moves the database to `target`, running `up` steps forwards or `down`
+    /// steps in reverse. Every step runs in one transaction, so a failure
+    /// leaves the database at its starting version.
+    pub(crate) fn to_version(&self, conn: &mut Connection, target: usize) -> Result<()> {
+        if target > self.steps.len() {
+            return Err(Error::UnknownVersion {
+                target,
+                latest: self.steps.len(),
+            });
+        }
+        let current = current_version(conn)?;
+        if current == target {
+            return Ok(());
+        }
+        if current > self.steps.len() {
+            // Written by a newer build whose steps we don't have.
+            return Err(Error::Irreversible { version: current });
+        }
+
+        let tx = conn.transaction()?;
+        if current < target {
+            for step in &self.steps[current..target] {
+                tx.execute_batch(&step.up)?;
+            }
+        } else {
+            for (index, step) in self.steps[target..current].iter().enumerate().rev() {
+                let down = step.down.as_deref().ok_or(Error::Irreversible {
+                    version: target + index + 1,
+                })?;
+                tx.execute_batch(down)?;
+            }
+        }
+        tx.pragma_update(None, "user_version", target as i64)?;
+        Ok(tx.commit()?)
+    }
+}
+
+pub(crate) fn current_version(conn: &Connection) -> rusqlite::Result<usize> {
+    conn.pragma_query_value(None, "user_version", |row| row.get::<_, i64>(0))
+        .map(|version| version.max(0) as usize)
+}
@@ -0,0 +1,2182 @@
+mod app_version;
+mod autosave;
+pub mod autosave_timer;
+mod batch;
+mod binary;
+mod checkpoints;
+mod checksum;
+mod compression;
+mod diff;
+mod directory_import;
+mod disk_space;
+mod error;
+pub mod events;
+mod export;
+mod flags;
+mod health;
+mod inventory;
+mod inventory_undo;
+mod item_rename;
+mod journal;
+mod locks;
+mod maintenance;
+mod merge;
+mod metadata_conn;
+mod migration_backup;
+mod migrations;
+mod new_game_plus;
+mod orphans;
+mod pragmas;
+mod preview;
+mod profiles;
+mod read_pool;
+mod search;
+mod settings;
+mod sources;
+mod stat_values;
+mod stats;
+mod store_archive;
+mod tags;
+mod transaction;
+mod trash;
+mod validation;
+pub mod worker;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, LazyLock, Mutex, PoisonError};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rusqlite::{params, Connection, OpenFlags, OptionalExtension};
+
+use crate::api::saves::{
+    PlayerData, SaveResult, SaveSlotMetadata, SlotEventKind, SlotHandle, SlotOrder,
+};
+pub use error::{SaveManagerError, SaveManagerResult};
+use migrations::{Migrations, M};
+pub use profiles::list_profiles;
+
+const DEFAULT_APP_NAME: &str = "my_app";
+const DEFAULT_SAVES_SUBDIRECTORY: &str = "saves";
+const METADATA_DB_FILE: &str = "metadata.db";
+
+/// Largest thumbnail accepted by `set_slot_thumbnail`, to keep the metadata
+/// database small.
+pub const MAX_THUMBNAIL_BYTES: usize = 2 * 1024 * 1024;
+
+/// Handle behind the single-active-slot API (`load_slot`, `save_player_data`,
+/// ...). Handles returned by `open_slot` start at 1 and never reuse it.
+pub const DEFAULT_HANDLE: SlotHandle = SlotHandle { value: 0 };
+
+static METADATA_DB_MIGRATIONS: LazyLock<Migrations> = LazyLock::new(|| {
+    Migrations::new(vec![
+        M::up(
+            "CREATE TABLE IF NOT EXISTS save_slots (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                file_path TEXT NOT NULL,
+                last_played TIMESTAMP NOT NULL
+            );",
+        ),
+        M::up(
+            "ALTER TABLE save_slots
+             ADD COLUMN play_time_seconds INTEGER NOT NULL DEFAULT 0;",
+        ),
+        M::up("ALTER TABLE save_slots ADD COLUMN thumbnail BLOB;"),
+        M::up(
+            "ALTER TABLE save_slots ADD COLUMN created_at TIMESTAMP;
+             UPDATE save_slots SET created_at = last_played;",
+        ),
+        M::up(
+            "CREATE TABLE slot_tags (
+                slot_id TEXT NOT NULL REFERENCES save_slots (id) ON DELETE CASCADE,
+                tag TEXT NOT NULL,
+                PRIMARY KEY (slot_id, tag)
+            );
+            CREATE INDEX slot_tags_by_tag ON slot_tags (tag);",
+        ),
+        M::up(
+            "CREATE TABLE app_settings (
+                key TEXT PRIMARY KEY,
+                value TEXT NOT NULL
+            );",
+        ),
+        M::up("ALTER TABLE save_slots ADD COLUMN description TEXT;"),
+        M::up("ALTER TABLE save_slots ADD COLUMN deleted_at TIMESTAMP;"),
+        M::up(
+            "ALTER TABLE save_slots ADD COLUMN last_played_ms INTEGER NOT NULL DEFAULT 0;
+             UPDATE save_slots
+             SET last_played_ms =
+                 COALESCE(CAST((julianday(last_played) - 2440587.5) * 86400000 AS INTEGER), 0);",
+        ),
+        M::up(
+            "CREATE TABLE change_log (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                timestamp_ms INTEGER NOT NULL,
+                slot_id TEXT NOT NULL,
+                operation TEXT NOT NULL,
+                revision INTEGER
+            );
+            CREATE INDEX change_log_by_time ON change_log (timestamp_ms);",
+        ),
+        M::up(
+            "CREATE TABLE slot_checkpoints (
+                id TEXT PRIMARY KEY,
+                slot_id TEXT NOT NULL REFERENCES save_slots (id) ON DELETE CASCADE,
+                label TEXT NOT NULL,
+                created_at TIMESTAMP NOT NULL,
+                file_path TEXT NOT NULL
+            );
+            CREATE INDEX slot_checkpoints_by_slot ON slot_checkpoints (slot_id);",
+        ),
+    ])
+});
+
+/// The built-in slot schema: player stats, inventory and game flags.
+static SLOT_DB_MIGRATIONS: LazyLock<Arc<Migrations>> = LazyLock::new(|| {
+    Arc::new(Migrations::new(vec![
+        M::up(
+            "CREATE TABLE player_stats (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                health INTEGER NOT NULL,
+                experience INTEGER NOT NULL
+            );
+            CREATE TABLE inventory (
+                position INTEGER PRIMARY KEY,
+                item TEXT NOT NULL
+            );",
+        )
+        .down(
+            "DROP TABLE inventory;
+             DROP TABLE player_stats;",
+        ),
+        M::up(
+            "CREATE TABLE game_flags (
+                key TEXT PRIMARY KEY,
+                value TEXT NOT NULL
+            );",
+        )
+        .down("DROP TABLE game_flags;"),
+        M::up("ALTER TABLE player_stats ADD COLUMN save_checksum TEXT;")
+            .down("ALTER TABLE player_stats DROP COLUMN save_checksum;"),
+        M::up("ALTER TABLE player_stats ADD COLUMN revision INTEGER NOT NULL DEFAULT 0;")
+            .down("ALTER TABLE player_stats DROP COLUMN revision;"),
+        M::up("ALTER TABLE player_stats ADD COLUMN app_save_version INTEGER NOT NULL DEFAULT 0;")
+            .down("ALTER TABLE player_stats DROP COLUMN app_save_version;"),
+        // Health and experience stay in `player_stats`, which everything else
+        // reads; the triggers mirror them here so every stat can be read by key.
+        M::up(
+            "CREATE TABLE player_stat_values (
+                key TEXT PRIMARY KEY,
+                value NOT NULL
+            );
+            INSERT INTO player_stat_values (key, value)
+                SELECT 'health', health FROM player_stats WHERE id = 1
+                UNION ALL
+                SELECT 'experience', experience FROM player_stats WHERE id = 1;
+            CREATE TRIGGER player_stats_mirror_insert AFTER INSERT ON player_stats
+            BEGIN
+                INSERT OR REPLACE INTO player_stat_values (key, value)
+                VALUES ('health', NEW.health), ('experience', NEW.experience);
+            END;
+            CREATE TRIGGER player_stats_mirror_update
+            AFTER UPDATE OF health, experience ON player_stats
+            BEGIN
+                INSERT OR REPLACE INTO player_stat_values (key, value)
+                VALUES ('health', NEW.health), ('experience', NEW.experience);
+            END;
+            CREATE TRIGGER player_stats_mirror_delete AFTER DELETE ON player_stats
+            BEGIN
+                DELETE FROM player_stat_values WHERE key IN ('health', 'experience');
+            END;",
+        )
+        .down(
+            "DROP TRIGGER player_stats_mirror_delete;
+             DROP TRIGGER player_stats_mirror_update;
+             DROP TRIGGER player_stats_mirror_insert;
+             DROP TABLE player_stat_values;",
+        ),
+    ]))
+});
+
+/// Slot migrations the app registered to replace `SLOT_DB_MIGRATIONS`.
+static CUSTOM_SLOT_MIGRATIONS: Mutex<Option<Arc<Migrations>>> = Mutex::new(None);
+
+/// Replaces the built-in slot schema with the app's own `(up, down)` SQL
+/// steps for managers initialized from now on; an empty list goes back to
+/// the built-in one. Step `n` takes slots to schema version `n`, and a step
+/// without down SQL can't be migrated below.
+///
+/// The player data, inventory, flag and export functions all expect the
+/// built-in `player_stats`, `inventory` and `game_flags` tables, so a custom
+/// schema that drops them can only use the slot management functions.
+pub fn register_slot_migrations(steps: Vec<(String, Option<String>)>) {
+    let migrations = (!steps.is_empty()).then(|| {
+        Arc::new(Migrations::new(
+            steps
+                .into_iter()
+                .map(|(up, down)| M::owned(up, down))
+                .collect(),
+        ))
+    });
+    *CUSTOM_SLOT_MIGRATIONS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = migrations;
+}
+
+/// The registered slot migrations, or the built-in ones.
+fn registered_slot_migrations() -> Arc<Migrations> {
+    CUSTOM_SLOT_MIGRATIONS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+        .unwrap_or_else(|| Arc::clone(&SLOT_DB_MIGRATIONS))
+}
+
+/// The schema version every slot is migrated to when it is loaded, by a
+/// manager initialized now.
+pub fn latest_slot_schema_version() -> usize {
+    registered_slot_migrations().latest_version()
+}
+
+/// The schema version the metadata database is migrated to on
+/// initialization.
+pub fn latest_metadata_schema_version() -> usize {
+    METADATA_DB_MIGRATIONS.latest_version()
+}
+
+/// Where under the base path a manager keeps its files, and how it opens
+/// them. Saves live in `<base>/<app_name>/<saves_subdirectory>`, next to
+/// `<base>/<app_name>/metadata.db`. With a profile, both move down into
+/// `<base>/<app_name>/profiles/<profile>`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SaveConfig {
+    pub app_name: String,
+    pub saves_subdirectory: String,
+    /// SQLCipher key applied to every connection, if saves are encrypted.
+    pub encryption_key: Option<String>,
+    /// Whose saves these are, on a device shared by several players. Each
+    /// profile has its own slots and metadata.
+    pub profile: Option<String>,
+}
+
+impl Default for SaveConfig {
+    fn default() -> Self {
+        Self {
+            app_name: DEFAULT_APP_NAME.to_owned(),
+            saves_subdirectory: DEFAULT_SAVES_SUBDIRECTORY.to_owned(),
+            encryption_key: None,
+            profile: None,
+        }
+    }
+}
+
+/// Owns the save directory layout, the metadata database and a connection
+/// per open slot handle.
+pub struct SaveManager {
+    base_path: PathBuf,
+    saves_dir: PathBuf,
+    metadata_db_path: PathBuf,
+    open_slots: HashMap<SlotHandle, OpenSlot>,
+    next_handle: u32,
+    autosave: Option<autosave::AutosaveConfig>,
+    autosave_payload: Option<PlayerData>,
+    autosave_retention: autosave::AutosaveRetention,
+    config: SaveConfig,
+    limits: validation::ValidationLimits,
+    integrity_check: bool,
+    app_save_version: u32,
+    new_game_plus_health: i32,
+    busy_retries: u32,
+    /// Fixed when the manager is initialized, so registering new ones
+    /// can't change the schema under open slots.
+    slot_migrations: Arc<Migrations>,
+    /// The slot whose schema is being migrated right now, if any.
+    migrating: RefCell<Option<String>>,
+    sqlite_options: pragmas::SqliteOptions,
+    keep_metadata_connection: bool,
+    metadata_cache: metadata_conn::MetadataConnectionCache,
+    clock: Box<dyn sources::Clock>,
+    ids: Box<dyn sources::IdGenerator>,
+    disk_space: Box<dyn sources::DiskSpace>,
+    low_disk_space_bytes: u64,
+}
+
+/// A slot handle's writer connection, with the slot it was opened for and
+/// read-only connections for queries.
+struct OpenSlot {
+    slot_id: String,
+    conn: Connection,
+    readers: read_pool::ReadPool,
+    undo: RefCell<inventory_undo::UndoBuffer>,
+}
+
+impl SaveManager {
+    /// Creates the on-disk layout under `base_path` and prepares the metadata
+    /// database. With an encryption key, every database the manager opens is
+    /// keyed with it; saves written without a key can't be read this way.
+    pub fn initialize(base_path: &Path, config: SaveConfig) -> SaveManagerResult<Self> {
+        Self::initialize_with_sources(
+            base_path,
+            config,
+            Box::new(sources::SystemClock),
+            Box::new(sources::RandomIds),
+        )
+    }
+
+    fn initialize_with_sources(
+        base_path: &Path,
+        config: SaveConfig,
+        clock: Box<dyn sources::Clock>,
+        ids: Box<dyn sources::IdGenerator>,
+    ) -> SaveManagerResult<Self> {
+        let manager = Self::unprepared(base_path, config, clock, ids)?;
+        manager.initialize_metadata_db()?;
+        Ok(manager)
+    }
+
+    /// Creates the directories and the manager itself, leaving the metadata
+    /// database untouched.
+    fn unprepared(
+        base_path: &Path,
+        config: SaveConfig,
+        clock: Box<dyn sources::Clock>,
+        ids: Box<dyn sources::IdGenerator>,
+    ) -> SaveManagerResult<Self> {
+        validate_directory_name("app name", &config.app_name)?;
+        validate_directory_name("saves subdirectory", &config.saves_subdirectory)?;
+
+        let app_dir = base_path.join(&config.app_name);
+        let data_dir = match &config.profile {
+            Some(profile) => {
+                validate_directory_name("profile", profile)?;
+                profiles::profile_dir(&app_dir, profile)
+            }
+            None => app_dir,
+        };
+        let saves_dir = data_dir.join(&config.saves_subdirectory);
+        fs::create_dir_all(&saves_dir)?;
+
+        Ok(Self {
+            base_path: base_path.to_path_buf(),
+            saves_dir,
+            metadata_db_path: data_dir.join(METADATA_DB_FILE),
+            open_slots: HashMap::new(),
+            next_handle: DEFAULT_HANDLE.value,
+            autosave: None,
+            autosave_payload: None,
+            autosave_retention: autosave::AutosaveRetention::default(),
+            config,
+            limits: validation::ValidationLimits::default(),
+            integrity_check: true,
+            app_save_version: 0,
+            new_game_plus_health: new_game_plus::DEFAULT_NEW_GAME_PLUS_HEALTH,
+            busy_retries: locks::DEFAULT_BUSY_RETRIES,
+            slot_migrations: registered_slot_migrations(),
+            migrating: RefCell::new(None),
+            sqlite_options: pragmas::SqliteOptions::default(),
+            keep_metadata_connection: true,
+            metadata_cache: metadata_conn::MetadataConnectionCache::default(),
+            clock,
+            ids,
+            disk_space: Box::new(sources::SystemDiskSpace),
+            low_disk_space_bytes: disk_space::DEFAULT_LOW_DISK_SPACE_BYTES,
+        })
+    }
+
+    /// Whether this manager was initialized for `base_path` with `config`.
+    pub fn is_configured_for(&self, base_path: &Path, config: &SaveConfig) -> bool {
+        same_file_path(&self.base_path, base_path) && self.config == *config
+    }
+
+    pub fn base_path(&self) -> &Path {
+        &self.base_path
+    }
+
+    pub fn config(&self) -> &SaveConfig {
+        &self.config
+    }
+
+    pub fn saves_dir(&self) -> &Path {
+        &self.saves_dir
+    }
+
+    pub fn metadata_db_path(&self) -> &Path {
+        &self.metadata_db_path
+    }
+
+    /// Checkpoints and closes every open slot, e.g. before the manager is
+    /// replaced.
+    pub fn close_all_slots(&mut self) {
+        let handles: Vec<_> = self.open_slots.keys().copied().collect();
+        for handle in handles {
+            self.close_connection(handle);
+        }
+    }
+
+    /// Closes every slot, folds the metadata WAL back into its file and
+    /// closes the cached metadata connection, so no file stays open or
+    /// locked. Meant for just before the manager is dropped.
+    pub fn shutdown(&mut self) -> SaveManagerResult<()> {
+        self.close_all_slots();
+        let checkpointed = self
+            .metadata_connection()
+            .and_then(|conn| Ok(checkpoint(&conn)?));
+        self.metadata_cache.discard();
+        checkpointed
+    }
+
+    fn initialize_metadata_db(&self) -> SaveManagerResult<()> {
+        let mut conn = self.open_connection(&self.metadata_db_path)?;
+        adopt_unversioned_metadata_db(&conn)?;
+        run_migrations(&METADATA_DB_MIGRATIONS, &mut conn)?;
+        verify_metadata_schema(&conn)
+    }
+
+    fn open_connection(&self, path: &Path) -> SaveManagerResult<Connection> {
+        open_configured_connection(
+            path,
+            self.config.encryption_key.as_deref(),
+            &self.sqlite_options,
+        )
+    }
+
+    fn open_readonly(&self, path: &Path) -> SaveManagerResult<Connection> {
+        open_readonly_connection(
+            path,
+            self.config.encryption_key.as_deref(),
+            &self.sqlite_options,
+        )
+    }
+
+    fn slot_path(&self, slot_id: &str) -> PathBuf {
+        self.saves_dir.join(format!("{slot_id}.db"))
+    }
+
+    /// Registers a new slot and creates its database file with the current
+    /// slot schema. Returns the complete new row, the same as `get_slot`
+    /// would.
+    pub fn create_slot(&self, display_name: &str) -> SaveManagerResult<SaveSlotMetadata> {
+        self.validate_slot_name_length(display_name)?;
+        let conn = self.metadata_connection()?;
+        let id = self.new_id(&conn)?;
+        let file_path = self.slot_path(&id);
+
+        let mut slot_conn = match self.open_connection(&file_path) {
+            Err(_) if self.restore_saves_dir()? => self.open_connection(&file_path)?,
+            result => result?,
+        };
+        run_migrations(&self.slot_migrations, &mut slot_conn)?;
+        drop(slot_conn);
+
+        let file_path = file_path.to_string_lossy().into_owned();
+        let (now, now_ms) = self.now_timestamp();
+        conn.execute(
+            "INSERT INTO save_slots (id, name, file_path, last_played, created_at, last_played_ms)
+             VALUES (?1, ?2, ?3, ?4, ?4, ?5)",
+            params![id, display_name, file_path, now, now_ms],
+        )?;
+        self.record_change(&id, SlotEventKind::Created, None)?;
+
+        // Read the row back so column defaults show up exactly as `get_slot`
+        // will report them.
+        fetch_slot(&conn, &id)
+    }
+
+    /// Lists every slot, most recently played first.
+    pub fn get_all_slots(&self) -> SaveManagerResult<Vec<SaveSlotMetadata>> {
+        self.query_slots(SlotOrder::LastPlayedDesc, 0, -1)
+    }
+
+    /// How many slots there are, not counting the trash.
+    pub fn slot_count(&self) -> SaveManagerResult<usize> {
+        let count: i64 = self.metadata_connection()?.query_row(
+            "SELECT COUNT(*) FROM save_slots WHERE deleted_at IS NULL",
+            [],
+            |row| row.get(0),
+        )?;
+        Ok(count as usize)
+    }
+
+    /// Returns up to `limit` slots starting at `offset` in the given order.
+    pub fn get_slots_page(
+        &self,
+        offset: u32,
+        limit: u32,
+        order: SlotOrder,
+    ) -> SaveManagerResult<Vec<SaveSlotMetadata>> {
+        self.query_slots(order, offset, i64::from(limit))
+    }
+
+    /// A negative `limit` means no limit, as in SQLite.
+    fn query_slots(
+        &self,
+        order: SlotOrder,
+        offset: u32,
+        limit: i64,
+    ) -> SaveManagerResult<Vec<SaveSlotMetadata>> {
+        // The id tie-breaker keeps pages stable when sort keys collide.
+        let order_by = match order {
+            SlotOrder::LastPlayedDesc => "last_played_ms DESC",
+            SlotOrder::LastPlayedAsc => "last_played_ms ASC",
+            SlotOrder::NameAsc => "name COLLATE NOCASE ASC",
+            SlotOrder::NameDesc => "name COLLATE NOCASE DESC",
+            SlotOrder::CreatedDesc => "created_at DESC",
+            SlotOrder::CreatedAsc => "created_at ASC",
+        };
+
+        let conn = self.metadata_connection()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {SLOT_COLUMNS} FROM save_slots
+             WHERE deleted_at IS NULL
+             ORDER BY {order_by}, id
+             LIMIT ?1 OFFSET ?2"
+        ))?;
+        let slots = stmt
+            .query_map(params![limit, offset], slot_from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(slots)
+    }
+
+    /// Creates a slot that already holds `data`, e.g. for New Game+ or a
+    /// difficulty preset: the data is validated, written and checkpointed
+    /// before this returns. If the write fails the new slot is removed again.
+    pub fn create_slot_with_data(
+        &mut self,
+        display_name: &str,
+        data: &PlayerData,
+    ) -> SaveManagerResult<SaveSlotMetadata> {
+        self.validate_player_data(data)?;
+
+        let checksum = self.checksum_for(data);
+        let metadata = self.create_slot(display_name)?;
+        let written = self
+            .open_connection(Path::new(&metadata.file_path))
+            .and_then(|mut conn| {
+                write_player_data(
+                    &mut conn,
+                    data,
+                    checksum.as_deref(),
+                    self.app_save_version,
+                    None,
+                )?;
+                Ok(checkpoint(&conn)?)
+            });
+        if let Err(err) = written {
+            // Don't leave a half-written slot in the list, or in the trash.
+            let _ = self.purge_slot(&metadata.id);
+            return Err(err);
+        }
+        Ok(metadata)
+    }
+
+    /// Fetches a single slot's metadata.
+    pub fn get_slot(&self, slot_id: &str) -> SaveManagerResult<SaveSlotMetadata> {
+        fetch_slot(&*self.metadata_connection()?, slot_id)
+    }
+
+    /// Changes a slot's display name and returns the updated metadata.
+    pub fn rename_slot(
+        &self,
+        slot_id: &str,
+        new_name: &str,
+    ) -> SaveManagerResult<SaveSlotMetadata> {
+        self.validate_slot_name(new_name)?;
+
+        let conn = self.metadata_connection()?;
+        let rows_affected = conn.execute(
+            "UPDATE save_slots SET name = ?2 WHERE id = ?1 AND deleted_at IS NULL",
+            params![slot_id, new_name],
+        )?;
+        if rows_affected == 0 {
+            return Err(SaveManagerError::SlotNotFound(slot_id.to_owned()));
+        }
+        self.record_change(slot_id, SlotEventKind::Renamed, None)?;
+
+        fetch_slot(&conn, slot_id)
+    }
+
+    /// Copies a slot into a new one named `new_name`, carrying over its play
+    /// time, description, thumbnail and tags. The source is checkpointed first
+    /// so the copy includes everything still sitting in its WAL.
+    pub fn duplicate_slot(
+        &self,
+        slot_id: &str,
+        new_name: &str,
+    ) -> SaveManagerResult<SaveSlotMetadata> {
+        self.validate_slot_name(new_name)?;
+
+        let conn = self.metadata_connection()?;
+        let source_path = PathBuf::from(slot_file_path(&conn, slot_id)?);
+        self.checkpoint_slot_file(&source_path)?;
+
+        let new_id = self.new_id(&conn)?;
+        let new_path = self.slot_path(&new_id);
+        fs::copy(&source_path, &new_path)?;
+
+        let (now, now_ms) = self.now_timestamp();
+        let inserted = conn.unchecked_transaction().and_then(|tx| {
+            tx.execute(
+                "INSERT INTO save_slots
+                     (id, name, file_path, last_played, created_at, last_played_ms,
+                      play_time_seconds, description, thumbnail)
+                 SELECT ?2, ?3, ?4, ?5, ?5, ?6, play_time_seconds, description, thumbnail
+                 FROM save_slots WHERE id = ?1",
+                params![
+                    slot_id,
+                    new_id,
+                    new_name,
+                    new_path.to_string_lossy(),
+                    now,
+                    now_ms
+                ],
+            )?;
+            tx.execute(
+                "INSERT INTO slot_tags (slot_id, tag)
+                 SELECT ?2, tag FROM slot_tags WHERE slot_id = ?1",
+                params![slot_id, new_id],
+            )?;
+            tx.commit()
+        });
+        if let Err(err) = inserted {
+            // Don't leave an unregistered copy behind.
+            let _ = remove_slot_files(&new_path);
+            return Err(err.into());
+        }
+        self.record_change(&new_id, SlotEventKind::Created, None)?;
+        fetch_slot(&conn, &new_id)
+    }
+
+    /// Sets `last_played` to now for every slot in `slot_ids`, in a single
+    /// metadata transaction, and returns the ids that exist. Unknown ids are
+    /// skipped rather than failing the batch.
+    pub fn touch_slots(&self, slot_ids: &[String]) -> SaveManagerResult<Vec<String>> {
+        let conn = self.metadata_connection()?;
+        let tx = conn.unchecked_transaction()?;
+        let (now, now_ms) = self.now_timestamp();
+        let mut touched = Vec::new();
+        {
+            let mut stmt = tx.prepare(
+                "UPDATE save_slots SET last_played = ?2, last_played_ms = ?3
+                 WHERE id = ?1 AND deleted_at IS NULL",
+            )?;
+            for slot_id in slot_ids {
+                if stmt.execute(params![slot_id, now, now_ms])? > 0 {
+                    touched.push(slot_id.clone());
+                }
+            }
+        }
+        tx.commit()?;
+        Ok(touched)
+    }
+
+    /// Adds `seconds` to a slot's accumulated play time.
+    pub fn add_play_time(&self, slot_id: &str, seconds: i64) -> SaveManagerResult<()> {
+        let conn = self.metadata_connection()?;
+        // Incrementing in SQL keeps concurrent callers from losing updates.
+        let rows_affected = conn.execute(
+            "UPDATE save_slots SET play_time_seconds = play_time_seconds + ?2 WHERE id = ?1 AND deleted_at IS NULL",
+            params![slot_id, seconds],
+        )?;
+        if rows_affected == 0 {
+            return Err(SaveManagerError::SlotNotFound(slot_id.to_owned()));
+        }
+        Ok(())
+    }
+
+    /// Replaces the player's notes on a slot; an empty string clears them.
+    pub fn set_slot_description(&self, slot_id: &str, text: &str) -> SaveManagerResult<()> {
+        let conn = self.metadata_connection()?;
+        let rows_affected = conn.execute(
+            "UPDATE save_slots SET description = NULLIF(?2, '') WHERE id = ?1 AND deleted_at IS NULL",
+            params![slot_id, text],
+        )?;
+        if rows_affected == 0 {
+            return Err(SaveManagerError::SlotNotFound(slot_id.to_owned()));
+        }
+        Ok(())
+    }
+
+    /// Stores a preview image for the slot's save card. Thumbnails live in the
+    /// metadata database but are left out of the slot listing queries.
+    pub fn set_slot_thumbnail(&self, slot_id: &str, png_bytes: &[u8]) -> SaveManagerResult<()> {
+        if png_bytes.len() > MAX_THUMBNAIL_BYTES {
+            return Err(SaveManagerError::ThumbnailTooLarge {
+                size: png_bytes.len(),
+                max: MAX_THUMBNAIL_BYTES,
+            });
+        }
+
+        let conn = self.metadata_connection()?;
+        let rows_affected = conn.execute(
+            "UPDATE save_slots SET thumbnail = ?2 WHERE id = ?1 AND deleted_at IS NULL",
+            params![slot_id, png_bytes],
+        )?;
+        if rows_affected == 0 {
+            return Err(SaveManagerError::SlotNotFound(slot_id.to_owned()));
+        }
+        Ok(())
+    }
+
+    pub fn get_slot_thumbnail(&self, slot_id: &str) -> SaveManagerResult<Option<Vec<u8>>> {
+        let conn = self.metadata_connection()?;
+        conn.query_row(
+            "SELECT thumbnail FROM save_slots WHERE id = ?1 AND deleted_at IS NULL",
+            params![slot_id],
+            |row| row.get(0),
+        )
+        .optional()?
+        .ok_or_else(|| SaveManagerError::SlotNotFound(slot_id.to_owned()))
+    }
+
+    /// Makes `slot_id` the active slot, upgrading its schema if needed.
+    pub fn load_slot(&mut self, slot_id: &str) -> SaveManagerResult<()> {
+        self.open_slot_as(DEFAULT_HANDLE, slot_id)
+    }
+
+    /// Checkpoints and closes the active slot, releasing its files. Does
+    /// nothing if no slot is loaded.
+    pub fn unload_slot(&mut self) {
+        self.close_connection(DEFAULT_HANDLE);
+    }
+
+    /// The id of the loaded slot, if any.
+    pub fn active_slot_id(&self) -> Option<&str> {
+        self.open_slots
+            .get(&DEFAULT_HANDLE)
+            .map(|slot| slot.slot_id.as_str())
+    }
+
+    /// Opens `slot_id` alongside any other open slots and returns a handle for
+    /// the handle-based operations.
+    pub fn open_slot(&mut self, slot_id: &str) -> SaveManagerResult<SlotHandle> {
+        let handle = SlotHandle {
+            value: self.next_handle + 1,
+        };
+        self.open_slot_as(handle, slot_id)?;
+        self.next_handle = handle.value;
+        Ok(handle)
+    }
+
+    /// Releases a handle returned by `open_slot` (or the default handle).
+    pub fn close_slot(&mut self, handle: SlotHandle) -> SaveManagerResult<()> {
+        self.connection(handle)?;
+        self.close_connection(handle);
+        Ok(())
+    }
+
+    fn open_slot_as(&mut self, handle: SlotHandle, slot_id: &str) -> SaveManagerResult<()> {
+        let meta_conn = self.metadata_connection()?;
+        let file_path = slot_file_path(&meta_conn, slot_id)?;
+
+        self.close_connection(handle);
+
+        let conn = self
+            .open_migrated_slot(slot_id, Path::new(&file_path))
+            .map_err(|err| match err {
+                SaveManagerError::Database(ref db_err) if is_corruption(db_err) => {
+                    SaveManagerError::CorruptSlot(slot_id.to_owned())
+                }
+                err => locks::locked_error(slot_id, err),
+            })?;
+
+        let (now, now_ms) = self.now_timestamp();
+        meta_conn.execute(
+            "UPDATE save_slots SET last_played = ?2, last_played_ms = ?3 WHERE id = ?1",
+            params![slot_id, now, now_ms],
+        )?;
+
+        self.open_slots.insert(
+            handle,
+            OpenSlot {
+                slot_id: slot_id.to_owned(),
+                conn,
+                readers: read_pool::ReadPool::default(),
+                undo: RefCell::default(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Whether the slot is registered (and not in the trash) and its database
+    /// file is still on disk. A row whose file has gone missing counts as
+    /// not existing, since loading it would fail.
+    pub fn slot_exists(&self, slot_id: &str) -> SaveManagerResult<bool> {
+        match slot_file_path(&*self.metadata_connection()?, slot_id) {
+            Ok(file_path) => Ok(Path::new(&file_path).is_file()),
+            Err(SaveManagerError::SlotNotFound(_)) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Runs SQLite's integrity check on a slot without opening it as a handle.
+    /// A missing or unreadable file counts as a failed check.
+    pub fn verify_slot(&self, slot_id: &str) -> SaveManagerResult<bool> {
+        let file_path = PathBuf::from(slot_file_path(&*self.metadata_connection()?, slot_id)?);
+        if !file_path.exists() {
+            return Ok(false);
+        }
+
+        let result = self.open_readonly(&file_path).and_then(|conn| {
+            Ok(conn.query_row("PRAGMA integrity_check", [], |row| row.get::<_, String>(0))?)
+        });
+        match result {
+            Ok(status) => Ok(status == "ok"),
+            Err(SaveManagerError::Database(ref err)) if is_corruption(err) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Bytes used by a slot's database and its `-wal`/`-shm` sidecars.
+    /// Autosave backups are not included.
+    pub fn slot_disk_usage(&self, slot_id: &str) -> SaveManagerResult<u64> {
+        let file_path = slot_file_path(&*self.metadata_connection()?, slot_id)?;
+        Ok(slot_files_size(Path::new(&file_path))?)
+    }
+
+    /// Bytes used by everything in the saves directory, autosaves included.
+    pub fn total_saves_disk_usage(&self) -> SaveManagerResult<u64> {
+        Ok(directory_size(&self.saves_dir)?)
+    }
+
+    /// The schema version a slot's file is at, read without migrating it, so
+    /// callers can tell whether loading will upgrade it.
+    pub fn slot_schema_version(&self, slot_id: &str) -> SaveManagerResult<usize> {
+        let file_path = slot_file_path(&*self.metadata_connection()?, slot_id)?;
+        let conn = self.open_readonly(Path::new(&file_path))?;
+        Ok(migrations::current_version(&conn)?)
+    }
+
+    /// Moves a closed slot's schema up or down to `target`, e.g. to test an
+    /// older schema or to hand a save back to a previous build. Migrating
+    /// down drops whatever the reverted steps added. The slot must not be
+    /// open, since loading it migrates it straight back to the latest
+    /// version.
+    pub fn migrate_slot_to_version(&self, slot_id: &str, target: usize) -> SaveManagerResult<()> {
+        let file_path = PathBuf::from(slot_file_path(&*self.metadata_connection()?, slot_id)?);
+        if !self.handles_for_path(&file_path).is_empty() {
+            return Err(SaveManagerError::SlotInUse(slot_id.to_owned()));
+        }
+
+        let mut conn = self.open_connection(&file_path)?;
+        self.slot_migrations.to_version(&mut conn, target).map_err(
+            |err| match migration_error(err) {
+                SaveManagerError::Migration(message) => SaveManagerError::Migration(format!(
+                    "migrating slot {slot_id} to version {target}: {message}"
+                )),
+                err => err,
+            },
+        )?;
+        Ok(checkpoint(&conn)?)
+    }
+
+    /// Rebuilds a closed slot's database to reclaim free pages. Open slots
+    /// are refused: `VACUUM` can't run while another connection may be mid
+    /// transaction on the same file.
+    pub fn vacuum_slot(&self, slot_id: &str) -> SaveManagerResult<()> {
+        let file_path = PathBuf::from(slot_file_path(&*self.metadata_connection()?, slot_id)?);
+        if !self.handles_for_path(&file_path).is_empty() {
+            return Err(SaveManagerError::SlotInUse(slot_id.to_owned()));
+        }
+        vacuum(&self.open_connection(&file_path)?)
+    }
+
+    /// Rebuilds the metadata database to reclaim free pages.
+    pub fn vacuum_metadata(&self) -> SaveManagerResult<()> {
+        vacuum(&*self.metadata_connection()?)
+    }
+
+    /// Moves a slot's database into `new_dir`, e.g. onto removable storage,
+    /// and points its metadata row at the new location. Handles opened with
+    /// `open_slot` are closed first; the loaded slot is refused until it is
+    /// unloaded. Autosaves and migration backups stay in the saves directory.
+    pub fn move_slot(&mut self, slot_id: &str, new_dir: &Path) -> SaveManagerResult<()> {
+        let meta_conn = self.metadata_connection()?;
+        let file_path = PathBuf::from(slot_file_path(&meta_conn, slot_id)?);
+        let handles = self.handles_for_path(&file_path);
+        if handles.contains(&DEFAULT_HANDLE) {
+            return Err(SaveManagerError::SlotInUse(slot_id.to_owned()));
+        }
+        for handle in handles {
+            self.close_connection(handle);
+        }
+        checkpoint(&self.open_connection(&file_path)?)?;
+
+        fs::create_dir_all(new_dir)?;
+        let file_name = file_path
+            .file_name()
+            .ok_or_else(|| SaveManagerError::SlotNotFound(slot_id.to_owned()))?;
+        let target = new_dir.join(file_name);
+        if same_file_path(&file_path, &target) {
+            return Ok(());
+        }
+        if target.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", target.display()),
+            )
+            .into());
+        }
+
+        move_slot_files(&file_path, &target)?;
+        let target_str = target.to_string_lossy().into_owned();
+        if let Err(err) = meta_conn.execute(
+            "UPDATE save_slots SET file_path = ?1 WHERE id = ?2",
+            params![target_str, slot_id],
+        ) {
+            // Put the files back so the row still points at a real slot.
+            let _ = move_slot_files(&target, &file_path);
+            return Err(err.into());
+        }
+        Ok(())
+    }
+
+    /// Validates `data`, overwrites the slot's stats and inventory in one
+    /// transaction, then checkpoints so the `.db` file alone holds the save.
+    /// That costs a little write latency but keeps the file safe to copy or
+    /// cloud-sync right away.
+    pub fn save_player_data(
+        &mut self,
+        handle: SlotHandle,
+        data: &PlayerData,
+    ) -> SaveManagerResult<SaveResult> {
+        self.write_slot(handle, data, None)
+    }
+
+    /// Like `save_player_data`, but fails with `RevisionConflict` unless the
+    /// slot is still at `expected_revision`, e.g. because another device
+    /// saved to it through cloud sync since this one last loaded it.
+    pub fn save_player_data_checked(
+        &mut self,
+        handle: SlotHandle,
+        data: &PlayerData,
+        expected_revision: i64,
+    ) -> SaveManagerResult<SaveResult> {
+        self.write_slot(handle, data, Some(expected_revision))
+    }
+
+    /// How many times the slot's player data has been written; 0 if never.
+    pub fn player_data_revision(&self, handle: SlotHandle) -> SaveManagerResult<i64> {
+        self.with_reader(handle, |conn| Ok(current_revision(conn)?))
+    }
+
+    fn write_slot(
+        &mut self,
+        handle: SlotHandle,
+        data: &PlayerData,
+        expected_revision: Option<i64>,
+    ) -> SaveManagerResult<SaveResult> {
+        self.validate_player_data(data)?;
+        let slot_id = &self.slot_entry(handle)?.slot_id;
+        self.ensure_not_migrating(slot_id)?;
+        self.ensure_no_transaction(handle)?;
+        self.warn_if_low_on_disk(slot_id);
+        self.reopen_if_saves_dir_vanished(handle)?;
+        let checksum = self.checksum_for(data);
+        let version = self.app_save_version;
+        let retries = self.busy_retries;
+        let slot = self.slot_entry_mut(handle)?;
+        let result = locks::retry_on_busy(retries, || {
+            write_player_data(
+                &mut slot.conn,
+                data,
+                checksum.as_deref(),
+                version,
+                expected_revision,
+            )
+        })
+        .map_err(|err| disk_space::full_error(locks::locked_error(&slot.slot_id, err)))?;
+        checkpoint(&slot.conn).map_err(|err| disk_space::full_error(err.into()))?;
+        let slot_id = slot.slot_id.clone();
+        self.record_change(&slot_id, SlotEventKind::Saved, Some(result.revision))?;
+        Ok(result)
+    }
+
+    /// Saves `data` and replaces the slot's thumbnail as one operation, so a
+    /// slot card never shows a preview from a different save.
+    ///
+    /// The two live in separate databases, so this can't be a single
+    /// transaction. The thumbnail update is staged in an open metadata
+    /// transaction, the player data is committed, and only then is the
+    /// metadata committed. Any failure up to the player data commit leaves
+    /// both untouched. If the final metadata commit fails, the previous
+    /// player data is written back; that restore is best-effort, so an I/O
+    /// failure at that exact point can still leave the new data with the old
+    /// thumbnail. While either database is busy the whole exchange is retried
+    /// like a plain save.
+    pub fn save_player_data_with_thumbnail(
+        &mut self,
+        handle: SlotHandle,
+        data: &PlayerData,
+        png_bytes: &[u8],
+    ) -> SaveManagerResult<SaveResult> {
+        if png_bytes.len() > MAX_THUMBNAIL_BYTES {
+            return Err(SaveManagerError::ThumbnailTooLarge {
+                size: png_bytes.len(),
+                max: MAX_THUMBNAIL_BYTES,
+            });
+        }
+        self.validate_player_data(data)?;
+        let slot_id = &self.slot_entry(handle)?.slot_id;
+        self.ensure_not_migrating(slot_id)?;
+        self.ensure_no_transaction(handle)?;
+        self.warn_if_low_on_disk(slot_id);
+        self.reopen_if_saves_dir_vanished(handle)?;
+        let checksum = self.checksum_for(data);
+
+        let meta_conn = self.metadata_connection()?;
+        let previous = read_player_data(&self.slot_entry(handle)?.conn)?;
+        let previous_checksum = previous.as_ref().and_then(|data| self.checksum_for(data));
+        let previous_version =
+            app_version::stored_app_save_version(&self.slot_entry(handle)?.conn)?;
+        let version = self.app_save_version;
+        let retries = self.busy_retries;
+        let slot = self.slot_entry_mut(handle)?;
+        let result = locks::retry_on_busy(retries, || {
+            let meta_tx = meta_conn.unchecked_transaction()?;
+            let rows_affected = meta_tx.execute(
+                "UPDATE save_slots SET thumbnail = ?2 WHERE id = ?1",
+                params![slot.slot_id, png_bytes],
+            )?;
+            if rows_affected == 0 {
+                return Err(SaveManagerError::SlotNotFound(slot.slot_id.clone()));
+            }
+
+            let result =
+                write_player_data(&mut slot.conn, data, checksum.as_deref(), version, None)?;
+            if let Err(err) = meta_tx.commit() {
+                let _ = match &previous {
+                    Some(previous) => write_player_data(
+                        &mut slot.conn,
+                        previous,
+                        previous_checksum.as_deref(),
+                        previous_version,
+                        None,
+                    )
+                    .map(drop),
+                    None => slot
+                        .conn
+                        .execute_batch("DELETE FROM inventory; DELETE FROM player_stats;")
+                        .map_err(SaveManagerError::from),
+                };
+                return Err(err.into());
+            }
+            Ok(result)
+        })
+        .map_err(|err| disk_space::full_error(locks::locked_error(&slot.slot_id, err)))?;
+
+        checkpoint(&slot.conn).map_err(|err| disk_space::full_error(err.into()))?;
+        let slot_id = slot.slot_id.clone();
+        self.record_change(&slot_id, SlotEventKind::Saved, Some(result.revision))?;
+        Ok(result)
+    }
+
+    /// Folds the slot's WAL into its main file, e.g. before the app is
+    /// backgrounded.
+    pub fn checkpoint_slot(&self, handle: SlotHandle) -> SaveManagerResult<()> {
+        Ok(checkpoint(self.connection(handle)?)?)
+    }
+
+    /// Reads the slot's stats and its inventory in position order, checking
+    /// them against their stored checksum unless integrity checks are off.
+    pub fn load_player_data(&self, handle: SlotHandle) -> SaveManagerResult<PlayerData> {
+        self.with_reader(handle, |conn| {
+            let data = read_player_data(conn)?.ok_or(SaveManagerError::NoPlayerData)?;
+            self.verify_checksum(handle, conn, &data)?;
+            Ok(data)
+        })
+    }
+
+    /// Moves a slot to the trash, closing any handles that have it open
+    /// first. Its files and backups are kept until `purge_trash` removes
+    /// them, so `restore_slot` can bring it back.
+    pub fn delete_slot(&mut self, slot_id: &str) -> SaveManagerResult<()> {
+        let meta_conn = self.metadata_connection()?;
+        let file_path = slot_file_path(&meta_conn, slot_id)?;
+
+        for handle in self.handles_for_path(Path::new(&file_path)) {
+            self.close_connection(handle);
+        }
+
+        let (now, _) = self.now_timestamp();
+        trash::mark_deleted(&meta_conn, slot_id, &now)?;
+        self.record_change(slot_id, SlotEventKind::Deleted, None)
+    }
+
+    /// Moves several slots to the trash in one metadata transaction and
+    /// returns the ids that were trashed; ids that don't exist (any more) are
+    /// skipped.
+    pub fn delete_slots(&mut self, slot_ids: &[String]) -> SaveManagerResult<Vec<String>> {
+        let meta_conn = self.metadata_connection()?;
+        let tx = meta_conn.unchecked_transaction()?;
+        let (now, now_ms) = self.now_timestamp();
+        let mut removed = Vec::new();
+        for slot_id in slot_ids {
+            let file_path = match slot_file_path(&tx, slot_id) {
+                Ok(file_path) => PathBuf::from(file_path),
+                Err(SaveManagerError::SlotNotFound(_)) => continue,
+                Err(err) => return Err(err),
+            };
+            for handle in self.handles_for_path(&file_path) {
+                self.close_connection(handle);
+            }
+            trash::mark_deleted(&tx, slot_id, &now)?;
+            journal::append(&tx, now_ms, slot_id, SlotEventKind::Deleted, None)?;
+            removed.push(slot_id.clone());
+        }
+        tx.commit()?;
+
+        for slot_id in &removed {
+            events::emit(slot_id, SlotEventKind::Deleted);
+        }
+        Ok(removed)
+    }
+
+    /// Closes every slot, empties the saves directory (autosaves and
+    /// checkpoints included) and clears the slot tables, trash included. Settings are kept.
+    pub fn delete_all_slots(&mut self) -> SaveManagerResult<()> {
+        self.close_all_slots();
+        self.autosave = None;
+        self.autosave_payload = None;
+
+        let meta_conn = self.metadata_connection()?;
+        let slot_ids = {
+            let mut stmt =
+                meta_conn.prepare("SELECT id FROM save_slots WHERE deleted_at IS NULL")?;
+            let ids = stmt
+                .query_map([], |row| row.get::<_, String>(0))?
+                .collect::<Result<Vec<_>, _>>()?;
+            ids
+        };
+
+        fs::remove_dir_all(&self.saves_dir)?;
+        fs::create_dir_all(&self.saves_dir)?;
+        // Tags and checkpoint rows cascade from the slots.
+        meta_conn.execute("DELETE FROM save_slots", [])?;
+
+        for slot_id in &slot_ids {
+            self.record_change(slot_id, SlotEventKind::Deleted, None)?;
+        }
+        Ok(())
+    }
+
+    fn connection(&self, handle: SlotHandle) -> SaveManagerResult<&Connection> {
+        self.open_slots
+            .get(&handle)
+            .map(|slot| &slot.conn)
+            .ok_or_else(|| missing_handle(handle))
+    }
+
+    fn slot_entry(&self, handle: SlotHandle) -> SaveManagerResult<&OpenSlot> {
+        self.open_slots
+            .get(&handle)
+            .ok_or_else(|| missing_handle(handle))
+    }
+
+    fn slot_entry_mut(&mut self, handle: SlotHandle) -> SaveManagerResult<&mut OpenSlot> {
+        self.open_slots
+            .get_mut(&handle)
+            .ok_or_else(|| missing_handle(handle))
+    }
+
+    /// Recreates the saves directory if something outside the app removed
+    /// it, e.g. the OS clearing app storage. Returns whether it had to.
+    fn restore_saves_dir(&self) -> io::Result<bool> {
+        if self.saves_dir.exists() {
+            return Ok(false);
+        }
+        fs::create_dir_all(&self.saves_dir)?;
+        Ok(true)
+    }
+
+    /// An open connection keeps writing to its unlinked file without any
+    /// error once the saves directory is gone, so saves check up front
+    /// instead of retrying on failure. The handle gets a fresh, empty slot
+    /// database at the same path for the save to land in.
+    fn reopen_if_saves_dir_vanished(&mut self, handle: SlotHandle) -> SaveManagerResult<()> {
+        if !self.restore_saves_dir()? {
+            return Ok(());
+        }
+        let path = PathBuf::from(self.slot_entry(handle)?.conn.path().unwrap_or_default());
+        if path.exists() {
+            // The slot was moved out of the saves directory and is intact.
+            return Ok(());
+        }
+
+        let mut conn = self.open_connection(&path)?;
+        run_migrations(&self.slot_migrations, &mut conn)?;
+        let slot = self.slot_entry_mut(handle)?;
+        transaction::abandon(&slot.conn);
+        slot.conn = conn;
+        slot.readers = read_pool::ReadPool::default();
+        Ok(())
+    }
+
+    /// Folds a slot file's WAL into it, through the handle that has it open
+    /// if there is one, so the file can be copied whole.
+    fn checkpoint_slot_file(&self, path: &Path) -> SaveManagerResult<()> {
+        match self.handles_for_path(path).first() {
+            Some(handle) => checkpoint(&self.open_slots[handle].conn)?,
+            None => checkpoint(&self.open_connection(path)?)?,
+        }
+        Ok(())
+    }
+
+    /// Every open handle whose connection points at `path`.
+    fn handles_for_path(&self, path: &Path) -> Vec<SlotHandle> {
+        self.open_slots
+            .iter()
+            .filter(|(_, slot)| {
+                slot.conn
+                    .path()
+                    .is_some_and(|open| same_file_path(Path::new(open), path))
+            })
+            .map(|(handle, _)| *handle)
+            .collect()
+    }
+
+    /// Closes a handle's connection after a best-effort checkpoint, so the
+    /// slot's `.db` file is complete once its handle is gone. A manual
+    /// transaction still open on it is rolled back.
+    fn close_connection(&mut self, handle: SlotHandle) {
+        if let Some(OpenSlot { conn, .. }) = self.open_slots.remove(&handle) {
+            transaction::abandon(&conn);
+            let _ = checkpoint(&conn);
+            // Dropping also closes; `close` just lets us ignore the error path.
+            let _ = conn.close();
+        }
+    }
+}
+
+fn missing_handle(handle: SlotHandle) -> SaveManagerError {
+    if handle == DEFAULT_HANDLE {
+        SaveManagerError::NoActiveSlot
+    } else {
+        SaveManagerError::UnknownHandle(handle.value)
+    }
+}
+
+/// Replaces the stats row and the whole inventory in a single transaction,
+/// stamps `app_save_version` and bumps the revision. With `expected_revision`, nothing is written
+/// unless the stored revision still matches it.
+fn write_player_data(
+    conn: &mut Connection,
+    data: &PlayerData,
+    checksum: Option<&str>,
+    app_save_version: u32,
+    expected_revision: Option<i64>,
+) -> SaveManagerResult<SaveResult> {
+    // IMMEDIATE takes the write lock before the revision is read, so no
+    // other connection can save in between.
+    let tx = conn.transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)?;
+    let current = current_revision(&tx)?;
+    if expected_revision.is_some_and(|expected| expected != current) {
+        return Err(SaveManagerError::RevisionConflict { current });
+    }
+    let revision = current + 1;
+    let stats_rows = tx.execute(
+        "INSERT OR REPLACE INTO player_stats
+             (id, health, experience, save_checksum, revision, app_save_version)
+         VALUES (1, ?1, ?2, ?3, ?4, ?5)",
+        params![
+            data.health,
+            data.experience,
+            checksum,
+            revision,
+            app_save_version
+        ],
+    )?;
+    tx.execute("DELETE FROM inventory", [])?;
+    let mut inventory_rows = 0;
+    {
+        let mut stmt = tx.prepare("INSERT INTO inventory (position, item) VALUES (?1, ?2)")?;
+        for (position, item) in data.inventory.iter().enumerate() {
+            inventory_rows += stmt.execute(params![position as i64, item])?;
+        }
+    }
+    tx.commit()?;
+    Ok(SaveResult {
+        stats_updated: stats_rows > 0,
+        inventory_rows,
+        revision,
+    })
+}
+
+fn current_revision(conn: &Connection) -> rusqlite::Result<i64> {
+    Ok(conn
+        .query_row(
+            "SELECT revision FROM player_stats WHERE id = 1",
+            [],
+            |row| row.get(0),
+        )
+        .optional()?
+        .unwrap_or(0))
+}
+
+fn read_player_data(conn: &Connection) -> SaveManagerResult<Option<PlayerData>> {
+    let Some((health, experience)) = read_player_stats(conn)? else {
+        return Ok(None);
+    };
+    Ok(Some(PlayerData {
+        health,
+        experience,
+        inventory: read_inventory(conn)?,
+    }))
+}
+
+/// Health and experience, or `None` if the slot was never saved.
+fn read_player_stats(conn: &Connection) -> rusqlite::Result<Option<(i32, i32)>> {
+    conn.query_row(
+        "SELECT health, experience FROM player_stats WHERE id = 1",
+        [],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    )
+    .optional()
+}
+
+fn read_inventory(conn: &Connection) -> rusqlite::Result<Vec<String>> {
+    let mut stmt = conn.prepare("SELECT item FROM inventory ORDER BY position")?;
+    let inventory = stmt
+        .query_map([], |row| row.get(0))?
+        .collect::<Result<Vec<String>, _>>()?;
+    Ok(inventory)
+}
+
+/// Column list matching the field order `slot_from_row` expects.
+/// `created_at` has no epoch column of its own, so it is converted here; a
+/// value SQLite can't parse comes back as 0.
+const SLOT_COLUMNS: &str = "id, name, last_played, created_at, file_path, play_time_seconds, \
+     description, last_played_ms, \
+     COALESCE(CAST((julianday(created_at) - 2440587.5) * 86400000 AS INTEGER), 0)";
+
+fn slot_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<SaveSlotMetadata> {
+    Ok(SaveSlotMetadata {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        last_played: row.get(2)?,
+        created_at: row.get(3)?,
+        file_path: row.get(4)?,
+        play_time_seconds: row.get(5)?,
+        description: row.get::<_, Option<String>>(6)?.unwrap_or_default(),
+        last_played_epoch_ms: row.get(7)?,
+        created_at_epoch_ms: row.get(8)?,
+        // Only the single-slot lookup pays for the tag query.
+        tags: Vec::new(),
+    })
+}
+
+/// Applies pending migrations. A damaged file is reported as a database error
+/// rather than a migration one, so callers can recognise it.
+fn run_migrations(migrations: &Migrations, conn: &mut Connection) -> SaveManagerResult<()> {
+    migrations.to_latest(conn).map_err(migration_error)
+}
+
+fn migration_error(err: migrations::Error) -> SaveManagerError {
+    match err {
+        migrations::Error::Database(err) if is_corruption(&err) => SaveManagerError::Database(err),
+        err => SaveManagerError::Migration(err.to_string()),
+    }
+}
+
+/// Whether SQLite rejected the file as damaged or as not a database at all.
+fn is_corruption(err: &rusqlite::Error) -> bool {
+    matches!(
+        err.sqlite_error_code(),
+        Some(rusqlite::ErrorCode::DatabaseCorrupt | rusqlite::ErrorCode::NotADatabase)
+    )
+}
+
+/// Metadata databases created before `METADATA_DB_MIGRATIONS` existed have the
+/// schema but no recorded version; stamp the version their columns match so
+/// the migrations don't try to re-add them.
+fn adopt_unversioned_metadata_db(conn: &Connection) -> SaveManagerResult<()> {
+    if migrations::current_version(conn)? != 0 || !table_has_column(conn, "save_slots", "id")? {
+        return Ok(());
+    }
+
+    let version = if table_has_column(conn, "save_slots", "play_time_seconds")? {
+        2
+    } else {
+        1
+    };
+    conn.pragma_update(None, "user_version", version)?;
+    Ok(())
+}
+
+/// Every `save_slots` column the manager reads or writes.
+const SAVE_SLOTS_COLUMNS: [&str; 10] = [
+    "id",
+    "name",
+    "file_path",
+    "last_played",
+    "play_time_seconds",
+    "thumbnail",
+    "created_at",
+    "description",
+    "deleted_at",
+    "last_played_ms",
+];
+
+/// Fails with `SchemaMismatch` if `save_slots` lacks columns the manager
+/// uses. Migrations add them all, so this only trips on a database whose
+/// version says it is up to date while its table says otherwise, e.g. one
+/// edited by hand or by a broken older build. Better that than a `no such
+/// column` error from whichever query happens to run first.
+fn verify_metadata_schema(conn: &Connection) -> SaveManagerResult<()> {
+    let mut missing = Vec::new();
+    for column in SAVE_SLOTS_COLUMNS {
+        if !table_has_column(conn, "save_slots", column)? {
+            missing.push(column.to_owned());
+        }
+    }
+    if !missing.is_empty() {
+        return Err(SaveManagerError::SchemaMismatch { missing });
+    }
+    Ok(())
+}
+
+fn table_has_column(conn: &Connection, table: &str, column: &str) -> rusqlite::Result<bool> {
+    let mut stmt = conn.prepare("SELECT 1 FROM pragma_table_info(?1) WHERE name = ?2")?;
+    stmt.exists(params![table, column])
+}
+
+/// Config values become single path components, so they must not be empty
+/// or able to climb out of the base path.
+fn validate_directory_name(what: &str, name: &str) -> SaveManagerResult<()> {
+    let mut components = Path::new(name).components();
+    match (components.next(), components.next()) {
+        (Some(Component::Normal(_)), None) => Ok(()),
+        _ => Err(SaveManagerError::InvalidConfig(format!(
+            "{what} must be a single directory name, got {name:?}"
+        ))),
+    }
+}
+
+/// Loads one slot's metadata, including its tags.
+fn fetch_slot(meta_conn: &Connection, slot_id: &str) -> SaveManagerResult<SaveSlotMetadata> {
+    let mut slot = meta_conn
+        .query_row(
+            &format!("SELECT {SLOT_COLUMNS} FROM save_slots WHERE id = ?1 AND deleted_at IS NULL"),
+            params![slot_id],
+            slot_from_row,
+        )
+        .optional()?
+        .ok_or_else(|| SaveManagerError::SlotNotFound(slot_id.to_owned()))?;
+    slot.tags = tags::slot_tags(meta_conn, slot_id)?;
+    Ok(slot)
+}
+
+/// Looks up the database file backing `slot_id` in the metadata table.
+fn slot_file_path(meta_conn: &Connection, slot_id: &str) -> SaveManagerResult<String> {
+    meta_conn
+        .query_row(
+            "SELECT file_path FROM save_slots WHERE id = ?1 AND deleted_at IS NULL",
+            params![slot_id],
+            |row| row.get(0),
+        )
+        .optional()?
+        .ok_or_else(|| SaveManagerError::SlotNotFound(slot_id.to_owned()))
+}
+
+fn open_configured_connection(
+    path: &Path,
+    encryption_key: Option<&str>,
+    options: &pragmas::SqliteOptions,
+) -> SaveManagerResult<Connection> {
+    let conn = Connection::open(path)?;
+    unlock(&conn, encryption_key)?;
+    conn.pragma_update(None, "journal_mode", "WAL")?;
+    // The bundled build already turns these on, but stock SQLite leaves them
+    // off and the schemas rely on `ON DELETE CASCADE`.
+    conn.pragma_update(None, "foreign_keys", true)?;
+    options.apply(&conn)?;
+    Ok(conn)
+}
+
+/// Opens an existing database without write access, for operations that only
+/// inspect a slot. Unlike `open_configured_connection` it never creates the
+/// file or changes its journal mode, and it doesn't take write locks.
+fn open_readonly_connection(
+    path: &Path,
+    encryption_key: Option<&str>,
+    options: &pragmas::SqliteOptions,
+) -> SaveManagerResult<Connection> {
+    let conn = Connection::open_with_flags(
+        path,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )?;
+    unlock(&conn, encryption_key)?;
+    options.apply_busy_timeout(&conn)?;
+    Ok(conn)
+}
+
+/// Applies the SQLCipher key, which must happen before anything else touches
+/// the database. A wrong key only shows up on the first read, as a "not a
+/// database" error, so read once here to report it as `DecryptionFailed`
+/// instead of corruption.
+fn unlock(conn: &Connection, encryption_key: Option<&str>) -> SaveManagerResult<()> {
+    let Some(key) = encryption_key else {
+        return Ok(());
+    };
+    conn.pragma_update(None, "key", key)?;
+    match conn.query_row("SELECT count(*) FROM sqlite_master", [], |_| Ok(())) {
+        Ok(()) => Ok(()),
+        Err(err) if is_corruption(&err) => Err(SaveManagerError::DecryptionFailed),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Folds the WAL back into the main database file and truncates it.
+fn checkpoint(conn: &Connection) -> rusqlite::Result<()> {
+    conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))
+}
+
+/// Vacuums, then truncates the WAL that `VACUUM` wrote the rebuilt pages
+/// into, so the space is actually returned to the filesystem.
+fn vacuum(conn: &Connection) -> SaveManagerResult<()> {
+    conn.execute_batch("VACUUM")?;
+    Ok(checkpoint(conn)?)
+}
+
+/// Compares paths after resolving symlinks and relative components, falling
+/// back to a literal comparison when either side no longer exists.
+fn same_file_path(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+/// Deletes a database file together with its `-wal` and `-shm` sidecars,
+/// ignoring any that are already gone. Every path that deletes a slot,
+/// backup or checkpoint goes through this (or `move_slot_files`), so a stray
+/// sidecar can't be mistaken for a crashed session's WAL later.
+fn remove_slot_files(path: &Path) -> io::Result<()> {
+    for file in slot_files(path) {
+        remove_file_if_exists(&file)?;
+    }
+    Ok(())
+}
+
+/// Moves a database and whichever sidecars exist from `from` to `to`. A
+/// rename can't cross filesystems, so that case falls back to copying and
+/// then deleting the original.
+fn move_slot_files(from: &Path, to: &Path) -> io::Result<()> {
+    for (source, target) in slot_files(from).iter().zip(slot_files(to)) {
+        match fs::rename(source, &target) {
+            Err(err) if err.kind() == io::ErrorKind::CrossesDevices => {
+                fs::copy(source, &target)?;
+                fs::remove_file(source)?;
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            result => result?,
+        }
+    }
+    Ok(())
+}
+
+/// A slot database and its WAL-mode sidecar files, which may not all exist.
+fn slot_files(path: &Path) -> [PathBuf; 3] {
+    let sidecar = |suffix: &str| {
+        let mut sidecar = path.as_os_str().to_owned();
+        sidecar.push(suffix);
+        PathBuf::from(sidecar)
+    };
+    [path.to_path_buf(), sidecar("-wal"), sidecar("-shm")]
+}
+
+/// Combined size of a database and whichever of its sidecars exist.
+fn slot_files_size(path: &Path) -> io::Result<u64> {
+    let mut total = 0;
+    for file in slot_files(path) {
+        total += file_size(&file)?;
+    }
+    Ok(total)
+}
+
+/// Size of the file at `path`, or zero if there is none.
+fn file_size(path: &Path) -> io::Result<u64> {
+    match fs::metadata(path) {
+        Ok(metadata) => Ok(metadata.len()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(0),
+        Err(err) => Err(err),
+    }
+}
+
+/// Total size of the files under `dir`, including subdirectories.
+fn directory_size(dir: &Path) -> io::Result<u64> {
+    let mut total = 0;
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            total += directory_size(&entry.path())?;
+        } else if file_type.is_file() {
+            total += entry.metadata()?.len();
+        }
+    }
+    Ok(total)
+}
+
+fn epoch_ms(time: SystemTime) -> i64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as i64)
+        .unwrap_or(0)
+}
+
+/// Formats a point in time the same way SQLite's `datetime()` does
+/// (`%Y-%m-%d %H:%M:%S`, UTC), so it sorts alongside `last_played`.
+fn format_timestamp(time: SystemTime) -> String {
+    let secs = epoch_ms(time).div_euclid(1_000);
+    let (year, month, day, secs_of_day) = civil_from_epoch_secs(secs);
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02}",
+        secs_of_day / 3_600,
+        secs_of_day % 3_600 / 60,
+        secs_of_day % 60
+    )
+}
+
+/// Formats Unix epoch milliseconds as ISO 8601 in UTC, e.g.
+/// `2024-05-01T18:30:00.250Z`.
+pub fn iso8601_from_epoch_ms(ms: i64) -> String {
+    let (year, month, day, secs_of_day) = civil_from_epoch_secs(ms.div_euclid(1_000));
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        secs_of_day / 3_600,
+        secs_of_day % 3_600 / 60,
+        secs_of_day % 60,
+        ms.rem_euclid(1_000)
+    )
+}
+
+/// Splits epoch seconds into a UTC date and the seconds into that day.
+fn civil_from_epoch_secs(secs: i64) -> (i64, i64, i64, i64) {
+    let (days, secs_of_day) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+
+    // Civil-from-days conversion (H. Hinnant), valid for the proleptic
+    // Gregorian calendar.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day, secs_of_day)
+}
+
+fn remove_file_if_exists(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_base(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("save_manager_{name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        path
+    }
+
+    /// A manager whose clock is stuck at 2024-05-01 18:30:00.250 UTC and
+    /// whose ids count up from 1.
+    fn deterministic_manager(base: &Path) -> SaveManager {
+        let now = UNIX_EPOCH + std::time::Duration::from_millis(1_714_588_200_250);
+        SaveManager::initialize_with_sources(
+            base,
+            SaveConfig::default(),
+            Box::new(sources::FixedClock(now)),
+            Box::<sources::SequentialIds>::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn slot_creation_uses_the_injected_clock_and_ids() {
+        let base = temp_base("deterministic_sources");
+        let manager = deterministic_manager(&base);
+
+        let first = manager.create_slot("First").unwrap();
+        let second = manager.create_slot("Second").unwrap();
+        assert_eq!(first.id, "00000000-0000-0000-0000-000000000001");
+        assert_eq!(second.id, "00000000-0000-0000-0000-000000000002");
+        assert_eq!(first.last_played, "2024-05-01 18:30:00");
+        assert_eq!(first.created_at, "2024-05-01 18:30:00");
+        assert_eq!(first.last_played_epoch_ms, 1_714_588_200_250);
+        assert_eq!(
+            manager.get_slot(&second.id).unwrap().last_played_iso8601(),
+            "2024-05-01T18:30:00.250Z"
+        );
+
+        drop(manager);
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn created_slot_matches_its_stored_row() {
+        let base = temp_base("create_matches_get");
+        let manager = deterministic_manager(&base);
+
+        let created = manager.create_slot("Fresh").unwrap();
+        let fetched = manager.get_slot(&created.id).unwrap();
+        assert_eq!(created.id, fetched.id);
+        assert_eq!(created.name, "Fresh");
+        assert_eq!(created.name, fetched.name);
+        assert_eq!(created.last_played, fetched.last_played);
+        assert_eq!(created.last_played_epoch_ms, fetched.last_played_epoch_ms);
+        assert_eq!(created.created_at, "2024-05-01 18:30:00");
+        assert_eq!(created.created_at, fetched.created_at);
+        assert_eq!(created.created_at_epoch_ms, 1_714_588_200_000);
+        assert_eq!(created.created_at_epoch_ms, fetched.created_at_epoch_ms);
+        assert_eq!(created.file_path, fetched.file_path);
+        assert_eq!(created.play_time_seconds, 0);
+        assert_eq!(created.play_time_seconds, fetched.play_time_seconds);
+        assert_eq!(created.description, "");
+        assert_eq!(created.description, fetched.description);
+        assert!(created.tags.is_empty());
+        assert_eq!(created.tags, fetched.tags);
+
+        drop(manager);
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn reads_succeed_while_a_write_is_in_flight() {
+        let base = temp_base("concurrent_read");
+        let mut manager = SaveManager::initialize(&base, SaveConfig::default()).unwrap();
+        let slot = manager.create_slot("Reader").unwrap();
+        manager.load_slot(&slot.id).unwrap();
+        let data = PlayerData {
+            health: 7,
+            experience: 3,
+            inventory: vec!["sword".to_owned()],
+        };
+        manager.save_player_data(DEFAULT_HANDLE, &data).unwrap();
+
+        let writer = &manager.slot_entry(DEFAULT_HANDLE).unwrap().conn;
+        writer
+            .execute_batch("BEGIN IMMEDIATE; UPDATE player_stats SET health = 99 WHERE id = 1;")
+            .unwrap();
+
+        let loaded = manager.load_player_data(DEFAULT_HANDLE).unwrap();
+        assert_eq!(loaded.health, 7);
+        assert_eq!(manager.get_flag(DEFAULT_HANDLE, "missing").unwrap(), None);
+
+        let writer = &manager.slot_entry(DEFAULT_HANDLE).unwrap().conn;
+        writer.execute_batch("COMMIT;").unwrap();
+        drop(manager);
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn saves_retry_while_another_connection_holds_the_lock() {
+        let base = temp_base("busy_retry");
+        let mut manager = SaveManager::initialize(&base, SaveConfig::default()).unwrap();
+        // Fail straight away on a lock instead of waiting the default 5 s.
+        manager.set_sqlite_options(0, "FULL").unwrap();
+        let slot = manager.create_slot("Contended").unwrap();
+        manager.load_slot(&slot.id).unwrap();
+        let data = PlayerData {
+            health: 9,
+            experience: 4,
+            inventory: vec!["shield".to_owned()],
+        };
+
+        let other = Connection::open(&slot.file_path).unwrap();
+        other.execute_batch("BEGIN IMMEDIATE;").unwrap();
+        manager.set_busy_retries(0);
+        assert!(matches!(
+            manager.save_player_data(DEFAULT_HANDLE, &data),
+            Err(SaveManagerError::SlotLocked(id)) if id == slot.id
+        ));
+
+        // Released during the first backoff, so the first retry gets through.
+        let release = std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            other.execute_batch("COMMIT;").unwrap();
+        });
+        manager.set_busy_retries(3);
+        let result = manager.save_player_data(DEFAULT_HANDLE, &data).unwrap();
+        release.join().unwrap();
+        assert_eq!(result.revision, 1);
+        assert_eq!(manager.load_player_data(DEFAULT_HANDLE).unwrap().health, 9);
+
+        drop(manager);
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn purging_a_slot_removes_its_sidecar_files() {
+        let base = temp_base("purge_sidecars");
+        let mut manager = SaveManager::initialize(&base, SaveConfig::default()).unwrap();
+        let slot = manager.create_slot("Doomed").unwrap();
+        manager.delete_slot(&slot.id).unwrap();
+
+        // What a crash with the slot open would have left behind.
+        let files = slot_files(Path::new(&slot.file_path));
+        for sidecar in &files[1..] {
+            fs::write(sidecar, b"stale").unwrap();
+        }
+        assert!(files.iter().all(|file| file.exists()));
+
+        assert_eq!(manager.purge_trash(0).unwrap(), vec![slot.id]);
+        for file in &files {
+            assert!(!file.exists(), "{} was left behind", file.display());
+        }
+
+        drop(manager);
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn in_memory_metadata_leaves_no_metadata_file() {
+        let base = temp_base("in_memory_metadata");
+        let mut manager = SaveManager::initialize_in_memory(&base).unwrap();
+        let slot = manager.create_slot("Ephemeral").unwrap();
+        manager.set_keep_metadata_connection(false);
+        assert_eq!(manager.get_slot(&slot.id).unwrap().name, "Ephemeral");
+        assert!(Path::new(&slot.file_path).exists());
+        assert!(!base.join(DEFAULT_APP_NAME).join(METADATA_DB_FILE).exists());
+
+        drop(manager);
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn saves_are_refused_while_their_slot_is_migrating() {
+        let base = temp_base("save_during_migration");
+        let mut manager = SaveManager::initialize_in_memory(&base).unwrap();
+        let slot = manager.create_slot("Upgrading").unwrap();
+        let other = manager.create_slot("Bystander").unwrap();
+        manager.load_slot(&slot.id).unwrap();
+        let data = PlayerData {
+            health: 3,
+            experience: 8,
+            inventory: Vec::new(),
+        };
+
+        // A save sent from another thread waits on the worker until the
+        // migration is done (see the test above), so set the mark by hand
+        // the way `open_migrated_slot` does while its migrations run.
+        *manager.migrating.borrow_mut() = Some(slot.id.clone());
+
+        assert!(matches!(
+            manager.save_player_data(DEFAULT_HANDLE, &data),
+            Err(SaveManagerError::SlotBusy(id)) if id == slot.id
+        ));
+        let handle = manager.open_slot(&other.id).unwrap();
+        manager.save_player_data(handle, &data).unwrap();
+
+        manager.migrating.borrow_mut().take();
+        let result = manager.save_player_data(DEFAULT_HANDLE, &data).unwrap();
+        assert_eq!(result.revision, 1);
+
+        drop(manager);
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    /// A load that has to migrate, and a save sent from another thread
+    /// through the public API while the migration runs on the worker.
+    #[test]
+    fn a_save_sent_during_a_slow_migration_waits_for_it() {
+        const SCHEMA: &str = "CREATE TABLE player_stats (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                health INTEGER NOT NULL,
+                experience INTEGER NOT NULL,
+                save_checksum TEXT,
+                revision INTEGER NOT NULL DEFAULT 0,
+                app_save_version INTEGER NOT NULL DEFAULT 0
+            );
+            CREATE TABLE inventory (
+                position INTEGER PRIMARY KEY,
+                item TEXT NOT NULL
+            );";
+        const SLOW_STEP: &str = "CREATE TABLE filler AS
+            WITH RECURSIVE n (i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 500000)
+            SELECT i FROM n;";
+        let _global = worker::GLOBAL_MANAGER_TEST_LOCK
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let base = temp_base("save_during_slow_migration");
+        let data = |health| PlayerData {
+            health,
+            experience: 8,
+            inventory: vec!["Map".to_owned()],
+        };
+        // The manager can't leave the worker thread, so it is set up there:
+        // a slot saved at schema version 1, then a manager whose slots have
+        // a slow second step to migrate through.
+        let worker_base = base.clone();
+        let slot = worker::run(move |current| {
+            let mut manager = SaveManager::initialize(&worker_base, SaveConfig::default())?;
+            manager.slot_migrations = Arc::new(Migrations::new(vec![M::up(SCHEMA)]));
+            let slot = manager.create_slot("Upgrading")?;
+            manager.load_slot(&slot.id)?;
+            manager.save_player_data(DEFAULT_HANDLE, &data(1))?;
+            manager.unload_slot();
+            manager.slot_migrations =
+                Arc::new(Migrations::new(vec![M::up(SCHEMA), M::up(SLOW_STEP)]));
+            *current = Some(manager);
+            SaveManagerResult::Ok(slot)
+        })
+        .unwrap()
+        .unwrap();
+
+        let (started_sender, started) = std::sync::mpsc::channel();
+        let slot_id = slot.id.clone();
+        let loader = std::thread::spawn(move || {
+            worker::run(move |current| {
+                let manager = current.as_mut().unwrap();
+                started_sender.send(()).unwrap();
+                manager.load_slot(&slot_id)
+            })
+        });
+        // The worker is inside `load_slot` now, so the save can only be
+        // queued behind the migration, never run in the middle of it.
+        started.recv().unwrap();
+        let saved = crate::api::saves::save_player_data(data(2)).unwrap();
+        loader.join().unwrap().unwrap().unwrap();
+
+        assert_eq!(saved.revision, 2);
+        assert_eq!(crate::api::saves::load_player_data().unwrap().health, 2);
+        assert_eq!(
+            crate::api::saves::slot_schema_version(slot.id.clone()).unwrap(),
+            2
+        );
+        assert!(crate::api::saves::list_migration_backups()
+            .unwrap()
+            .is_empty());
+
+        crate::api::saves::close_system().unwrap();
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn saves_warn_but_go_ahead_when_disk_space_is_low() {
+        let base = temp_base("low_disk_space");
+        let mut manager = SaveManager::initialize_in_memory(&base).unwrap();
+        manager.disk_space = Box::new(sources::FixedDiskSpace(1024));
+        let slot = manager.create_slot("Cramped").unwrap();
+        manager.load_slot(&slot.id).unwrap();
+        let data = PlayerData {
+            health: 5,
+            experience: 2,
+            inventory: vec!["potion".to_owned()],
+        };
+
+        let (sender, received) = std::sync::mpsc::channel();
+        events::subscribe(move |event| sender.send(event.clone()).is_ok());
+        let result = manager.save_player_data(DEFAULT_HANDLE, &data).unwrap();
+        assert_eq!(result.revision, 1);
+        let kinds: Vec<_> = received
+            .try_iter()
+            .filter(|event| event.slot_id == slot.id)
+            .map(|event| event.kind)
+            .collect();
+        assert!(matches!(
+            kinds.as_slice(),
+            [SlotEventKind::LowDiskSpace, SlotEventKind::Saved]
+        ));
+
+        manager
+            .save_player_data_with_thumbnail(DEFAULT_HANDLE, &data, b"png")
+            .unwrap();
+        assert!(received
+            .try_iter()
+            .any(|event| event.slot_id == slot.id && event.kind == SlotEventKind::LowDiskSpace));
+
+        manager.set_low_disk_space_threshold(0);
+        manager.save_player_data(DEFAULT_HANDLE, &data).unwrap();
+        assert!(received
+            .try_iter()
+            .all(|event| event.slot_id != slot.id || event.kind != SlotEventKind::LowDiskSpace));
+
+        drop(manager);
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn recreates_a_deleted_saves_directory() {
+        let base = temp_base("deleted_saves_dir");
+        let mut manager = SaveManager::initialize(&base, SaveConfig::default()).unwrap();
+        fs::remove_dir_all(manager.saves_dir()).unwrap();
+
+        let slot = manager.create_slot("Survivor").unwrap();
+        assert!(Path::new(&slot.file_path).exists());
+
+        manager.load_slot(&slot.id).unwrap();
+        fs::remove_dir_all(manager.saves_dir()).unwrap();
+        let data = PlayerData {
+            health: 5,
+            experience: 1,
+            inventory: Vec::new(),
+        };
+        manager.save_player_data(DEFAULT_HANDLE, &data).unwrap();
+        assert!(Path::new(&slot.file_path).exists());
+        assert_eq!(manager.load_player_data(DEFAULT_HANDLE).unwrap().health, 5);
+
+        drop(manager);
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn deleting_a_slot_row_cascades_to_its_tags_and_checkpoints() {
+        let base = temp_base("foreign_key_cascade");
+        let mut manager = SaveManager::initialize_in_memory(&base).unwrap();
+        let slot = manager.create_slot("Parent").unwrap();
+        let other = manager.create_slot("Sibling").unwrap();
+        manager.add_slot_tag(&slot.id, "boss").unwrap();
+        manager.add_slot_tag(&other.id, "boss").unwrap();
+        manager.load_slot(&slot.id).unwrap();
+        manager.create_checkpoint(DEFAULT_HANDLE, "before").unwrap();
+
+        let conn = manager.metadata_connection().unwrap();
+        conn.execute("DELETE FROM save_slots WHERE id = ?1", [&slot.id])
+            .unwrap();
+        let count = |table: &str| -> i64 {
+            conn.query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |row| {
+                row.get(0)
+            })
+            .unwrap()
+        };
+        assert_eq!(count("slot_tags"), 1);
+        assert_eq!(count("slot_checkpoints"), 0);
+        assert_eq!(manager.get_slot(&other.id).unwrap().tags, vec!["boss"]);
+
+        drop(conn);
+        drop(manager);
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn autosaves_expire_by_the_time_they_were_written() {
+        const DAY: std::time::Duration = std::time::Duration::from_secs(24 * 60 * 60);
+
+        let base = temp_base("autosave_expiry");
+        let mut manager = deterministic_manager(&base);
+        let slot = manager.create_slot("Aging").unwrap();
+        manager.configure_autosave(&slot.id, 5).unwrap();
+        manager.set_autosave_retention(0, 7);
+        let data = PlayerData {
+            health: 10,
+            experience: 0,
+            inventory: Vec::new(),
+        };
+
+        // The files are all written within a second of each other, so only
+        // the recorded write times tell these backups apart.
+        let start = manager.clock.now();
+        for days in [0, 8, 13] {
+            manager.clock = Box::new(sources::FixedClock(start + DAY * days));
+            manager.autosave(&data).unwrap();
+        }
+        let saved_at: Vec<_> = manager
+            .list_autosaves(&slot.id)
+            .unwrap()
+            .into_iter()
+            .map(|autosave| (autosave.index, autosave.saved_at))
+            .collect();
+        assert_eq!(
+            saved_at,
+            [
+                (0, format_timestamp(start + DAY * 13)),
+                (1, format_timestamp(start + DAY * 8)),
+            ]
+        );
+
+        drop(manager);
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn autosave_expiry_spares_the_newest_backup() {
+        /// Jumps a year ahead every time it is read, so each backup is
+        /// already past any age limit once the pruning looks at it.
+        struct RacingClock(std::cell::Cell<SystemTime>);
+
+        impl sources::Clock for RacingClock {
+            fn now(&self) -> SystemTime {
+                let now = self.0.get() + std::time::Duration::from_secs(365 * 24 * 60 * 60);
+                self.0.set(now);
+                now
+            }
+        }
+
+        let base = temp_base("autosave_expiry_newest");
+        let mut manager = deterministic_manager(&base);
+        let slot = manager.create_slot("Ancient").unwrap();
+        manager.configure_autosave(&slot.id, 5).unwrap();
+        manager.set_autosave_retention(0, 1);
+        manager.clock = Box::new(RacingClock(std::cell::Cell::new(manager.clock.now())));
+        let data = PlayerData {
+            health: 10,
+            experience: 0,
+            inventory: Vec::new(),
+        };
+
+        manager.autosave(&data).unwrap();
+        manager.autosave(&data).unwrap();
+        let indexes: Vec<_> = manager
+            .list_autosaves(&slot.id)
+            .unwrap()
+            .into_iter()
+            .map(|autosave| autosave.index)
+            .collect();
+        assert_eq!(indexes, [0]);
+
+        drop(manager);
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    /// Times `get_all_slots` over 5,000 slots with the metadata connection
+    /// cached and reopened per call, and prints the mean of each. Only the
+    /// metadata rows are inserted; listing never opens slot files.
+    #[test]
+    #[ignore = "benchmark; run with --release -- --ignored --nocapture"]
+    fn metadata_connection_benchmark() {
+        const SLOTS: i64 = 5_000;
+        const ROUNDS: u32 = 50;
+
+        let base = temp_base("metadata_connection_benchmark");
+        let mut manager = SaveManager::initialize(&base, SaveConfig::default()).unwrap();
+        manager
+            .metadata_connection()
+            .unwrap()
+            .execute(
+                "WITH RECURSIVE n (i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < ?1)
+                 INSERT INTO save_slots (id, name, file_path, last_played, created_at, last_played_ms)
+                 SELECT 'bench-' || i, 'Slot ' || i, 'bench-' || i || '.db',
+                        '2024-05-01 18:30:00', '2024-05-01 18:30:00', i
+                 FROM n",
+                [SLOTS],
+            )
+            .unwrap();
+
+        for keep in [true, false] {
+            manager.set_keep_metadata_connection(keep);
+            assert_eq!(manager.get_all_slots().unwrap().len(), SLOTS as usize);
+            let started = std::time::Instant::now();
+            for _ in 0..ROUNDS {
+                manager.get_all_slots().unwrap();
+            }
+            println!(
+                "get_all_slots over {SLOTS} slots, connection {}: {:?} per call",
+                if keep { "cached" } else { "reopened" },
+                started.elapsed() / ROUNDS
+            );
+        }
+
+        drop(manager);
+        let _ = fs::remove_dir_all(&base);
+    }
+}
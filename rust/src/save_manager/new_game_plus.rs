@@ -0,0 +1,86 @@
+//! New Game Plus: a fresh run in a copy of a finished slot that keeps some of
+//! the player's progress.
+
+use std::path::Path;
+
+use super::{checkpoint, write_player_data, SaveManager, SaveManagerError, SaveManagerResult};
+use crate::api::saves::{PlayerData, SaveSlotMetadata, SlotEventKind};
+
+/// Health a New Game Plus run starts with by default.
+pub(super) const DEFAULT_NEW_GAME_PLUS_HEALTH: i32 = 100;
+
+impl SaveManager {
+    /// Sets the health every New Game Plus run starts with (default 100).
+    /// Lasts until the save system is re-initialized.
+    pub fn set_new_game_plus_health(&mut self, health: i32) -> SaveManagerResult<()> {
+        if health < 0 {
+            return Err(SaveManagerError::InvalidConfig(
+                "new game plus health must not be negative".to_owned(),
+            ));
+        }
+        self.new_game_plus_health = health;
+        Ok(())
+    }
+
+    /// Copies `slot_id` into a new slot named "<name> NG+" and resets it for
+    /// another run: health goes back to the starting value, experience keeps
+    /// `experience_carryover_pct` percent (0 to 100, rounded down), and the
+    /// inventory is kept only if `keep_inventory` is set. The source slot is
+    /// left as it was, and must have been saved.
+    pub fn start_new_game_plus(
+        &mut self,
+        slot_id: &str,
+        keep_inventory: bool,
+        experience_carryover_pct: f32,
+    ) -> SaveManagerResult<SaveSlotMetadata> {
+        if !(0.0..=100.0).contains(&experience_carryover_pct) {
+            return Err(SaveManagerError::InvalidPlayerData {
+                field: "experience_carryover_pct",
+                reason: "must be between 0 and 100".to_owned(),
+            });
+        }
+        let source = self.get_slot(slot_id)?;
+        let finished = self
+            .read_slot_data(slot_id)?
+            .ok_or(SaveManagerError::NoPlayerData)?;
+        let data = PlayerData {
+            health: self.new_game_plus_health,
+            experience: (f64::from(finished.experience) * f64::from(experience_carryover_pct)
+                / 100.0) as i32,
+            inventory: if keep_inventory {
+                finished.inventory
+            } else {
+                Vec::new()
+            },
+        };
+        self.validate_player_data(&data)?;
+
+        let copy = self.duplicate_slot(slot_id, &format!("{} NG+", source.name))?;
+        let written = self
+            .open_migrated_slot(&copy.id, Path::new(&copy.file_path))
+            .and_then(|mut conn| {
+                let result = write_player_data(
+                    &mut conn,
+                    &data,
+                    self.checksum_for(&data).as_deref(),
+                    self.app_save_version,
+                    None,
+                )?;
+                checkpoint(&conn)?;
+                Ok(result)
+            });
+        match written {
+            Ok(result) => {
+                self.record_change(&copy.id, SlotEventKind::Saved, Some(result.revision))?;
+                Ok(copy)
+            }
+            Err(err) => {
+                // Don't leave a copy that still holds the finished run.
+                let _ = self
+                    .purge_slot(&copy.id)
+                    .and_then(|()| self.record_change(&copy.id, SlotEventKind::Deleted, None));
+                Err(err)
+            }
+        }
+    }
+}
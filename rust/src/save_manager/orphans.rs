@@ -0,0 +1,141 @@
+//! Reconciling the saves directory with the metadata database after crashes
+//! leave one without the other: slot files nobody registered, and rows
+//! whose file is gone.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rusqlite::params;
+
+use super::{
+    migrations, move_slot_files, remove_slot_files, same_file_path, SaveManager, SaveManagerResult,
+};
+use crate::api::saves::{IntegrityReport, RepairPolicy, RepairReport, SlotEventKind};
+
+/// Name given to slots re-registered from orphaned files.
+const RECOVERED_SLOT_NAME: &str = "Recovered save";
+
+impl SaveManager {
+    /// Lists slot files in the saves directory that no row points at, and
+    /// rows (trashed ones included) whose file is missing. Changes nothing.
+    pub fn scan_integrity(&self) -> SaveManagerResult<IntegrityReport> {
+        let rows = self.registered_files()?;
+        let orphaned_files = self
+            .orphaned_files(&rows)?
+            .into_iter()
+            .map(|path| path.to_string_lossy().into_owned())
+            .collect();
+        let dangling_rows = rows
+            .into_iter()
+            .filter(|(_, path)| !path.is_file())
+            .map(|(slot_id, _)| slot_id)
+            .collect();
+        Ok(IntegrityReport {
+            orphaned_files,
+            dangling_rows,
+        })
+    }
+
+    /// Removes dangling rows and either deletes orphaned files or registers
+    /// them as new slots, depending on `policy`. Orphans that don't open as
+    /// a slot database are left alone when registering.
+    pub fn repair_integrity(&mut self, policy: RepairPolicy) -> SaveManagerResult<RepairReport> {
+        let rows = self.registered_files()?;
+        let mut report = RepairReport {
+            files_deleted: 0,
+            files_registered: 0,
+            rows_removed: 0,
+        };
+
+        for path in self.orphaned_files(&rows)? {
+            match policy {
+                RepairPolicy::DeleteOrphans => {
+                    remove_slot_files(&path)?;
+                    report.files_deleted += 1;
+                }
+                RepairPolicy::RegisterOrphans => {
+                    if self.register_orphan(&path)? {
+                        report.files_registered += 1;
+                    }
+                }
+            }
+        }
+
+        for (slot_id, path) in rows {
+            if path.is_file() {
+                continue;
+            }
+            self.purge_slot(&slot_id)?;
+            self.record_change(&slot_id, SlotEventKind::Deleted, None)?;
+            report.rows_removed += 1;
+        }
+        Ok(report)
+    }
+
+    /// Every row's id and file, trashed slots included.
+    fn registered_files(&self) -> SaveManagerResult<Vec<(String, PathBuf)>> {
+        let conn = self.metadata_connection()?;
+        let mut stmt = conn.prepare("SELECT id, file_path FROM save_slots ORDER BY id")?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    PathBuf::from(row.get::<_, String>(1)?),
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(rows)
+    }
+
+    /// Slot databases in the saves directory that no row points at. Only
+    /// `<id>.db` names are considered; autosaves and migration backups have
+    /// a dotted suffix and belong to their slot.
+    fn orphaned_files(&self, rows: &[(String, PathBuf)]) -> SaveManagerResult<Vec<PathBuf>> {
+        let mut orphans = Vec::new();
+        for entry in fs::read_dir(&self.saves_dir)? {
+            let path = entry?.path();
+            let is_slot_file = path.is_file()
+                && path.extension().is_some_and(|ext| ext == "db")
+                && path
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .is_some_and(|stem| !stem.contains('.'));
+            if is_slot_file && !rows.iter().any(|(_, row)| same_file_path(row, &path)) {
+                orphans.push(path);
+            }
+        }
+        orphans.sort();
+        Ok(orphans)
+    }
+
+    /// Registers an orphaned slot file under a fresh id, moving it to that
+    /// id's path. Returns `false` if the file isn't a readable slot.
+    fn register_orphan(&self, path: &Path) -> SaveManagerResult<bool> {
+        let readable = self
+            .open_readonly(path)
+            .and_then(|conn| Ok(migrations::current_version(&conn)?))
+            .is_ok_and(|version| version > 0);
+        if !readable {
+            return Ok(false);
+        }
+
+        let meta_conn = self.metadata_connection()?;
+        let slot_id = self.new_id(&meta_conn)?;
+        let target = self.slot_path(&slot_id);
+        move_slot_files(path, &target)?;
+        let (now, now_ms) = self.now_timestamp();
+        meta_conn.execute(
+            "INSERT INTO save_slots (id, name, file_path, last_played, created_at, last_played_ms)
+             VALUES (?1, ?2, ?3, ?4, ?4, ?5)",
+            params![
+                slot_id,
+                RECOVERED_SLOT_NAME,
+                target.to_string_lossy(),
+                now,
+                now_ms
+            ],
+        )?;
+        self.record_change(&slot_id, SlotEventKind::Created, None)?;
+        Ok(true)
+    }
+}
@@ -0,0 +1,124 @@
+//! Per-connection SQLite tuning. These pragmas are connection state rather
+//! than part of the file, so they are reapplied to every connection opened.
+
+use std::time::Duration;
+
+use rusqlite::Connection;
+
+use super::{SaveManager, SaveManagerError, SaveManagerResult};
+
+/// How long a statement waits on another connection's lock before failing
+/// with `SQLITE_BUSY`.
+const DEFAULT_BUSY_TIMEOUT_MS: u32 = 5_000;
+
+/// WAL size, in pages, at which a commit checkpoints automatically. SQLite's
+/// own default.
+const DEFAULT_WAL_AUTOCHECKPOINT_PAGES: u32 = 1_000;
+
+/// `PRAGMA synchronous` levels worth choosing between in WAL mode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Synchronous {
+    /// Syncs only at checkpoints. Under WAL this can't corrupt the database;
+    /// a power cut can at worst lose the last few commits.
+    Normal,
+    /// Syncs on every commit, so a committed save survives a power cut.
+    Full,
+}
+
+impl Synchronous {
+    fn parse(value: &str) -> SaveManagerResult<Self> {
+        match value.to_ascii_uppercase().as_str() {
+            "NORMAL" => Ok(Self::Normal),
+            "FULL" => Ok(Self::Full),
+            _ => Err(SaveManagerError::InvalidConfig(format!(
+                "synchronous must be NORMAL or FULL, got {value:?}"
+            ))),
+        }
+    }
+
+    fn pragma_value(self) -> &'static str {
+        match self {
+            Self::Normal => "NORMAL",
+            Self::Full => "FULL",
+        }
+    }
+}
+
+pub(super) struct SqliteOptions {
+    busy_timeout_ms: u32,
+    synchronous: Synchronous,
+    /// 0 turns automatic checkpoints off.
+    wal_autocheckpoint_pages: u32,
+}
+
+impl Default for SqliteOptions {
+    fn default() -> Self {
+        // FULL is SQLite's own default, kept so saves stay as durable as
+        // they were before the option existed.
+        Self {
+            busy_timeout_ms: DEFAULT_BUSY_TIMEOUT_MS,
+            synchronous: Synchronous::Full,
+            wal_autocheckpoint_pages: DEFAULT_WAL_AUTOCHECKPOINT_PAGES,
+        }
+    }
+}
+
+impl SqliteOptions {
+    /// Applies every option to a connection that may write.
+    pub(super) fn apply(&self, conn: &Connection) -> rusqlite::Result<()> {
+        self.apply_busy_timeout(conn)?;
+        conn.pragma_update(None, "synchronous", self.synchronous.pragma_value())?;
+        self.apply_wal_autocheckpoint(conn)
+    }
+
+    fn apply_wal_autocheckpoint(&self, conn: &Connection) -> rusqlite::Result<()> {
+        conn.pragma_update(None, "wal_autocheckpoint", self.wal_autocheckpoint_pages)
+    }
+
+    /// Read-only connections never sync, so only the timeout matters there.
+    pub(super) fn apply_busy_timeout(&self, conn: &Connection) -> rusqlite::Result<()> {
+        conn.busy_timeout(Duration::from_millis(u64::from(self.busy_timeout_ms)))
+    }
+}
+
+impl SaveManager {
+    /// Replaces the busy timeout and `synchronous` level (`"NORMAL"` or
+    /// `"FULL"`, any case) used for connections opened from now on, until the
+    /// save system is re-initialized. Already open slots keep their settings
+    /// until they are reopened; the cached metadata connection is reopened
+    /// with them straight away.
+    pub fn set_sqlite_options(
+        &mut self,
+        busy_timeout_ms: u32,
+        synchronous: &str,
+    ) -> SaveManagerResult<()> {
+        self.sqlite_options.synchronous = Synchronous::parse(synchronous)?;
+        self.sqlite_options.busy_timeout_ms = busy_timeout_ms;
+        self.metadata_cache.discard();
+        Ok(())
+    }
+
+    /// Sets how many pages the WAL may grow to before a commit folds it back
+    /// into the database (default 1000). Applies to open slots straight away
+    /// and to every connection opened until the save system is
+    /// re-initialized.
+    ///
+    /// A lower value keeps the WAL small and each `.db` file closer to
+    /// complete on its own, at the cost of more frequent writes to storage. A
+    /// higher value batches those writes, which is gentler on flash but
+    /// leaves more recent saves living only in the `-wal` file. 0 turns
+    /// automatic checkpoints off entirely, leaving it to the explicit ones:
+    /// every save already checkpoints its slot, and `checkpoint_slot` does
+    /// so on demand.
+    pub fn set_wal_autocheckpoint(&mut self, pages: i32) -> SaveManagerResult<()> {
+        let pages = u32::try_from(pages).map_err(|_| {
+            SaveManagerError::InvalidConfig("wal_autocheckpoint must not be negative".to_owned())
+        })?;
+        self.sqlite_options.wal_autocheckpoint_pages = pages;
+        for slot in self.open_slots.values() {
+            self.sqlite_options.apply_wal_autocheckpoint(&slot.conn)?;
+        }
+        self.metadata_cache.discard();
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+//! A cheap look at a slot's stats, e.g. for the main menu's Continue button.
+
+use std::path::Path;
+
+use super::{
+    read_player_stats, slot_file_path, table_has_column, SaveManager, SaveManagerError,
+    SaveManagerResult,
+};
+use crate::api::saves::SlotPreview;
+
+impl SaveManager {
+    /// Reads a slot's stats and item count on a read-only connection. Unlike
+    /// loading, this runs no migrations and doesn't mark the slot as played,
+    /// so the file is left exactly as it was. A slot that was never saved
+    /// previews with no stats and no items.
+    pub fn preview_slot(&self, slot_id: &str) -> SaveManagerResult<SlotPreview> {
+        let file_path = slot_file_path(&*self.metadata_connection()?, slot_id)?;
+        let file_path = Path::new(&file_path);
+        if !file_path.is_file() {
+            // Opening it read-only would fail with a less useful error.
+            return Err(SaveManagerError::SlotNotFound(slot_id.to_owned()));
+        }
+        let conn = self.open_readonly(file_path)?;
+
+        let stats = if table_has_column(&conn, "player_stats", "health")? {
+            read_player_stats(&conn)?
+        } else {
+            None
+        };
+        let item_count = if table_has_column(&conn, "inventory", "item")? {
+            conn.query_row("SELECT COUNT(*) FROM inventory", [], |row| {
+                row.get::<_, i64>(0)
+            })? as usize
+        } else {
+            0
+        };
+        Ok(SlotPreview {
+            health: stats.map(|(health, _)| health),
+            experience: stats.map(|(_, experience)| experience),
+            item_count,
+        })
+    }
+}
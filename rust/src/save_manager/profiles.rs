@@ -0,0 +1,37 @@
+//! Separate save stores for the players sharing a device.
+//!
+//! A profile is just a directory under `<base>/<app_name>/profiles` holding
+//! its own saves and metadata database, so slot ids, settings and the trash
+//! never leak between profiles.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::SaveManagerResult;
+
+const PROFILES_DIRECTORY: &str = "profiles";
+
+/// The profiles that have a save store under `base_path` for `app_name`,
+/// sorted by name. Empty if no profile was ever initialized there.
+pub fn list_profiles(base_path: &Path, app_name: &str) -> SaveManagerResult<Vec<String>> {
+    let entries = match fs::read_dir(base_path.join(app_name).join(PROFILES_DIRECTORY)) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+    let mut profiles = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            profiles.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+    profiles.sort();
+    Ok(profiles)
+}
+
+/// The directory holding `profile`'s saves and metadata inside `app_dir`.
+pub(super) fn profile_dir(app_dir: &Path, profile: &str) -> PathBuf {
+    app_dir.join(PROFILES_DIRECTORY).join(profile)
+}
@@ -0,0 +1,46 @@
+//! Read-only connections kept beside each open slot's writer.
+//!
+//! WAL mode lets readers work from the last committed snapshot while a write
+//! transaction is open, but only on a separate connection. Reads through the
+//! pool never see, or wait on, a half-finished write on the writer.
+
+use std::cell::RefCell;
+use std::path::Path;
+
+use rusqlite::Connection;
+
+use super::{SaveManager, SaveManagerResult, SlotHandle};
+
+/// Idle readers kept per slot. Commands run one at a time on the save worker,
+/// so more than a couple are never in use together.
+const MAX_IDLE_READERS: usize = 2;
+
+#[derive(Default)]
+pub(super) struct ReadPool {
+    idle: RefCell<Vec<Connection>>,
+}
+
+impl SaveManager {
+    /// Runs `read` on one of the slot's read-only connections, opening one if
+    /// none is idle.
+    pub(super) fn with_reader<T>(
+        &self,
+        handle: SlotHandle,
+        read: impl FnOnce(&Connection) -> SaveManagerResult<T>,
+    ) -> SaveManagerResult<T> {
+        let slot = self.slot_entry(handle)?;
+        let reader = slot.readers.idle.borrow_mut().pop();
+        let reader = match reader {
+            Some(reader) => reader,
+            None => self.open_readonly(Path::new(slot.conn.path().unwrap_or_default()))?,
+        };
+
+        let result = read(&reader);
+
+        let mut idle = slot.readers.idle.borrow_mut();
+        if idle.len() < MAX_IDLE_READERS {
+            idle.push(reader);
+        }
+        result
+    }
+}
@@ -0,0 +1,41 @@
+//! Finding slots by name for a save browser with many entries.
+//!
+//! This is a plain `LIKE` scan of `save_slots`, not an FTS5 index. Names are
+//! short, so scanning even thousands of them costs little next to reading
+//! the matching rows, and an index would be a second copy of every name to
+//! keep in sync.
+
+use super::{slot_from_row, SaveManager, SaveManagerResult, SLOT_COLUMNS};
+use crate::api::saves::SaveSlotMetadata;
+
+impl SaveManager {
+    /// Slots whose name contains `query`, most recently played first. The
+    /// match ignores ASCII case, and `%` and `_` in `query` match only
+    /// themselves. A blank query matches every slot.
+    pub fn search_slots(&self, query: &str) -> SaveManagerResult<Vec<SaveSlotMetadata>> {
+        let pattern = format!("%{}%", escape_like(query.trim()));
+        let conn = self.metadata_connection()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {SLOT_COLUMNS} FROM save_slots
+             WHERE name LIKE ?1 ESCAPE '\\' AND deleted_at IS NULL
+             ORDER BY last_played_ms DESC, id"
+        ))?;
+        let slots = stmt
+            .query_map([pattern], slot_from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(slots)
+    }
+}
+
+/// Escapes `LIKE` wildcards so they match literally, with `\` as the escape
+/// character.
+fn escape_like(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
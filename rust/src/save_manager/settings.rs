@@ -0,0 +1,39 @@
+//! Global key/value settings (volume, language, ...) kept in the metadata
+//! database, independent of any slot.
+
+use rusqlite::{params, OptionalExtension};
+
+use super::{SaveManager, SaveManagerResult};
+
+impl SaveManager {
+    /// Stores `value` under `key`, replacing any previous value.
+    pub fn set_setting(&self, key: &str, value: &str) -> SaveManagerResult<()> {
+        self.metadata_connection()?.execute(
+            "INSERT INTO app_settings (key, value) VALUES (?1, ?2)
+             ON CONFLICT (key) DO UPDATE SET value = excluded.value",
+            params![key, value],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_setting(&self, key: &str) -> SaveManagerResult<Option<String>> {
+        Ok(self
+            .metadata_connection()?
+            .query_row(
+                "SELECT value FROM app_settings WHERE key = ?1",
+                params![key],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    /// Every stored setting, sorted by key.
+    pub fn get_all_settings(&self) -> SaveManagerResult<Vec<(String, String)>> {
+        let conn = self.metadata_connection()?;
+        let mut stmt = conn.prepare("SELECT key, value FROM app_settings ORDER BY key")?;
+        let settings = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(settings)
+    }
+}
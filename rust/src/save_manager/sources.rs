@@ -0,0 +1,189 @@
+//! Where the manager gets new ids, the current time and free disk space.
+//!
+//! Each goes through a trait object so tests can swap in fixed values and
+//! assert on exact ids and timestamps, or pretend the disk is full. The real
+//! sources are the system clock, SQLite's CSPRNG and the filesystem.
+
+use std::path::Path;
+use std::time::SystemTime;
+
+use rusqlite::Connection;
+
+use super::{epoch_ms, format_timestamp, SaveManager, SaveManagerResult};
+
+pub(super) trait Clock {
+    fn now(&self) -> SystemTime;
+}
+
+pub(super) trait IdGenerator {
+    /// A new unique id. `conn` is an open metadata connection, for sources
+    /// that want SQLite's randomness.
+    fn next_id(&self, conn: &Connection) -> SaveManagerResult<String>;
+}
+
+pub(super) trait DiskSpace {
+    /// Bytes available on the filesystem holding `path`, or `None` if that
+    /// can't be determined.
+    fn free_bytes(&self, path: &Path) -> Option<u64>;
+}
+
+pub(super) struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Random (version 4) UUIDs.
+pub(super) struct RandomIds;
+
+impl IdGenerator for RandomIds {
+    fn next_id(&self, conn: &Connection) -> SaveManagerResult<String> {
+        let mut bytes: Vec<u8> = conn.query_row("SELECT randomblob(16)", [], |row| row.get(0))?;
+        bytes[6] = (bytes[6] & 0x0f) | 0x40;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+        Ok(format_uuid(&bytes))
+    }
+}
+
+pub(super) struct SystemDiskSpace;
+
+impl DiskSpace for SystemDiskSpace {
+    fn free_bytes(&self, path: &Path) -> Option<u64> {
+        free_disk_bytes(path)
+    }
+}
+
+impl SaveManager {
+    /// The current time in both forms `last_played` is stored in: display
+    /// text and Unix epoch milliseconds.
+    pub(super) fn now_timestamp(&self) -> (String, i64) {
+        let now = self.clock.now();
+        (format_timestamp(now), epoch_ms(now))
+    }
+
+    /// A new id for a slot or checkpoint.
+    pub(super) fn new_id(&self, conn: &Connection) -> SaveManagerResult<String> {
+        self.ids.next_id(conn)
+    }
+}
+
+/// Bytes available to this process on the filesystem holding `path`, or
+/// `None` where that can't be determined.
+#[cfg(unix)]
+fn free_disk_bytes(path: &Path) -> Option<u64> {
+    use std::ffi::CString;
+    use std::mem::MaybeUninit;
+    use std::os::unix::ffi::OsStrExt;
+
+    let path = CString::new(path.as_os_str().as_bytes()).ok()?;
+    let mut stats = MaybeUninit::<libc::statvfs>::uninit();
+    // SAFETY: `path` is NUL-terminated and outlives the call, and `stats` is
+    // only read once `statvfs` reports that it filled it in.
+    let stats = unsafe {
+        if libc::statvfs(path.as_ptr(), stats.as_mut_ptr()) != 0 {
+            return None;
+        }
+        stats.assume_init()
+    };
+    // The field widths differ between platforms.
+    #[allow(clippy::unnecessary_cast)]
+    Some(stats.f_bavail as u64 * stats.f_frsize as u64)
+}
+
+#[cfg(windows)]
+fn free_disk_bytes(path: &Path) -> Option<u64> {
+    use std::os::windows::ffi::OsStrExt;
+    use std::ptr;
+
+    // kernel32 is always linked on Windows; `ULARGE_INTEGER` has the layout
+    // of a `u64` and `BOOL` that of an `i32`.
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetDiskFreeSpaceExW(
+            directory_name: *const u16,
+            free_bytes_available_to_caller: *mut u64,
+            total_number_of_bytes: *mut u64,
+            total_number_of_free_bytes: *mut u64,
+        ) -> i32;
+    }
+
+    let path: Vec<u16> = path.as_os_str().encode_wide().chain([0]).collect();
+    let mut available = 0;
+    // SAFETY: `path` is NUL-terminated and outlives the call, `available` is
+    // a valid place for the result, and null asks it to skip the totals.
+    let succeeded = unsafe {
+        GetDiskFreeSpaceExW(
+            path.as_ptr(),
+            &mut available,
+            ptr::null_mut(),
+            ptr::null_mut(),
+        )
+    };
+    (succeeded != 0).then_some(available)
+}
+
+#[cfg(not(any(unix, windows)))]
+fn free_disk_bytes(_path: &Path) -> Option<u64> {
+    None
+}
+
+fn format_uuid(bytes: &[u8]) -> String {
+    let hex: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+/// Whether `id` has the `8-4-4-4-12` lowercase hex shape of the ids issued
+/// here.
+pub(super) fn is_uuid(id: &str) -> bool {
+    id.len() == 36
+        && id.char_indices().all(|(index, c)| match index {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_digit() || ('a'..='f').contains(&c),
+        })
+}
+
+/// Always reports the same moment.
+#[cfg(test)]
+pub(super) struct FixedClock(pub SystemTime);
+
+#[cfg(test)]
+impl Clock for FixedClock {
+    fn now(&self) -> SystemTime {
+        self.0
+    }
+}
+
+/// UUID-shaped ids counting up from 1.
+#[cfg(test)]
+#[derive(Default)]
+pub(super) struct SequentialIds {
+    issued: std::cell::Cell<u128>,
+}
+
+#[cfg(test)]
+impl IdGenerator for SequentialIds {
+    fn next_id(&self, _conn: &Connection) -> SaveManagerResult<String> {
+        self.issued.set(self.issued.get() + 1);
+        Ok(format_uuid(&self.issued.get().to_be_bytes()))
+    }
+}
+
+/// Always reports the same amount of free space.
+#[cfg(test)]
+pub(super) struct FixedDiskSpace(pub u64);
+
+#[cfg(test)]
+impl DiskSpace for FixedDiskSpace {
+    fn free_bytes(&self, _path: &Path) -> Option<u64> {
+        Some(self.0)
+    }
+}
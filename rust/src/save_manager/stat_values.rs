@@ -0,0 +1,102 @@
+//! Player stats of any SQLite type, stored by key, so a game can add stats
+//! without a schema change.
+//!
+//! Health and experience are stats like any other here, but they still live
+//! in `player_stats`, where saves, checksums and exports expect them; the
+//! slot schema mirrors them into `player_stat_values` with triggers. Writing
+//! either of them by key updates `player_stats` and so must be an integer.
+
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSqlOutput, ValueRef};
+use rusqlite::{params, Connection, OptionalExtension, ToSql};
+
+use super::{read_player_stats, SaveManager, SaveManagerError, SaveManagerResult, SlotHandle};
+use crate::api::saves::StatValue;
+
+impl SaveManager {
+    /// Stores `value` under `key`, replacing any previous value. Like the
+    /// inventory edits, this bumps the revision and needs a slot that has
+    /// been saved at least once. `health` and `experience` only take `Int`
+    /// values that pass validation.
+    pub fn set_stat(
+        &self,
+        handle: SlotHandle,
+        key: &str,
+        value: &StatValue,
+    ) -> SaveManagerResult<()> {
+        self.edit_player_data(handle, |tx| match key {
+            "health" => self.set_core_stat(tx, "health", value),
+            "experience" => self.set_core_stat(tx, "experience", value),
+            _ => {
+                tx.execute(
+                    "INSERT OR REPLACE INTO player_stat_values (key, value) VALUES (?1, ?2)",
+                    params![key, value],
+                )?;
+                Ok(())
+            }
+        })
+    }
+
+    /// The stat stored under `key`, or `None` if there is none.
+    pub fn get_stat(&self, handle: SlotHandle, key: &str) -> SaveManagerResult<Option<StatValue>> {
+        self.with_reader(handle, |conn| {
+            Ok(conn
+                .query_row(
+                    "SELECT value FROM player_stat_values WHERE key = ?1",
+                    [key],
+                    |row| row.get(0),
+                )
+                .optional()?)
+        })
+    }
+
+    /// Writes health or experience, named by `field`, into `player_stats`.
+    fn set_core_stat(
+        &self,
+        tx: &Connection,
+        field: &'static str,
+        value: &StatValue,
+    ) -> SaveManagerResult<()> {
+        let invalid = |reason: &str| SaveManagerError::InvalidPlayerData {
+            field,
+            reason: reason.to_owned(),
+        };
+        let StatValue::Int(value) = *value else {
+            return Err(invalid("must be an integer"));
+        };
+        let value = i32::try_from(value).map_err(|_| invalid("is out of range"))?;
+        let (health, experience) = read_player_stats(tx)?.ok_or(SaveManagerError::NoPlayerData)?;
+        if field == "health" {
+            self.validate_stats(value, experience)?;
+        } else {
+            self.validate_stats(health, value)?;
+        }
+        tx.execute(
+            &format!("UPDATE player_stats SET {field} = ?1 WHERE id = 1"),
+            [value],
+        )?;
+        Ok(())
+    }
+}
+
+impl ToSql for StatValue {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::Borrowed(match self {
+            Self::Int(value) => ValueRef::Integer(*value),
+            Self::Real(value) => ValueRef::Real(*value),
+            Self::Text(value) => ValueRef::Text(value.as_bytes()),
+            Self::Blob(value) => ValueRef::Blob(value),
+        }))
+    }
+}
+
+impl FromSql for StatValue {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Integer(value) => Ok(Self::Int(value)),
+            ValueRef::Real(value) => Ok(Self::Real(value)),
+            ValueRef::Text(_) => Ok(Self::Text(value.as_str()?.to_owned())),
+            ValueRef::Blob(value) => Ok(Self::Blob(value.to_vec())),
+            ValueRef::Null => Err(FromSqlError::InvalidType),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Totals across every save, for a profile or achievements screen.
+
+use std::path::Path;
+
+use super::{read_player_data, SaveManager, SaveManagerError, SaveManagerResult};
+use crate::api::saves::{AggregateStats, SkippedSlot};
+
+impl SaveManager {
+    /// Reads every live slot on its own read-only connection and adds up
+    /// their player data. Slots that were never saved count towards
+    /// `slot_count` only. A slot that can't be read, e.g. because it is
+    /// corrupt or its file is missing, is listed in `skipped` and left out of
+    /// the totals.
+    ///
+    /// This opens every slot file, so it costs O(number of slots) and is meant
+    /// for an occasional screen, not for every frame.
+    pub fn aggregate_stats(&self) -> SaveManagerResult<AggregateStats> {
+        let slots = {
+            let conn = self.metadata_connection()?;
+            let mut stmt = conn.prepare(
+                "SELECT id, file_path, play_time_seconds FROM save_slots
+                 WHERE deleted_at IS NULL ORDER BY id",
+            )?;
+            let rows = stmt
+                .query_map([], |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, i64>(2)?,
+                    ))
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+            rows
+        };
+
+        let mut stats = AggregateStats {
+            slot_count: slots.len(),
+            saved_slot_count: 0,
+            highest_experience: 0,
+            highest_health: 0,
+            total_items: 0,
+            total_play_time_seconds: 0,
+            skipped: Vec::new(),
+        };
+        for (slot_id, file_path, play_time_seconds) in slots {
+            let file_path = Path::new(&file_path);
+            let data = if file_path.is_file() {
+                self.open_readonly(file_path)
+                    .and_then(|conn| read_player_data(&conn))
+            } else {
+                // Opening it would fail anyway; say why.
+                Err(SaveManagerError::SlotNotFound(slot_id.clone()))
+            };
+            match data {
+                Ok(data) => {
+                    stats.total_play_time_seconds += play_time_seconds;
+                    let Some(data) = data else { continue };
+                    stats.saved_slot_count += 1;
+                    stats.highest_experience = stats.highest_experience.max(data.experience);
+                    stats.highest_health = stats.highest_health.max(data.health);
+                    stats.total_items += data.inventory.len();
+                }
+                Err(err) => stats.skipped.push(SkippedSlot {
+                    slot_id,
+                    error: err.to_string(),
+                }),
+            }
+        }
+        Ok(stats)
+    }
+}
@@ -0,0 +1,431 @@
+//! Whole-store backups: the metadata database and every slot in one tar
+//! file, e.g. for a device backup or moving to a new phone.
+//!
+//! The archive holds `metadata.db` and `saves/<slot_id>.db` for each slot
+//! outside the trash. Checkpoints, autosaves and trashed slots are left out.
+//! Like the gzip framing in `compression`, the tar format is written by hand
+//! rather than pulling in an archive crate; only plain ustar files are read
+//! back, so archives from other tools are rejected.
+
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Component, Path, PathBuf};
+
+use rusqlite::{params, Connection};
+
+use super::sources::is_uuid;
+use super::{
+    adopt_unversioned_metadata_db, checkpoint, remove_slot_files, run_migrations, tags,
+    verify_metadata_schema, SaveManager, SaveManagerError, SaveManagerResult, METADATA_DB_FILE,
+    METADATA_DB_MIGRATIONS,
+};
+use crate::api::saves::{ArchiveProgress, SlotEventKind};
+
+const BLOCK_SIZE: usize = 512;
+
+/// Directory the slot files sit in inside the archive.
+const SAVES_ENTRY_DIR: &str = "saves";
+
+/// Slot columns carried from an archive into the store.
+struct ArchivedSlot {
+    id: String,
+    name: String,
+    last_played: String,
+    created_at: Option<String>,
+    last_played_ms: i64,
+    play_time_seconds: i64,
+    description: Option<String>,
+    thumbnail: Option<Vec<u8>>,
+    tags: Vec<String>,
+}
+
+impl SaveManager {
+    /// Writes the metadata database and every slot to a tar archive at
+    /// `path`, checkpointing each database first so the copies are whole.
+    /// `on_progress` hears about each file once it is in the archive. The
+    /// archive only appears at `path` once it is complete.
+    pub fn export_all(
+        &self,
+        path: &Path,
+        mut on_progress: impl FnMut(ArchiveProgress),
+    ) -> SaveManagerResult<()> {
+        let meta_conn = self.metadata_connection()?;
+        let slots = {
+            let mut stmt = meta_conn
+                .prepare("SELECT id, file_path FROM save_slots WHERE deleted_at IS NULL")?;
+            let slots = stmt
+                .query_map([], |row| {
+                    Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+            slots
+        };
+        checkpoint(&meta_conn)?;
+        for (_, file_path) in &slots {
+            self.checkpoint_slot_file(Path::new(file_path))?;
+        }
+
+        let mut entries = vec![(METADATA_DB_FILE.to_owned(), self.metadata_db_path.clone())];
+        entries.extend(
+            slots
+                .into_iter()
+                .map(|(id, file_path)| (format!("{SAVES_ENTRY_DIR}/{id}.db"), file_path.into())),
+        );
+
+        let pending = pending_path(path);
+        let written = write_archive(&pending, &entries, &mut on_progress)
+            .and_then(|()| Ok(fs::rename(&pending, path)?));
+        if written.is_err() {
+            let _ = fs::remove_file(&pending);
+        }
+        written
+    }
+
+    /// Restores slots from an archive written by `export_all` and returns
+    /// how many were imported. With `merge` they are added next to the
+    /// existing slots under new ids; without it the store is emptied first
+    /// and the archive's slots keep their ids, and its settings are restored
+    /// too. The whole archive is unpacked and checked before anything is
+    /// changed. `on_progress` hears about each slot once it is imported.
+    pub fn import_all(
+        &mut self,
+        path: &Path,
+        merge: bool,
+        mut on_progress: impl FnMut(ArchiveProgress),
+    ) -> SaveManagerResult<usize> {
+        let staging = self
+            .metadata_db_path
+            .with_file_name("archive_import.pending");
+        if staging.exists() {
+            fs::remove_dir_all(&staging)?;
+        }
+        fs::create_dir_all(staging.join(SAVES_ENTRY_DIR))?;
+        let imported = self.import_staged(path, &staging, merge, &mut on_progress);
+        let _ = fs::remove_dir_all(&staging);
+        imported
+    }
+
+    fn import_staged(
+        &mut self,
+        path: &Path,
+        staging: &Path,
+        merge: bool,
+        on_progress: &mut impl FnMut(ArchiveProgress),
+    ) -> SaveManagerResult<usize> {
+        unpack_archive(path, staging)?;
+        let archived_meta = staging.join(METADATA_DB_FILE);
+        if !archived_meta.exists() {
+            return Err(SaveManagerError::UnsupportedFormat(
+                "archive has no metadata database".to_owned(),
+            ));
+        }
+        let (slots, settings) = {
+            let mut conn = self.open_connection(&archived_meta)?;
+            adopt_unversioned_metadata_db(&conn)?;
+            run_migrations(&METADATA_DB_MIGRATIONS, &mut conn)?;
+            verify_metadata_schema(&conn)?;
+            (archived_slots(&conn)?, archived_settings(&conn)?)
+        };
+        let slot_file = |id: &str| staging.join(SAVES_ENTRY_DIR).join(format!("{id}.db"));
+        // Ids become file names below, so anything but a plain UUID could
+        // point the rename outside the saves directory.
+        if let Some(bad) = slots.iter().find(|slot| !is_uuid(&slot.id)) {
+            return Err(SaveManagerError::UnsupportedFormat(format!(
+                "archive has an invalid slot id {:?}",
+                bad.id
+            )));
+        }
+        for slot in &slots {
+            self.check_archived_slot_file(&slot.id, &slot_file(&slot.id))?;
+        }
+
+        if !merge {
+            // Nothing has been deleted yet, so a row that won't register has
+            // to fail here rather than after the store is emptied.
+            check_registration(&*self.metadata_connection()?, &slots)?;
+            self.delete_all_slots()?;
+            let meta_conn = self.metadata_connection()?;
+            for (key, value) in &settings {
+                meta_conn.execute(
+                    "INSERT OR REPLACE INTO app_settings (key, value) VALUES (?1, ?2)",
+                    params![key, value],
+                )?;
+            }
+        }
+
+        let meta_conn = self.metadata_connection()?;
+        let total = slots.len();
+        for (index, slot) in slots.iter().enumerate() {
+            let id = if merge {
+                self.new_id(&meta_conn)?
+            } else {
+                slot.id.clone()
+            };
+            let file_path = self.slot_path(&id);
+            fs::rename(slot_file(&slot.id), &file_path)?;
+            let registered = register_slot(&meta_conn, &id, &file_path, slot);
+            if let Err(err) = registered {
+                let _ = remove_slot_files(&file_path);
+                return Err(err);
+            }
+            self.record_change(&id, SlotEventKind::Created, None)?;
+            on_progress(ArchiveProgress {
+                current: index + 1,
+                total,
+                file_name: format!("{SAVES_ENTRY_DIR}/{}.db", slot.id),
+            });
+        }
+        Ok(total)
+    }
+
+    /// Fails unless the staged file for slot `id` is a database that opens
+    /// with this store's key and passes a quick check.
+    fn check_archived_slot_file(&self, id: &str, file_path: &Path) -> SaveManagerResult<()> {
+        if !file_path.is_file() {
+            return Err(SaveManagerError::UnsupportedFormat(format!(
+                "archive has no file for slot {id}"
+            )));
+        }
+        let status: String =
+            self.open_readonly(file_path)?
+                .query_row("PRAGMA quick_check", [], |row| row.get(0))?;
+        if status != "ok" {
+            return Err(SaveManagerError::UnsupportedFormat(format!(
+                "archived slot {id} is damaged: {status}"
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Where an archive is built before it is moved to `path`.
+fn pending_path(path: &Path) -> PathBuf {
+    let mut pending = path.as_os_str().to_owned();
+    pending.push(".pending");
+    pending.into()
+}
+
+fn archived_slots(conn: &Connection) -> SaveManagerResult<Vec<ArchivedSlot>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, name, last_played, created_at, last_played_ms, play_time_seconds,
+                description, thumbnail
+         FROM save_slots WHERE deleted_at IS NULL
+         ORDER BY last_played_ms, id",
+    )?;
+    let mut slots = stmt
+        .query_map([], |row| {
+            Ok(ArchivedSlot {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                last_played: row.get(2)?,
+                created_at: row.get(3)?,
+                last_played_ms: row.get(4)?,
+                play_time_seconds: row.get(5)?,
+                description: row.get(6)?,
+                thumbnail: row.get(7)?,
+                tags: Vec::new(),
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    for slot in &mut slots {
+        slot.tags = tags::slot_tags(conn, &slot.id)?;
+    }
+    Ok(slots)
+}
+
+fn archived_settings(conn: &Connection) -> SaveManagerResult<Vec<(String, String)>> {
+    let mut stmt = conn.prepare("SELECT key, value FROM app_settings")?;
+    let settings = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(settings)
+}
+
+/// Inserts every slot of a replace-mode import in a transaction that is
+/// rolled back, after clearing the current rows the same way, so a row that
+/// would fail to register is found before anything is deleted.
+fn check_registration(meta_conn: &Connection, slots: &[ArchivedSlot]) -> SaveManagerResult<()> {
+    let tx = meta_conn.unchecked_transaction()?;
+    tx.execute("DELETE FROM save_slots", [])?;
+    for slot in slots {
+        insert_slot_rows(&tx, &slot.id, Path::new(""), slot)?;
+    }
+    Ok(())
+}
+
+/// Adds `slot`'s row and tags to the store as `id`, backed by `file_path`.
+fn register_slot(
+    meta_conn: &Connection,
+    id: &str,
+    file_path: &Path,
+    slot: &ArchivedSlot,
+) -> SaveManagerResult<()> {
+    let tx = meta_conn.unchecked_transaction()?;
+    insert_slot_rows(&tx, id, file_path, slot)?;
+    tx.commit()?;
+    Ok(())
+}
+
+fn insert_slot_rows(
+    conn: &Connection,
+    id: &str,
+    file_path: &Path,
+    slot: &ArchivedSlot,
+) -> SaveManagerResult<()> {
+    conn.execute(
+        "INSERT INTO save_slots
+             (id, name, file_path, last_played, created_at, last_played_ms,
+              play_time_seconds, description, thumbnail)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        params![
+            id,
+            slot.name,
+            file_path.to_string_lossy(),
+            slot.last_played,
+            slot.created_at,
+            slot.last_played_ms,
+            slot.play_time_seconds,
+            slot.description,
+            slot.thumbnail
+        ],
+    )?;
+    for tag in &slot.tags {
+        conn.execute(
+            "INSERT INTO slot_tags (slot_id, tag) VALUES (?1, ?2)",
+            params![id, tag],
+        )?;
+    }
+    Ok(())
+}
+
+fn write_archive(
+    path: &Path,
+    entries: &[(String, PathBuf)],
+    on_progress: &mut impl FnMut(ArchiveProgress),
+) -> SaveManagerResult<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    for (index, (name, source)) in entries.iter().enumerate() {
+        let mut file = File::open(source)?;
+        let size = file.metadata()?.len();
+        out.write_all(&tar_header(name, size))?;
+        if io::copy(&mut file, &mut out)? != size {
+            return Err(
+                io::Error::other(format!("{} changed while archiving", source.display())).into(),
+            );
+        }
+        write_padding(&mut out, size)?;
+        on_progress(ArchiveProgress {
+            current: index + 1,
+            total: entries.len(),
+            file_name: name.clone(),
+        });
+    }
+    // Two empty blocks mark the end of a tar archive.
+    out.write_all(&[0; 2 * BLOCK_SIZE])?;
+    out.into_inner()
+        .map_err(io::IntoInnerError::into_error)?
+        .sync_all()?;
+    Ok(())
+}
+
+/// Extracts the archive at `path` into `dir`, accepting only the entries
+/// `export_all` writes.
+fn unpack_archive(path: &Path, dir: &Path) -> SaveManagerResult<()> {
+    let unsupported = |reason: String| SaveManagerError::UnsupportedFormat(reason);
+    let mut input = BufReader::new(File::open(path)?);
+    let mut header = [0; BLOCK_SIZE];
+    loop {
+        input.read_exact(&mut header)?;
+        if header.iter().all(|&byte| byte == 0) {
+            return Ok(());
+        }
+        let stored_checksum = parse_octal(&header[148..156]);
+        if stored_checksum != Some(header_checksum(&header)) || &header[257..262] != b"ustar" {
+            return Err(unsupported("not a tar archive".to_owned()));
+        }
+        let name = String::from_utf8_lossy(field(&header[0..100])).into_owned();
+        let size = parse_octal(&header[124..136])
+            .ok_or_else(|| unsupported(format!("bad size for {name}")))?;
+        let regular_file = matches!(header[156], b'0' | 0);
+        let target =
+            entry_target(&name).filter(|_| regular_file && field(&header[345..500]).is_empty());
+        let target =
+            target.ok_or_else(|| unsupported(format!("unexpected archive entry {name}")))?;
+
+        let mut file = File::create(dir.join(target))?;
+        if io::copy(&mut (&mut input).take(size), &mut file)? != size {
+            return Err(unsupported(format!("{name} is truncated")));
+        }
+        let padding = padding_len(size);
+        input.read_exact(&mut vec![0; padding])?;
+    }
+}
+
+/// Where an archive entry goes inside the staging directory, or `None` for
+/// anything `export_all` wouldn't have written.
+fn entry_target(name: &str) -> Option<PathBuf> {
+    if name == METADATA_DB_FILE {
+        return Some(PathBuf::from(name));
+    }
+    let file_name = name.strip_prefix(SAVES_ENTRY_DIR)?.strip_prefix('/')?;
+    let mut components = Path::new(file_name).components();
+    match (components.next(), components.next()) {
+        (Some(Component::Normal(_)), None) if file_name.ends_with(".db") => {
+            Some(Path::new(SAVES_ENTRY_DIR).join(file_name))
+        }
+        _ => None,
+    }
+}
+
+fn tar_header(name: &str, size: u64) -> [u8; BLOCK_SIZE] {
+    let mut header = [0; BLOCK_SIZE];
+    header[..name.len()].copy_from_slice(name.as_bytes());
+    header[100..108].copy_from_slice(b"0000644\0");
+    header[108..116].copy_from_slice(b"0000000\0");
+    header[116..124].copy_from_slice(b"0000000\0");
+    header[124..136].copy_from_slice(format!("{size:011o}\0").as_bytes());
+    header[136..148].copy_from_slice(b"00000000000\0");
+    header[156] = b'0';
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[263..265].copy_from_slice(b"00");
+    let checksum = header_checksum(&header);
+    header[148..156].copy_from_slice(format!("{checksum:06o}\0 ").as_bytes());
+    header
+}
+
+/// The sum of the header's bytes, counting the checksum field as spaces.
+fn header_checksum(header: &[u8; BLOCK_SIZE]) -> u64 {
+    header
+        .iter()
+        .enumerate()
+        .map(|(index, &byte)| {
+            if (148..156).contains(&index) {
+                u64::from(b' ')
+            } else {
+                u64::from(byte)
+            }
+        })
+        .sum()
+}
+
+/// A NUL-terminated header field without its terminator.
+fn field(bytes: &[u8]) -> &[u8] {
+    let end = bytes
+        .iter()
+        .position(|&byte| byte == 0)
+        .unwrap_or(bytes.len());
+    &bytes[..end]
+}
+
+fn parse_octal(bytes: &[u8]) -> Option<u64> {
+    let digits = std::str::from_utf8(field(bytes)).ok()?.trim();
+    u64::from_str_radix(digits, 8).ok()
+}
+
+fn padding_len(size: u64) -> usize {
+    (BLOCK_SIZE - (size % BLOCK_SIZE as u64) as usize) % BLOCK_SIZE
+}
+
+fn write_padding(out: &mut impl Write, size: u64) -> io::Result<()> {
+    out.write_all(&[0; BLOCK_SIZE][..padding_len(size)])
+}
@@ -0,0 +1,69 @@
+//! Free-form labels ("main", "speedrun", "NG+") attached to slots.
+
+use rusqlite::{params, Connection};
+
+use super::{
+    slot_file_path, slot_from_row, SaveManager, SaveManagerError, SaveManagerResult, SLOT_COLUMNS,
+};
+use crate::api::saves::SaveSlotMetadata;
+
+impl SaveManager {
+    /// Tags a slot. Tags are trimmed, and adding one twice is a no-op.
+    pub fn add_slot_tag(&self, slot_id: &str, tag: &str) -> SaveManagerResult<()> {
+        let tag = normalize_tag(tag)?;
+        let conn = self.metadata_connection()?;
+        slot_file_path(&conn, slot_id)?;
+        conn.execute(
+            "INSERT OR IGNORE INTO slot_tags (slot_id, tag) VALUES (?1, ?2)",
+            params![slot_id, tag],
+        )?;
+        Ok(())
+    }
+
+    /// Removes a tag from a slot; removing a tag it doesn't have is a no-op.
+    pub fn remove_slot_tag(&self, slot_id: &str, tag: &str) -> SaveManagerResult<()> {
+        let tag = normalize_tag(tag)?;
+        let conn = self.metadata_connection()?;
+        slot_file_path(&conn, slot_id)?;
+        conn.execute(
+            "DELETE FROM slot_tags WHERE slot_id = ?1 AND tag = ?2",
+            params![slot_id, tag],
+        )?;
+        Ok(())
+    }
+
+    /// Lists the slots carrying `tag`, most recently played first.
+    pub fn get_slots_by_tag(&self, tag: &str) -> SaveManagerResult<Vec<SaveSlotMetadata>> {
+        let tag = normalize_tag(tag)?;
+        let conn = self.metadata_connection()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {SLOT_COLUMNS} FROM save_slots
+             WHERE id IN (SELECT slot_id FROM slot_tags WHERE tag = ?1)
+               AND deleted_at IS NULL
+             ORDER BY last_played_ms DESC, id"
+        ))?;
+        let slots = stmt
+            .query_map([tag], slot_from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(slots)
+    }
+}
+
+/// A slot's tags in alphabetical order.
+pub(super) fn slot_tags(conn: &Connection, slot_id: &str) -> SaveManagerResult<Vec<String>> {
+    let mut stmt = conn.prepare("SELECT tag FROM slot_tags WHERE slot_id = ?1 ORDER BY tag")?;
+    let tags = stmt
+        .query_map([slot_id], |row| row.get(0))?
+        .collect::<Result<Vec<String>, _>>()?;
+    Ok(tags)
+}
+
+fn normalize_tag(tag: &str) -> SaveManagerResult<&str> {
+    let tag = tag.trim();
+    if tag.is_empty() {
+        return Err(SaveManagerError::InvalidTag(
+            "tag must not be empty".to_owned(),
+        ));
+    }
+    Ok(tag)
+}
@@ -0,0 +1,98 @@
+//! Manual transactions on a slot, for callers that build up one change over
+//! several UI events and want it to land all at once or not at all.
+//!
+//! `begin_transaction` opens an IMMEDIATE transaction on the handle's writer
+//! connection, which holds it until `commit_transaction` or
+//! `rollback_transaction`. Meanwhile flag writes, inventory edits and batches
+//! on that handle join it, each edit in its own savepoint so a failed one
+//! undoes only itself, and reads keep seeing the last committed state. Whole-slot saves
+//! refuse with `TransactionInProgress` instead of committing it early.
+//! Closing the handle, e.g. with `unload_slot`, rolls back whatever is still
+//! uncommitted, so a forgotten transaction never outlives its slot.
+
+use rusqlite::Connection;
+
+use super::{
+    checkpoint, current_revision, SaveManager, SaveManagerError, SaveManagerResult, SlotHandle,
+};
+use crate::api::saves::SlotEventKind;
+
+impl SaveManager {
+    /// Starts a manual transaction on `handle`. Fails with
+    /// `TransactionInProgress` if one is already open.
+    pub fn begin_transaction(&self, handle: SlotHandle) -> SaveManagerResult<()> {
+        let slot = self.slot_entry(handle)?;
+        self.ensure_no_transaction(handle)?;
+        self.ensure_not_migrating(&slot.slot_id)?;
+        slot.conn.execute_batch("BEGIN IMMEDIATE")?;
+        Ok(())
+    }
+
+    /// Commits the manual transaction on `handle` and reports the slot as
+    /// saved.
+    pub fn commit_transaction(&self, handle: SlotHandle) -> SaveManagerResult<()> {
+        let slot = self.slot_entry(handle)?;
+        if slot.conn.is_autocommit() {
+            return Err(SaveManagerError::NoTransaction);
+        }
+        slot.conn.execute_batch("COMMIT")?;
+        checkpoint(&slot.conn)?;
+        let revision = current_revision(&slot.conn)?;
+        self.record_change(&slot.slot_id, SlotEventKind::Saved, Some(revision))
+    }
+
+    /// Discards everything done since `begin_transaction` on `handle`,
+    /// along with the inventory undo buffer, whose removals may have been
+    /// among it.
+    pub fn rollback_transaction(&self, handle: SlotHandle) -> SaveManagerResult<()> {
+        let slot = self.slot_entry(handle)?;
+        if slot.conn.is_autocommit() {
+            return Err(SaveManagerError::NoTransaction);
+        }
+        slot.conn.execute_batch("ROLLBACK")?;
+        slot.undo.borrow_mut().clear();
+        Ok(())
+    }
+
+    /// Whether `handle` has a manual transaction open.
+    pub(super) fn in_transaction(&self, handle: SlotHandle) -> SaveManagerResult<bool> {
+        Ok(!self.connection(handle)?.is_autocommit())
+    }
+
+    /// Fails with `TransactionInProgress` while `handle` has a manual
+    /// transaction open, for writes that commit on their own.
+    pub(super) fn ensure_no_transaction(&self, handle: SlotHandle) -> SaveManagerResult<()> {
+        if self.in_transaction(handle)? {
+            let slot_id = self.slot_entry(handle)?.slot_id.clone();
+            return Err(SaveManagerError::TransactionInProgress(slot_id));
+        }
+        Ok(())
+    }
+}
+
+/// Runs `f` inside a savepoint on `conn`, rolling back just its own changes
+/// if it fails.
+pub(super) fn in_savepoint<T>(
+    conn: &Connection,
+    f: impl FnOnce(&Connection) -> SaveManagerResult<T>,
+) -> SaveManagerResult<T> {
+    conn.execute_batch("SAVEPOINT edit")?;
+    match f(conn) {
+        Ok(value) => {
+            conn.execute_batch("RELEASE edit")?;
+            Ok(value)
+        }
+        Err(err) => {
+            let _ = conn.execute_batch("ROLLBACK TO edit; RELEASE edit");
+            Err(err)
+        }
+    }
+}
+
+/// Rolls back a manual transaction left open on `conn`, before the
+/// connection is closed or replaced.
+pub(super) fn abandon(conn: &Connection) {
+    if !conn.is_autocommit() {
+        let _ = conn.execute_batch("ROLLBACK");
+    }
+}
@@ -0,0 +1,122 @@
+//! Soft deletion. Deleting a slot only stamps `deleted_at`; every other
+//! query skips stamped rows, and the files stay on disk until the trash is
+//! purged, so an accidental delete can be undone.
+
+use std::path::PathBuf;
+use std::time::{Duration, UNIX_EPOCH};
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+use super::{
+    fetch_slot, format_timestamp, remove_slot_files, slot_from_row, SaveManager, SaveManagerError,
+    SaveManagerResult, SLOT_COLUMNS,
+};
+use crate::api::saves::{SaveSlotMetadata, SlotEventKind, TrashedSlot};
+
+impl SaveManager {
+    /// Slots in the trash, most recently deleted first.
+    pub fn list_trashed_slots(&self) -> SaveManagerResult<Vec<TrashedSlot>> {
+        let conn = self.metadata_connection()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {SLOT_COLUMNS}, deleted_at FROM save_slots
+             WHERE deleted_at IS NOT NULL
+             ORDER BY deleted_at DESC, id"
+        ))?;
+        let slots = stmt
+            .query_map([], |row| {
+                Ok(TrashedSlot {
+                    slot: slot_from_row(row)?,
+                    deleted_at: row.get("deleted_at")?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(slots)
+    }
+
+    /// Takes a slot back out of the trash.
+    pub fn restore_slot(&self, slot_id: &str) -> SaveManagerResult<SaveSlotMetadata> {
+        let conn = self.metadata_connection()?;
+        let rows_affected = conn.execute(
+            "UPDATE save_slots SET deleted_at = NULL
+             WHERE id = ?1 AND deleted_at IS NOT NULL",
+            params![slot_id],
+        )?;
+        if rows_affected == 0 {
+            return Err(SaveManagerError::SlotNotFound(slot_id.to_owned()));
+        }
+        self.record_change(slot_id, SlotEventKind::Restored, None)?;
+        fetch_slot(&conn, slot_id)
+    }
+
+    /// Permanently removes slots trashed at least `older_than_days` days ago,
+    /// files and backups included, and returns their ids. A negative age
+    /// counts as zero, which empties the trash.
+    ///
+    /// Files go first so a failure leaves the rows behind for a retry; each
+    /// removal tolerates a file an earlier attempt already cleaned up.
+    pub fn purge_trash(&mut self, older_than_days: i64) -> SaveManagerResult<Vec<String>> {
+        let meta_conn = self.metadata_connection()?;
+        let age = Duration::from_secs((older_than_days.max(0) as u64).saturating_mul(86_400));
+        let cutoff = format_timestamp(self.clock.now().checked_sub(age).unwrap_or(UNIX_EPOCH));
+        let expired = {
+            let mut stmt = meta_conn.prepare(
+                "SELECT id FROM save_slots
+                 WHERE deleted_at IS NOT NULL AND deleted_at <= ?1",
+            )?;
+            let ids = stmt
+                .query_map([cutoff], |row| row.get::<_, String>(0))?
+                .collect::<Result<Vec<_>, _>>()?;
+            ids
+        };
+
+        for slot_id in &expired {
+            self.purge_slot(slot_id)?;
+        }
+        Ok(expired)
+    }
+
+    /// Permanently removes one slot, trashed or not: closes its handles,
+    /// deletes its files, backups and checkpoints, then its rows.
+    pub(super) fn purge_slot(&mut self, slot_id: &str) -> SaveManagerResult<()> {
+        let meta_conn = self.metadata_connection()?;
+        let file_path: String = meta_conn
+            .query_row(
+                "SELECT file_path FROM save_slots WHERE id = ?1",
+                params![slot_id],
+                |row| row.get(0),
+            )
+            .optional()?
+            .ok_or_else(|| SaveManagerError::SlotNotFound(slot_id.to_owned()))?;
+        let file_path = PathBuf::from(file_path);
+
+        for handle in self.handles_for_path(&file_path) {
+            self.close_connection(handle);
+        }
+        remove_slot_files(&file_path)?;
+        self.remove_autosaves(slot_id)?;
+        self.remove_migration_backup(slot_id)?;
+        self.remove_checkpoints(slot_id)?;
+
+        // Its tags go with it through the foreign key cascade.
+        meta_conn.execute("DELETE FROM save_slots WHERE id = ?1", params![slot_id])?;
+        Ok(())
+    }
+}
+
+/// Stamps a live slot as deleted at `deleted_at`, a `now_timestamp` display
+/// time. Callers close its handles first.
+pub(super) fn mark_deleted(
+    meta_conn: &Connection,
+    slot_id: &str,
+    deleted_at: &str,
+) -> SaveManagerResult<()> {
+    let rows_affected = meta_conn.execute(
+        "UPDATE save_slots SET deleted_at = ?2
+         WHERE id = ?1 AND deleted_at IS NULL",
+        params![slot_id, deleted_at],
+    )?;
+    if rows_affected == 0 {
+        return Err(SaveManagerError::SlotNotFound(slot_id.to_owned()));
+    }
+    Ok(())
+}
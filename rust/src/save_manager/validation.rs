@@ -0,0 +1,120 @@
+//! Sanity limits checked before player data or slot names are written
+//! anywhere.
+
+use super::{SaveManager, SaveManagerError, SaveManagerResult};
+use crate::api::saves::PlayerData;
+
+/// Inventory size accepted until the app sets its own limit.
+const DEFAULT_MAX_INVENTORY: u32 = 1_000;
+
+/// Slot name length, in characters, accepted until the app sets its own.
+const DEFAULT_MAX_NAME_LENGTH: u32 = 100;
+
+pub(super) struct ValidationLimits {
+    max_inventory: u32,
+    max_health: i32,
+    max_name_length: u32,
+}
+
+impl Default for ValidationLimits {
+    fn default() -> Self {
+        Self {
+            max_inventory: DEFAULT_MAX_INVENTORY,
+            max_health: i32::MAX,
+            max_name_length: DEFAULT_MAX_NAME_LENGTH,
+        }
+    }
+}
+
+impl SaveManager {
+    /// Replaces the limits `validate_player_data` enforces. They last until
+    /// the save system is re-initialized.
+    pub fn set_validation_limits(
+        &mut self,
+        max_inventory: u32,
+        max_health: i32,
+    ) -> SaveManagerResult<()> {
+        if max_health < 0 {
+            return Err(SaveManagerError::InvalidConfig(
+                "max_health must not be negative".to_owned(),
+            ));
+        }
+        self.limits.max_inventory = max_inventory;
+        self.limits.max_health = max_health;
+        Ok(())
+    }
+
+    /// Replaces the longest slot name, in characters, that creating or
+    /// renaming a slot accepts. Lasts until the save system is re-initialized.
+    pub fn set_max_slot_name_length(&mut self, max_length: u32) -> SaveManagerResult<()> {
+        if max_length == 0 {
+            return Err(SaveManagerError::InvalidConfig(
+                "max_slot_name_length must be at least 1".to_owned(),
+            ));
+        }
+        self.limits.max_name_length = max_length;
+        Ok(())
+    }
+
+    /// Rejects blank names and names over the length limit.
+    pub(super) fn validate_slot_name(&self, name: &str) -> SaveManagerResult<()> {
+        if name.trim().is_empty() {
+            return Err(SaveManagerError::InvalidSlotName(
+                "name must not be empty".to_owned(),
+            ));
+        }
+        self.validate_slot_name_length(name)
+    }
+
+    /// Counts Unicode scalar values rather than bytes, so names in
+    /// non-Latin scripts get the same allowance.
+    pub(super) fn validate_slot_name_length(&self, name: &str) -> SaveManagerResult<()> {
+        let length = name.chars().count();
+        if length > self.limits.max_name_length as usize {
+            return Err(SaveManagerError::InvalidSlotName(format!(
+                "name is {length} characters, the limit is {}",
+                self.limits.max_name_length
+            )));
+        }
+        Ok(())
+    }
+
+    /// Rejects data that no legitimate save should contain. Runs before any
+    /// transaction is opened, so invalid data never leaves a partial write.
+    pub(super) fn validate_player_data(&self, data: &PlayerData) -> SaveManagerResult<()> {
+        self.validate_stats(data.health, data.experience)?;
+        self.validate_inventory_len(data.inventory.len())
+    }
+
+    pub(super) fn validate_stats(&self, health: i32, experience: i32) -> SaveManagerResult<()> {
+        let invalid = |field, reason: String| SaveManagerError::InvalidPlayerData { field, reason };
+
+        if health < 0 {
+            return Err(invalid("health", "must not be negative".to_owned()));
+        }
+        if health > self.limits.max_health {
+            return Err(invalid(
+                "health",
+                format!("must be at most {}", self.limits.max_health),
+            ));
+        }
+        if experience < 0 {
+            return Err(invalid("experience", "must not be negative".to_owned()));
+        }
+        Ok(())
+    }
+
+    /// Rejects an inventory of `len` items if that is over the limit.
+    pub(super) fn validate_inventory_len(&self, len: usize) -> SaveManagerResult<()> {
+        if len > self.limits.max_inventory as usize {
+            return Err(SaveManagerError::InvalidPlayerData {
+                field: "inventory",
+                reason: format!(
+                    "holds {len} items, the limit is {}",
+                    self.limits.max_inventory
+                ),
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+//! The thread that owns the process-wide `SaveManager`.
+//!
+//! Every public save function sends a command to this thread and blocks on
+//! its reply, so all database work is serialized in one place and nothing is
+//! shared behind a lock that a panic could poison. Commands are boxed
+//! closures rather than an enum of operations, so a new API function doesn't
+//! need its own command variant.
+
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, Sender};
+use std::sync::LazyLock;
+use std::thread;
+
+use super::SaveManager;
+
+type Command = Box<dyn FnOnce(&mut Option<SaveManager>) + Send>;
+
+static WORKER: LazyLock<Option<Sender<Command>>> = LazyLock::new(|| {
+    let (sender, receiver) = mpsc::channel::<Command>();
+    thread::Builder::new()
+        .name("save-manager".to_owned())
+        .spawn(move || {
+            let mut manager = None;
+            for command in receiver {
+                // A panicking command drops its reply sender, which its caller
+                // sees as `WorkerError::Panicked`; the thread keeps serving.
+                // The warning goes to the app's logger rather than being left
+                // to the panic hook, which release builds or the app may have
+                // silenced.
+                if let Err(payload) =
+                    panic::catch_unwind(AssertUnwindSafe(|| command(&mut manager)))
+                {
+                    log::warn!(
+                        "save command panicked, the save worker keeps running: {}",
+                        panic_message(payload.as_ref())
+                    );
+                }
+            }
+        })
+        .ok()
+        .map(|_| sender)
+});
+
+/// The message a panic was raised with, if it was a string.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "non-string panic payload"
+    }
+}
+
+/// Held by tests that put a manager in the worker's global slot, so they
+/// don't swap it out from under each other.
+#[cfg(test)]
+pub(crate) static GLOBAL_MANAGER_TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+#[derive(Debug)]
+pub enum WorkerError {
+    /// The worker thread could not be started.
+    Unavailable,
+    /// The command panicked before producing a result.
+    Panicked,
+}
+
+/// Runs `command` on the worker thread against the global manager slot and
+/// waits for its result.
+///
+/// Must not be called from inside another command: the worker would wait on
+/// itself.
+pub fn run<T: Send + 'static>(
+    command: impl FnOnce(&mut Option<SaveManager>) -> T + Send + 'static,
+) -> Result<T, WorkerError> {
+    let sender = WORKER.as_ref().ok_or(WorkerError::Unavailable)?;
+    let (reply_sender, reply) = mpsc::sync_channel(1);
+    sender
+        .send(Box::new(move |manager| {
+            let _ = reply_sender.send(command(manager));
+        }))
+        .map_err(|_| WorkerError::Unavailable)?;
+    reply.recv().map_err(|_| WorkerError::Panicked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_panicking_command_does_not_stop_the_worker() {
+        let panicked = run(|_| -> () { panic!("command failed mid-operation") });
+        assert!(matches!(panicked, Err(WorkerError::Panicked)));
+
+        let next = run(|_| 42);
+        assert_eq!(next.ok(), Some(42));
+    }
+}